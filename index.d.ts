@@ -1,4 +1,62 @@
-import { ConstantModelType, DeviationModel, Position, MovingAverageType } from "./dist/bundler/ti_engine";
+import {
+  ConstantModelType,
+  ConstantModelFamily,
+  DeviationModel,
+  Position,
+  MovingAverageType,
+  BandPoint,
+  McGinleyBandPoint,
+  IchimokuPoint,
+  McGinleyCommodityChannelIndexPoint,
+  McGinleyMacdLinePoint,
+  ChaikinOscillatorPoint,
+  ReturnOnInvestmentPoint,
+  MacdPoint,
+  AroonPoint,
+  DirectionalMovementPoint,
+  VolumeFlowPoint,
+  TrendBreakConfig,
+  PersonalisedModel,
+  OhlcvSeries,
+  MissingData,
+  SimpleMovingAverageStream,
+  ExponentialMovingAverageStream,
+  SmoothedMovingAverageStream,
+  MedianStream,
+  ModeStream,
+  McGinleyDynamicStream,
+  KaufmanAdaptiveMovingAverageStream,
+  FractalAdaptiveMovingAverageStream,
+  VariableIndexDynamicAverageStream,
+  ArnaudLegouxMovingAverageStream,
+  TillsonT3Stream,
+  RelativeStrengthIndexStream,
+  StochasticOscillatorStream,
+  SlowStochasticStream,
+  SlowestStochasticStream,
+  WilliamsPercentRStream,
+  MoneyFlowIndexStream,
+  RateOfChangeStream,
+  OnBalanceVolumeStream,
+  CommodityChannelIndexStream,
+  McGinleyDynamicCommodityChannelIndexStream,
+  MacdLineStream,
+  McGinleyDynamicMacdLineStream,
+  ChaikinOscillatorStream,
+  PercentagePriceOscillatorStream,
+  ChandeMomentumOscillatorStream,
+  MovingConstantEnvelopesStream,
+  McGinleyDynamicEnvelopesStream,
+  MovingConstantBandsStream,
+  McGinleyDynamicBandsStream,
+  IchimokuCloudStream,
+  DonchianChannelsStream,
+  KeltnerChannelStream,
+  SupertrendStream,
+  ParabolicSarStream,
+  DirectionalMovementSystemStream,
+  VolumeFlowStream,
+} from "./dist/bundler/ti_engine";
 
 // Re-export enums from the generated wasm types so consumers get both types and JSDoc.
 /**
@@ -8,9 +66,41 @@ import { ConstantModelType, DeviationModel, Position, MovingAverageType } from "
  * - ExponentialMovingAverage: EMA
  * - SimpleMovingMedian: median
  * - SimpleMovingMode: mode
+ *
+ * The moving average families in `ConstantModelFamily` are also accepted by the indicators that
+ * take a `FamilyModelLike`.
  */
 export { ConstantModelType } from "./dist/bundler/ti_engine";
 
+/**
+ * Moving average families computed by ti_engine, taken as the moving constant of the
+ * moving-constant envelopes and bands, the RSI, the MACD line and the signal line (single, bulk,
+ * OhlcvSeries and streams) wherever the argument is a `FamilyModelLike`.
+ * - WeightedMovingAverage: WMA, weights 1 (oldest) to n (newest)
+ * - HullMovingAverage: HMA, WMA over sqrt(n) values of 2 * WMA(n / 2) - WMA(n)
+ * - DoubleExponentialMovingAverage: DEMA, 2 * EMA - EMA(EMA)
+ * - TripleExponentialMovingAverage: TEMA, 3 * EMA - 3 * EMA(EMA) + EMA(EMA(EMA))
+ * - ZeroLagExponentialMovingAverage: ZLEMA, EMA of 2 * price - price (n - 1) / 2 bars earlier
+ * - TriangularMovingAverage: TRIMA, SMA of the SMA (triangular weights)
+ *
+ * The values follow on from `ConstantModelType`, so one argument takes either enum.
+ * Like the RustTI models, the families are taken over one window of n values, so lookbacks stay
+ * `period - 1`. WMA and TRIMA are the standard averages, but DEMA, TEMA, ZLEMA and Hull are not
+ * the usual recursive forms that run over the whole history:
+ * - their EMAs weight the window like `ExponentialMovingAverage` (weights (1 - alpha)^age with
+ *   alpha = 2 / (n + 1), divided by their sum) instead of being seeded and carried forward
+ * - the EMA of the EMA (and its EMA for TEMA) runs over the EMAs of the window's prefixes
+ * - ZLEMA's series 2 * price - price (n - 1) / 2 bars earlier starts (n - 1) / 2 bars into the
+ *   window
+ * - Hull's last sqrt(n) values of 2 * WMA(n / 2) - WMA(n) take their WMA(n) over the prefix
+ *   ending at that bar, so the earlier ones average fewer than n values
+ *
+ * So they differ from libraries such as TA-Lib (whose DEMA, TEMA and HMA need 2n - 2, 3n - 3 and
+ * n + sqrt(n) - 2 bars before the first value). Hull, DEMA, TEMA and ZLEMA extrapolate the
+ * trend, so they can leave the range of their inputs (and an RSI over them can leave 0..100).
+ */
+export { ConstantModelFamily } from "./dist/bundler/ti_engine";
+
 /**
  * Price deviation models used to form bands around a central line.
  * - StandardDeviation
@@ -37,11 +127,730 @@ export { Position } from "./dist/bundler/ti_engine";
  * - Simple
  * - Smoothed
  * - Exponential
+ * - Weighted, Hull, DoubleExponential, TripleExponential, ZeroLagExponential, Triangular: the
+ *   families of the same names in `ConstantModelFamily`, taken over one window (Hull, DEMA, TEMA
+ *   and ZLEMA are not the usual recursive forms, see there)
+ * - VolumeWeighted: VWMA, sum(price * volume) / sum(volume), weighted by the trailing `volume`
+ *   argument of `movingAverage.*.movingAverage` (computeBatch reads the volume column or the
+ *   input named after the parameters); same values as `volumeWeightedMovingAverage`
  *
- * Note: The Rust Personalised variant is not exposed in this JS API.
+ * For a custom smoothing factor pass a `PersonalisedModel` instead (see `MovingAverageLike`).
  */
 export { MovingAverageType } from "./dist/bundler/ti_engine";
 
+/**
+ * Candles kept in WASM memory: `new OhlcvSeries(open, high, low, close, volume, timestamps?)`.
+ * Columns are copied in once; indicator methods read them directly instead of copying per call.
+ *
+ * Methods mirror the bulk functions of candleIndicators, momentumIndicators, otherIndicators,
+ * strengthIndicators, trendIndicators and volatilityIndicators (including `*Columns` variants),
+ * with the same names and outputs but without the price array arguments; `prices` is `close`.
+ * Indicators over derived series (slowStochastic, slowestStochastic, signalLine, aroonOscillator)
+ * stay module functions.
+ *
+ * `series.computeBatch(specs)` and `series.sweep(spec)` run `computeBatch` / `sweep` requests over
+ * the resident columns.
+ *
+ * Throws a TiEngineError if close is empty or any column (or timestamps) differs in length.
+ *
+ * @example
+ * const series = new OhlcvSeries(open, high, low, close, volume);
+ * const rsi = series.relativeStrengthIndex(ConstantModelType.SmoothedMovingAverage, 14);
+ * const { upper, lower } = series.keltnerChannelColumns(
+ *   ConstantModelType.ExponentialMovingAverage, ConstantModelType.SimpleMovingAverage, 2, 20
+ * );
+ * series.free(); // release the WASM memory when done
+ */
+export { OhlcvSeries } from "./dist/bundler/ti_engine";
+
+/**
+ * Streaming moving averages: `new SimpleMovingAverageStream(period)`, then `push(price)` per tick.
+ * Each push returns the value the bulk function returns for that bar (NaN until `period` prices),
+ * bit-identical to:
+ * - SimpleMovingAverageStream: `standardIndicators.bulk.simpleMovingAverage`
+ * - ExponentialMovingAverageStream: `standardIndicators.bulk.exponentialMovingAverage`
+ * - SmoothedMovingAverageStream: `standardIndicators.bulk.smoothedMovingAverage`
+ * - MedianStream / ModeStream: the `SimpleMovingMedian` / `SimpleMovingMode` constant models
+ * - McGinleyDynamicStream(period, previousMcginleyDynamic?): `movingAverage.bulk.mcginleyDynamic`
+ * - KaufmanAdaptiveMovingAverageStream(period, fastPeriod, slowPeriod, previousKama?),
+ *   FractalAdaptiveMovingAverageStream(period, previousFrama?),
+ *   VariableIndexDynamicAverageStream(period, cmoPeriod, previousVidya?),
+ *   ArnaudLegouxMovingAverageStream(period, offset, sigma) and
+ *   TillsonT3Stream(period, volumeFactor): `kaufmanAdaptiveMovingAverage`,
+ *   `fractalAdaptiveMovingAverage`, `variableIndexDynamicAverage`, `arnaudLegouxMovingAverage`
+ *   and `tillsonT3` of `movingAverage.bulk`; KAMA is NaN for the first `period` prices and VIDYA
+ *   for the first `cmoPeriod`
+ *
+ * The window stays in WASM memory, so nothing is re-sent per tick. The SMA, EMA and SMMA still sum
+ * their window in RustTI's order (O(period) arithmetic) to stay bit-identical; the median keeps its
+ * window in two ordered halves (O(log period)), the mode keeps counts, and the McGinley dynamic
+ * updates in O(1). The adaptive averages rerun their step over the window per push (O(period)).
+ * Streams below that average with a `ConstantModelType` share this behaviour.
+ * Every stream has `value`, `period`, `isReady` and `reset()`; call `free()` when done.
+ *
+ * Every `*Stream` class, here and below, also takes the still-forming bar: `update(...)` (same
+ * arguments as `push`) recomputes from the state after the last closed bar, so a candle revised on
+ * each websocket tick is counted once, and getters follow the latest revision. `commit(...)`, like
+ * `push`, closes the bar and replaces any revision.
+ *
+ * `toState()` returns the whole state as a versioned `Uint8Array` (format version, class name,
+ * fields); `SomeStream.fromState(bytes)` rebuilds the stream, which then returns the same values
+ * as the original would, so a restarted service does not replay its history.
+ *
+ * `extend(...)` takes one array per `push` argument (`prices`, or `highs, lows, close, volumes`
+ * ...) of equal length, pushes them in order and returns only the new outputs: a `Float64Array`,
+ * or the matching `*Columns` object for streams returning points (the Ichimoku lagging close is
+ * `displacedClose`). Results of successive calls concatenate to the bulk result over all bars,
+ * so a chart computed once is appended to without recomputing its history. Outputs start at the
+ * first ready bar, or are NaN-padded to the input length with `setAlignedOutput(true)`. On an
+ * error no bar of the call is kept.
+ *
+ * @example
+ * const ema = new ExponentialMovingAverageStream(20);
+ * for (const price of ticks) {
+ *   const value = ema.push(price); // NaN for the first 19 prices
+ * }
+ * socket.on("kline", ({ close, isClosed }) => (isClosed ? ema.commit(close) : ema.update(close)));
+ * process.on("SIGTERM", () => fs.writeFileSync("ema.state", ema.toState()));
+ * const restored = ExponentialMovingAverageStream.fromState(fs.readFileSync("ema.state"));
+ * const sma = ConstantModelType.SimpleMovingAverage;
+ * const bands = new MovingConstantBandsStream(sma, DeviationModel.StandardDeviation, 2, 20);
+ * const chart = bands.extend(history); // same as standardIndicators.bulk.bollingerBandsColumns
+ * const appended = bands.extend(newBars); // only the bands for newBars
+ */
+export {
+  SimpleMovingAverageStream,
+  ExponentialMovingAverageStream,
+  SmoothedMovingAverageStream,
+  MedianStream,
+  ModeStream,
+  McGinleyDynamicStream,
+  KaufmanAdaptiveMovingAverageStream,
+  FractalAdaptiveMovingAverageStream,
+  VariableIndexDynamicAverageStream,
+  ArnaudLegouxMovingAverageStream,
+  TillsonT3Stream,
+} from "./dist/bundler/ti_engine";
+
+/**
+ * Streaming momentum oscillators: each `push` takes the next bar and returns the value the
+ * matching `momentumIndicators.bulk` function returns for it (NaN during warm-up), bit for bit.
+ * - RelativeStrengthIndexStream(constantModelType, period): `relativeStrengthIndex`
+ * - StochasticOscillatorStream(period): `stochasticOscillator`
+ * - SlowStochasticStream(stochasticPeriod, constantModelType, period) and
+ *   SlowestStochasticStream(stochasticPeriod, slowModel, slowPeriod, constantModelType, period):
+ *   `slowStochastic` / `slowestStochastic` chained on the stochastic oscillator of the prices
+ * - WilliamsPercentRStream(period): `push(high, low, close)`, `williamsPercentR`
+ * - MoneyFlowIndexStream(period): `push(price, volume)`, `moneyFlowIndex`
+ * - RateOfChangeStream(): `rateOfChange`
+ * - OnBalanceVolumeStream(previousOnBalanceVolume?): `push(price, volume)`, `onBalanceVolume`
+ * - CommodityChannelIndexStream(constantModelType, deviationModel, constantMultiplier, period) and
+ *   McGinleyDynamicCommodityChannelIndexStream(previousMcginleyDynamic, deviationModel,
+ *   constantMultiplier, period): `commodityChannelIndex` / `mcginleyDynamicCommodityChannelIndex`
+ * - MacdLineStream(shortPeriod, shortPeriodModel, longPeriod, longPeriodModel, signalPeriod?,
+ *   signalModel?): `macdLine`, plus `signal` (`signalLine` over the MACD, EMA by default)
+ * - McGinleyDynamicMacdLineStream(shortPeriod, previousShortMcginley, longPeriod,
+ *   previousLongMcginley): `mcginleyDynamicMacdLine`
+ * - ChaikinOscillatorStream(shortPeriod, longPeriod, previousAccumulationDistribution,
+ *   shortPeriodModel, longPeriodModel): `push(high, low, close, volume)`, `chaikinOscillator`
+ * - PercentagePriceOscillatorStream(shortPeriod, longPeriod, constantModelType):
+ *   `percentagePriceOscillator`
+ * - ChandeMomentumOscillatorStream(period): `chandeMomentumOscillator`
+ *
+ * Rolling maxima/minima, moving constants and McGinley values are kept between pushes; RSI, MFI,
+ * CCI, Chaikin and Chande evaluate their window (kept in WASM memory) like the bulk function.
+ * Secondary outputs are getters (`signal`, `mcginley`, `shortMcginley`, `longMcginley`,
+ * `accumulationDistribution`). The CCI streams' `push` throws on a non-positive price under
+ * `LogStandardDeviation`.
+ *
+ * @example
+ * const macd = new MacdLineStream(12, ConstantModelType.ExponentialMovingAverage, 26, ConstantModelType.ExponentialMovingAverage, 9);
+ * for (const price of ticks) {
+ *   macd.push(price);
+ *   draw(macd.value, macd.signal);
+ * }
+ */
+export {
+  RelativeStrengthIndexStream,
+  StochasticOscillatorStream,
+  SlowStochasticStream,
+  SlowestStochasticStream,
+  WilliamsPercentRStream,
+  MoneyFlowIndexStream,
+  RateOfChangeStream,
+  OnBalanceVolumeStream,
+  CommodityChannelIndexStream,
+  McGinleyDynamicCommodityChannelIndexStream,
+  MacdLineStream,
+  McGinleyDynamicMacdLineStream,
+  ChaikinOscillatorStream,
+  PercentagePriceOscillatorStream,
+  ChandeMomentumOscillatorStream,
+} from "./dist/bundler/ti_engine";
+
+/**
+ * Streaming channels and bands: each `push` takes the next bar and returns the point the matching
+ * `candleIndicators.bulk` function returns for it (NaN fields during warm-up), bit for bit.
+ * - MovingConstantEnvelopesStream(constantModelType, difference, period): `BandPoint`,
+ *   `movingConstantEnvelopes`
+ * - McGinleyDynamicEnvelopesStream(difference, previousMcginleyDynamic, period):
+ *   `McGinleyBandPoint`, `mcginleyDynamicEnvelopes`
+ * - MovingConstantBandsStream(constantModelType, deviationModel, deviationMultiplier, period):
+ *   `BandPoint`, `movingConstantBands`
+ * - McGinleyDynamicBandsStream(deviationModel, deviationMultiplier, previousMcginleyDynamic,
+ *   period): `McGinleyBandPoint`, `mcginleyDynamicBands`
+ * - IchimokuCloudStream(conversionPeriod, basePeriod, spanBPeriod): `push(high, low, close)`,
+ *   `IchimokuPoint`, `ichimokuCloud`
+ * - DonchianChannelsStream(period): `push(high, low)`, `BandPoint`, `donchianChannels`
+ * - KeltnerChannelStream(constantModelType, atrConstantModelType, multiplier, period):
+ *   `push(high, low, close)`, `BandPoint`, `keltnerChannel`
+ * - SupertrendStream(constantModelType, multiplier, period): `push(high, low, close)`, `BandPoint`
+ *   of the final lower band, the window midpoint and the final upper band, where the basic upper
+ *   band is the `supertrend` bulk value; the final bands and the trend (`position`, and the
+ *   `supertrend` line on the lower band when long and the upper band when short) carry over
+ *   between bars
+ *
+ * The McGinley dynamic, moving constants and rolling highs/lows are kept between pushes; the
+ * bands' deviation is taken over the window kept in WASM memory. The bands streams' `push` throws
+ * on a non-positive price under `LogStandardDeviation`.
+ *
+ * @example
+ * const keltner = new KeltnerChannelStream(ConstantModelType.ExponentialMovingAverage, ConstantModelType.SimpleMovingAverage, 2, 20);
+ * for (const { high, low, close } of bars) {
+ *   const { lower, middle, upper } = keltner.push(high, low, close);
+ * }
+ */
+export {
+  MovingConstantEnvelopesStream,
+  McGinleyDynamicEnvelopesStream,
+  MovingConstantBandsStream,
+  McGinleyDynamicBandsStream,
+  IchimokuCloudStream,
+  DonchianChannelsStream,
+  KeltnerChannelStream,
+  SupertrendStream,
+} from "./dist/bundler/ti_engine";
+
+/**
+ * Parabolic SAR with automatic reversals, matching `trendIndicators.bulk.parabolicTimePriceSystem`
+ * bar for bar: `new ParabolicSarStream(accelerationFactorStart, accelerationFactorMax,
+ * accelerationFactorStep, startPosition, previousSar?)`, then `push(high, low)` per bar.
+ *
+ * The stream tracks the position, acceleration factor and extreme point, and flips the position
+ * when price crosses the SAR. After each push it exposes `value` (the SAR), `position`,
+ * `accelerationFactor`, `extremePoint` and `reversal` (true on the bar that flipped).
+ *
+ * @example
+ * const sar = new ParabolicSarStream(0.02, 0.2, 0.02, Position.Long);
+ * for (const { high, low } of bars) {
+ *   sar.push(high, low);
+ *   if (sar.reversal) exitOrFlip(sar.position, sar.value);
+ * }
+ */
+export { ParabolicSarStream } from "./dist/bundler/ti_engine";
+
+/**
+ * Directional movement system, matching `trendIndicators.bulk.directionalMovementSystem` bar for
+ * bar: `new DirectionalMovementSystemStream(period, constantModelType)`, then
+ * `push(high, low, close)` per bar. Returns a `DirectionalMovementPoint` (`pdi`, `ndi`, `adx`,
+ * `adxr`), all NaN until `3 * period - 2` bars have been pushed, like the first bulk row.
+ *
+ * @example
+ * const dms = new DirectionalMovementSystemStream(14, ConstantModelType.SmoothedMovingAverage);
+ * for (const { high, low, close } of bars) {
+ *   const { pdi, ndi, adx } = dms.push(high, low, close);
+ *   if (adx > 25 && pdi > ndi) trendingUp();
+ * }
+ */
+export { DirectionalMovementSystemStream } from "./dist/bundler/ti_engine";
+
+/**
+ * On-balance volume, accumulation/distribution, positive and negative volume index and volume
+ * price trend in one stream: `new VolumeFlowStream(previousOnBalanceVolume?,
+ * previousAccumulationDistribution?, previousPositiveVolumeIndex?, previousNegativeVolumeIndex?,
+ * previousVolumePriceTrend?)`, then `push(high, low, close, volume)` per bar, returning a
+ * `VolumeFlowPoint`. Values match `momentumIndicators.bulk.onBalanceVolume`,
+ * `strengthIndicators.bulk.accumulationDistribution` / `positiveVolumeIndex` /
+ * `negativeVolumeIndex` and `trendIndicators.bulk.volumePriceTrend` (whose volumes start at the
+ * second bar). Accumulation/distribution is set from the first bar, the other fields from the second.
+ *
+ * `snapshot()` returns the running totals and previous close/volume as a plain `VolumeFlowSnapshot`
+ * object; `VolumeFlowStream.fromSnapshot(snapshot)` continues exactly where it left off.
+ *
+ * @example
+ * const flow = saved ? VolumeFlowStream.fromSnapshot(JSON.parse(saved)) : new VolumeFlowStream();
+ * for (const { high, low, close, volume } of bars) flow.push(high, low, close, volume);
+ * saved = JSON.stringify(flow.snapshot());
+ */
+export { VolumeFlowStream } from "./dist/bundler/ti_engine";
+
+/** State returned by `VolumeFlowStream.snapshot()`; missing totals default to 0. */
+export interface VolumeFlowSnapshot {
+  onBalanceVolume?: number;
+  accumulationDistribution?: number;
+  positiveVolumeIndex?: number;
+  negativeVolumeIndex?: number;
+  volumePriceTrend?: number;
+  /** Close and volume of the last pushed bar; given together or not at all. */
+  previousClose?: number;
+  previousVolume?: number;
+}
+
+/**
+ * Moving average with a custom smoothing factor: alpha = alphaNum / (window length + alphaDen).
+ * - `new PersonalisedModel(1, 0)`: same as Smoothed
+ * - `new PersonalisedModel(2, 1)`: same as Exponential
+ * - `PersonalisedModel.fromAlpha(alpha, period)`: exact alpha for windows of `period` values
+ *
+ * Throws a TiEngineError unless alphaNum > 0 and alphaDen > -1.
+ */
+export { PersonalisedModel } from "./dist/bundler/ti_engine";
+
+/** Plain-object form of a PersonalisedModel, validated the same way when passed to an indicator. */
+export interface PersonalisedModelOptions {
+  alphaNum: number;
+  alphaDen: number;
+}
+
+/** Accepted anywhere a ConstantModelType is: an enum value or a personalised model. */
+export type ConstantModelLike = ConstantModelType | PersonalisedModel | PersonalisedModelOptions;
+
+/** A ConstantModelLike or a ConstantModelFamily value, for the indicators that take the families. */
+export type FamilyModelLike = ConstantModelLike | ConstantModelFamily;
+
+/** Accepted anywhere a MovingAverageType is: an enum value or a personalised model. */
+export type MovingAverageLike = MovingAverageType | PersonalisedModel | PersonalisedModelOptions;
+
+/**
+ * Named-field results returned by the single `*Point` functions.
+ * Fields are read-only getters, e.g. `candleIndicators.single.ichimokuCloudPoint(...).spanA`.
+ * - BandPoint: lower, middle, upper
+ * - McGinleyBandPoint: lower, mcginley, upper
+ * - IchimokuPoint: spanA, spanB, base, conversion, laggingClose
+ * - McGinleyCommodityChannelIndexPoint: cci, mcginley
+ * - McGinleyMacdLinePoint: macd, shortMcginley, longMcginley
+ * - ChaikinOscillatorPoint: oscillator, accumulationDistribution
+ * - ReturnOnInvestmentPoint: finalValue, percentReturn
+ * - MacdPoint: macd, signal, histogram
+ * - AroonPoint: aroonUp, aroonDown, aroonOscillator
+ * - DirectionalMovementPoint: pdi, ndi, adx, adxr (from DirectionalMovementSystemStream)
+ * - VolumeFlowPoint: onBalanceVolume, accumulationDistribution, positiveVolumeIndex,
+ *   negativeVolumeIndex, volumePriceTrend (from VolumeFlowStream)
+ */
+export {
+  BandPoint,
+  McGinleyBandPoint,
+  IchimokuPoint,
+  McGinleyCommodityChannelIndexPoint,
+  McGinleyMacdLinePoint,
+  ChaikinOscillatorPoint,
+  ReturnOnInvestmentPoint,
+  MacdPoint,
+  AroonPoint,
+  DirectionalMovementPoint,
+  VolumeFlowPoint,
+} from "./dist/bundler/ti_engine";
+
+/**
+ * Option object for `chartTrends.breakDownTrendsWithConfig`.
+ * - `new TrendBreakConfig()`: RustTI defaults
+ * - `TrendBreakConfig.conservative()`: tolerates more noise, fewer and longer trends
+ * - `TrendBreakConfig.aggressive()`: breaks sooner, more and shorter trends
+ *
+ * Builder-style setters (`withMaxOutliers`, `withSoftRmseMultiplier`, `withSoftDurbinWatson(min, max)`, ...)
+ * return a new config and throw a TiEngineError for negative multipliers or min > max bounds.
+ */
+export { TrendBreakConfig } from "./dist/bundler/ti_engine";
+
+/**
+ * Error codes carried by a thrown TiEngineError.
+ * - EmptyInput: a required array was empty
+ * - LengthMismatch: arrays that must be aligned (e.g. highs/lows/close) differ in length,
+ *   or a fixed-window single function received the wrong number of values
+ * - PeriodTooLong: a period (or fixed window) is longer than the data
+ * - InvalidParameter: a parameter is out of range (e.g. period of 0, shortPeriod >= longPeriod)
+ * - MissingValue: a bulk input contains NaN while the `MissingData.Reject` policy is active
+ * - InvalidState: a `fromState` byte array has another format version (`values.version`,
+ *   `values.expected`), was written by another class, is truncated or holds inconsistent fields
+ *   (e.g. a period of 0 or above 2^32 - 1)
+ */
+export type TiEngineErrorCode =
+  | "EmptyInput"
+  | "LengthMismatch"
+  | "PeriodTooLong"
+  | "InvalidParameter"
+  | "MissingValue"
+  | "InvalidState";
+
+/**
+ * Error thrown by every function documented with `@throws`.
+ * Inputs are validated before the computation runs, so the WASM instance stays usable afterwards.
+ *
+ * @example
+ * try {
+ *   movingAverage.bulk.movingAverage([1, 2], MovingAverageType.Simple, 5);
+ * } catch (e) {
+ *   e.name;     // "TiEngineError"
+ *   e.code;     // "PeriodTooLong"
+ *   e.argument; // "period"
+ *   e.values;   // { period: 5, length: 2 }
+ * }
+ */
+export interface TiEngineError extends Error {
+  name: "TiEngineError";
+  code: TiEngineErrorCode;
+  /** Name of the offending argument, as spelled in these typings. */
+  argument: string;
+  /** Numeric values involved in the failed check. */
+  values: Record<string, number>;
+}
+
+/**
+ * Input-aligned bulk output (off by default).
+ *
+ * When enabled, every bulk function (including `*Columns` variants) left-pads its result with NaN
+ * up to the length of its first input array, so output index `i` is the value at input bar `i`.
+ * Bars before the first complete window are NaN (NaN tuples for multi-output functions).
+ *
+ * @example
+ * setAlignedOutput(true);
+ * standardIndicators.bulk.simpleMovingAverage([1, 2, 3, 4], 3); // Float64Array [NaN, NaN, 2, 3]
+ */
+export function setAlignedOutput(aligned: boolean): void;
+
+/** Whether bulk outputs are currently padded to the length of their input. */
+export function isAlignedOutput(): boolean;
+
+/**
+ * How bulk functions (including `*Columns` variants) treat missing values (NaN) in their inputs.
+ * - Passthrough: hand NaN to the indicator unchanged (default); it poisons every window containing it,
+ *   and every later value of recursive indicators
+ * - Reject: throw a TiEngineError with code `MissingValue`, the input in `argument` and `values.index`
+ * - Propagate: NaN only for outputs whose window includes a missing bar; each gap-free run of bars
+ *   is computed as its own series (runs shorter than the period are all NaN). Indicators seeded with
+ *   a `previous*` value carry their last value across the gap: the McGinley dynamic (and its
+ *   envelopes, bands, CCI and MACD), KAMA, FRAMA, VIDYA, OBV, accumulation/distribution (and the
+ *   Chaikin oscillator's), positive/negative volume index and volume price trend. The parabolic SAR
+ *   restarts from `previousSar` and `startPosition` after each gap, since its acceleration factor
+ *   and extreme point are not carried
+ * - Skip: drop missing bars, compute over the rest and return NaN at the dropped bars
+ * - ForwardFill: repeat the previous value, then Propagate any leading gap
+ * - Interpolate: fill linearly between the surrounding values, then Propagate gaps at either end
+ *
+ * Output lengths follow the usual bulk convention (`input length - lookback`), so results line up
+ * with the Passthrough output of gap-free data. `null` in a plain JS array becomes 0 when copied into
+ * WASM memory: map nulls to NaN first (`prices.map((v) => v ?? NaN)`).
+ */
+export { MissingData } from "./dist/bundler/ti_engine";
+
+/** Sets the missing-data policy used by every bulk function (default `MissingData.Passthrough`). */
+export function setMissingData(policy: MissingData): void;
+
+/** Current missing-data policy. */
+export function getMissingData(): MissingData;
+
+/**
+ * Calls `callback` with `policy` in effect and restores the previous policy afterwards, e.g.
+ * `withMissingData(MissingData.ForwardFill, () => movingAverage.bulk.movingAverage(prices, MovingAverageType.Simple, 20))`.
+ */
+export function withMissingData<T>(policy: MissingData, callback: () => T): T;
+
+/**
+ * Number of leading input bars a bulk indicator consumes before its first value,
+ * i.e. `input length - output length` (the NaN padding added by `setAlignedOutput(true)`).
+ * Fetch at least `lookback + 1` bars to get one value.
+ *
+ * @param indicator Namespaced bulk function name, e.g. `"momentumIndicators.macdLine"`.
+ *   `*Columns` variants share the lookback of their base function.
+ * @param params Period arguments of the bulk function under the same names
+ *   (`period`, `shortPeriod`/`longPeriod`, `conversionPeriod`/`basePeriod`/`spanBPeriod`,
+ *   `firstPeriod`/`secondPeriod`, `signalPeriod`); other arguments are ignored.
+ *   Omit for fixed-window indicators such as `"standardIndicators.macd"`.
+ * @throws {TiEngineError} InvalidParameter for an unknown indicator or a missing/invalid period.
+ *
+ * @example
+ * lookback("momentumIndicators.macdLine", { shortPeriod: 12, longPeriod: 26 }); // 25
+ * lookback("candleIndicators.ichimokuCloud", { conversionPeriod: 9, basePeriod: 26, spanBPeriod: 52 }); // 51
+ * lookback("trendIndicators.directionalMovementSystem", { period: 14 }); // 40
+ */
+export function lookback(indicator: string, params?: Record<string, number>): number;
+
+/** Positional arguments of a single or bulk function: input arrays first, then `params`. */
+export interface IndicatorSignature {
+  inputs: string[];
+  params: string[];
+  /** Input arrays that may be passed after the parameters (e.g. the volume of a VolumeWeighted average). */
+  optional: string[];
+}
+
+/** One parameter of an indicator, shared by its single and bulk functions. */
+export interface IndicatorParam {
+  name: string;
+  /**
+   * "integer" | "number", or the name of the enum to pass (ConstantModelType also accepts a
+   * PersonalisedModel, and ConstantModelFamily a FamilyModelLike).
+   */
+  type:
+    | "integer"
+    | "number"
+    | "ConstantModelType"
+    | "ConstantModelFamily"
+    | "DeviationModel"
+    | "MovingAverageType"
+    | "Position";
+  /** Conventional default; an enum variant name for enum parameters. Absent when there is none. */
+  default?: number | string;
+  min?: number;
+  max?: number;
+  /** Enum variant names for enum parameters (for ConstantModelFamily, the ConstantModelType names too). */
+  choices?: string[];
+}
+
+/** Registry entry describing an indicator of the namespaced API. */
+export interface IndicatorInfo {
+  /** Namespace, e.g. "momentumIndicators". */
+  module: string;
+  /** Function name within `single`/`bulk`, e.g. "relativeStrengthIndex". */
+  name: string;
+  /** Absent if there is no single function. */
+  single?: IndicatorSignature;
+  /** Absent if there is no bulk function. */
+  bulk?: IndicatorSignature;
+  params: IndicatorParam[];
+  /** Output field names: the `*Columns`/`*Point` keys, or the indicator name for single-valued outputs. */
+  outputs: string[];
+}
+
+/**
+ * Every indicator in the namespaced API (chartTrends excluded), for generating pickers and validators.
+ *
+ * @example
+ * for (const info of listIndicators()) {
+ *   const fn = api[info.module].bulk?.[info.name];
+ *   const defaults = info.bulk?.params.map((p) => info.params.find((q) => q.name === p).default);
+ * }
+ */
+export function listIndicators(): IndicatorInfo[];
+
+/**
+ * Registry entry of one indicator.
+ * @param indicator Namespaced name, e.g. `"trendIndicators.aroonIndicator"`.
+ * @throws {TiEngineError} InvalidParameter if the indicator is not registered.
+ */
+export function describeIndicator(indicator: string): IndicatorInfo;
+
+/** One entry of a `computeBatch` request. */
+export interface BatchSpec {
+  /** Namespaced bulk indicator, e.g. `"momentumIndicators.macdLine"`. */
+  indicator: string;
+  /** Key of the result (defaults to `indicator`); also how later specs refer to it in `inputs`. */
+  id?: string;
+  /**
+   * Bulk function arguments by name (see `describeIndicator`). Missing arguments use the registry
+   * default; enum arguments take the enum value or its variant name (`"SmoothedMovingAverage"`),
+   * and model arguments also take a `PersonalisedModel`-like `{ alphaNum, alphaDen }`.
+   */
+  params?: Record<string, number | string | PersonalisedModelOptions>;
+  /**
+   * Sources of the input arrays, in order: a column (`"open"`, `"high"`, `"low"`, `"close"`,
+   * `"volume"`) or the `id` of an earlier single-series result. By default `prices` is `close`,
+   * `highs`/`lows` are `high`/`low`, and `volumes` is `volume` without its first bar; inputs such
+   * as `stochastics` or `macds` must be named. Input names are the bulk inputs listed by
+   * `describeIndicator`. Without `inputs`, `otherIndicators.positivityIndicator` compares each
+   * `open` with the previous bar's `close`; with them, it takes `open` and `previousClose` as given.
+   */
+  inputs?: string[];
+  /** Missing-data policy for this spec only, as a `MissingData` value or its name. */
+  missingData?: MissingData | keyof typeof MissingData;
+}
+
+/** Candle columns for `computeBatch`; only the columns used by the specs are required. */
+export interface BatchSeries {
+  open?: ArrayLike<number>;
+  high?: ArrayLike<number>;
+  low?: ArrayLike<number>;
+  close?: ArrayLike<number>;
+  volume?: ArrayLike<number>;
+}
+
+/** `computeBatch` output: a `Float64Array` per single-output spec, named columns otherwise. */
+export type BatchResult = Record<string, Float64Array | Record<string, Float64Array>>;
+
+/**
+ * Runs several bulk indicators over one copy of the candles and returns the results by id.
+ * Multi-output indicators return their `*Columns` object. `OhlcvSeries.computeBatch(specs)` does
+ * the same over resident columns.
+ *
+ * @param specs An array of `BatchSpec`, or the same as a JSON string.
+ * @throws {TiEngineError} For an unknown indicator, a duplicate id, a missing input or any error of
+ *   the underlying bulk function; `values.spec` is the index of the failing spec.
+ *
+ * @example
+ * const out = computeBatch({ close, high, low }, [
+ *   { id: "rsi", indicator: "momentumIndicators.relativeStrengthIndex", params: { period: 14 } },
+ *   { id: "macd", indicator: "momentumIndicators.macdLine" },
+ *   { id: "signal", indicator: "momentumIndicators.signalLine", inputs: ["macd"] },
+ * ]);
+ * out.rsi; // Float64Array
+ */
+export function computeBatch(series: BatchSeries | OhlcvSeries, specs: BatchSpec[] | string): BatchResult;
+
+/** Values of a swept parameter: an explicit list, or `start` to `end` inclusive by `step` (default 1). */
+export type SweepAxis = Array<number | string | PersonalisedModelOptions> | { start: number; end: number; step?: number };
+
+/** A `sweep` request: a `computeBatch` spec plus the one or two parameters to vary. */
+export interface SweepSpec extends Omit<BatchSpec, "id"> {
+  sweep: Record<string, SweepAxis>;
+}
+
+/**
+ * Parameter × bar matrix returned by `sweep`.
+ * Row `i * axes[1].values.length + j` holds `axes[0].values[i]` and `axes[1].values[j]`;
+ * rows are left-padded with `NaN` so column `k` is bar `k`.
+ */
+export interface SweepResult {
+  values: Float64Array;
+  /** `[axis lengths..., bars]`. */
+  shape: number[];
+  axes: Array<{ name: string; values: Array<number | string | PersonalisedModelOptions> }>;
+}
+
+/**
+ * Runs one single-output bulk indicator for every combination of one or two swept parameters in a
+ * single call, e.g. an RSI for every period from 2 to 200. `OhlcvSeries.sweep(spec)` does the same
+ * over resident columns. The spec is parsed and its inputs resolved (and gap-filled, for
+ * `ForwardFill` / `Interpolate`) once, then shared by every row.
+ *
+ * @param series Candle columns, or a single price array used as `close`.
+ * @param spec A `SweepSpec`, or the same as a JSON string.
+ * @throws {TiEngineError} InvalidParameter for a multi-output indicator, an unknown swept parameter
+ *   or an empty range; errors of the bulk function carry the failing row in `values.row`.
+ *
+ * @example
+ * const { values, shape } = sweep(close, {
+ *   indicator: "momentumIndicators.relativeStrengthIndex",
+ *   sweep: { period: { start: 2, end: 200 } },
+ * });
+ * const [periods, bars] = shape;
+ * const rsi14 = values.subarray((14 - 2) * bars, (14 - 1) * bars);
+ */
+export function sweep(series: BatchSeries | OhlcvSeries | ArrayLike<number>, spec: SweepSpec | string): SweepResult;
+
+/**
+ * Candle columns for many symbols. Each column is either one flat `symbols × bars` array (row per
+ * symbol) with `shape: [symbols, bars]`, or an array of per-symbol arrays of any lengths.
+ * Only the columns used by the indicator are required.
+ */
+export interface Panel {
+  open?: ArrayLike<number> | ArrayLike<number>[];
+  high?: ArrayLike<number> | ArrayLike<number>[];
+  low?: ArrayLike<number> | ArrayLike<number>[];
+  close?: ArrayLike<number> | ArrayLike<number>[];
+  volume?: ArrayLike<number> | ArrayLike<number>[];
+  shape?: [number, number];
+}
+
+/**
+ * `computePanel` output: `symbols × bars` matrices, one row per symbol, right-aligned so the last
+ * column is each symbol's latest bar (shorter histories and warm-up bars are `NaN`).
+ * `values` for single-output indicators, `columns` (one matrix per output) otherwise.
+ */
+export interface PanelResult {
+  shape: [number, number];
+  values?: Float64Array;
+  columns?: Record<string, Float64Array>;
+}
+
+/**
+ * Runs one bulk indicator over every symbol of a panel in a single call, e.g. Bollinger Bands or
+ * Aroon for a whole screener universe.
+ *
+ * @param spec A `computeBatch` spec (`indicator`, `params`, `inputs`) or the same as a JSON string.
+ * @throws {TiEngineError} For inconsistent panel shapes or any error of the bulk function; the
+ *   failing symbol is in `values.symbol`.
+ *
+ * @example
+ * const { shape, columns } = computePanel(
+ *   { close: flatCloses, shape: [500, 250] },
+ *   { indicator: "standardIndicators.bollingerBands" }
+ * );
+ * const [symbols, bars] = shape;
+ * const lastUpper = (symbol) => columns.upper[symbol * bars + bars - 1];
+ */
+export function computePanel(panel: Panel, spec: Omit<BatchSpec, "id"> | string): PanelResult;
+
+/**
+ * Struct-of-arrays results returned by the `*Columns` bulk functions.
+ * Every field is a Float64Array of the same length (one entry per window, or per input bar when
+ * `setAlignedOutput(true)` is on).
+ */
+export interface BandsColumns {
+  lower: Float64Array;
+  middle: Float64Array;
+  upper: Float64Array;
+}
+
+export interface McGinleyBandsColumns {
+  lower: Float64Array;
+  mcginley: Float64Array;
+  upper: Float64Array;
+}
+
+export interface IchimokuCloudColumns {
+  spanA: Float64Array;
+  spanB: Float64Array;
+  base: Float64Array;
+  conversion: Float64Array;
+  displacedClose: Float64Array;
+}
+
+export interface McGinleyCommodityChannelIndexColumns {
+  cci: Float64Array;
+  mcginley: Float64Array;
+}
+
+export interface McGinleyMacdLineColumns {
+  macd: Float64Array;
+  shortMcginley: Float64Array;
+  longMcginley: Float64Array;
+}
+
+export interface ChaikinOscillatorColumns {
+  oscillator: Float64Array;
+  accumulationDistribution: Float64Array;
+}
+
+export interface ReturnOnInvestmentColumns {
+  finalValue: Float64Array;
+  percentReturn: Float64Array;
+}
+
+export interface PositivityIndicatorColumns {
+  positivityIndicator: Float64Array;
+  signal: Float64Array;
+}
+
+export interface MacdColumns {
+  macd: Float64Array;
+  signal: Float64Array;
+  histogram: Float64Array;
+}
+
+export interface AroonIndicatorColumns {
+  aroonUp: Float64Array;
+  aroonDown: Float64Array;
+  aroonOscillator: Float64Array;
+}
+
+export interface DirectionalMovementSystemColumns {
+  /** +DI */
+  pdi: Float64Array;
+  /** -DI */
+  ndi: Float64Array;
+  adx: Float64Array;
+  adxr: Float64Array;
+}
+
 /**
  * Initialize the WASM module.
  * - Web: required (fetches and instantiates the WebAssembly module)
@@ -68,10 +877,19 @@ export interface CandleIndicatorsSingle {
    */
   movingConstantEnvelopes(
     prices: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: FamilyModelLike,
     difference: number
   ): [number, number, number];
 
+  /**
+   * Same as `movingConstantEnvelopes`, returned as a `BandPoint` with named fields instead of a positional array.
+   */
+  movingConstantEnvelopesPoint(
+    prices: number[],
+    constantModelType: FamilyModelLike,
+    difference: number
+  ): BandPoint;
+
   /**
    * Envelopes around the McGinley Dynamic value.
    * @param prices Close prices for the full window.
@@ -85,6 +903,15 @@ export interface CandleIndicatorsSingle {
     previousMcGinleyDynamic: number
   ): [number, number, number];
 
+  /**
+   * Same as `mcginleyDynamicEnvelopes`, returned as a `McGinleyBandPoint` with named fields instead of a positional array.
+   */
+  mcginleyDynamicEnvelopesPoint(
+    prices: number[],
+    difference: number,
+    previousMcGinleyDynamic: number
+  ): McGinleyBandPoint;
+
   /**
    * Generalized bands (e.g., Bollinger Bands) around a moving constant using a deviation model.
    * @param prices Close prices for the full window.
@@ -95,11 +922,21 @@ export interface CandleIndicatorsSingle {
    */
   movingConstantBands(
     prices: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: FamilyModelLike,
     deviationModel: DeviationModel,
     deviationMultiplier: number
   ): [number, number, number];
 
+  /**
+   * Same as `movingConstantBands`, returned as a `BandPoint` with named fields instead of a positional array.
+   */
+  movingConstantBandsPoint(
+    prices: number[],
+    constantModelType: FamilyModelLike,
+    deviationModel: DeviationModel,
+    deviationMultiplier: number
+  ): BandPoint;
+
   /**
    * Bands around the McGinley Dynamic using a deviation model and multiplier.
    * @param prices Close prices for the full window.
@@ -115,6 +952,16 @@ export interface CandleIndicatorsSingle {
     previousMcGinleyDynamic: number
   ): [number, number, number];
 
+  /**
+   * Same as `mcginleyDynamicBands`, returned as a `McGinleyBandPoint` with named fields instead of a positional array.
+   */
+  mcginleyDynamicBandsPoint(
+    prices: number[],
+    deviationModel: DeviationModel,
+    deviationMultiplier: number,
+    previousMcGinleyDynamic: number
+  ): McGinleyBandPoint;
+
   /**
    * Ichimoku Cloud for a full window.
    * @param highs High prices.
@@ -134,6 +981,18 @@ export interface CandleIndicatorsSingle {
     spanBPeriod: number
   ): [number, number, number, number, number];
 
+  /**
+   * Same as `ichimokuCloud`, returned as an `IchimokuPoint` with named fields instead of a positional array.
+   */
+  ichimokuCloudPoint(
+    highs: number[],
+    lows: number[],
+    close: number[],
+    conversionPeriod: number,
+    basePeriod: number,
+    spanBPeriod: number
+  ): IchimokuPoint;
+
   /**
    * Donchian Channels for a full window.
    * @param highs High prices.
@@ -142,6 +1001,11 @@ export interface CandleIndicatorsSingle {
    */
   donchianChannels(highs: number[], lows: number[]): [number, number, number];
 
+  /**
+   * Same as `donchianChannels`, returned as a `BandPoint` with named fields instead of a positional array.
+   */
+  donchianChannelsPoint(highs: number[], lows: number[]): BandPoint;
+
   /**
    * Keltner Channel using ATR and a central moving constant.
    * @param highs High prices.
@@ -156,11 +1020,23 @@ export interface CandleIndicatorsSingle {
     highs: number[],
     lows: number[],
     close: number[],
-    constantModelType: ConstantModelType,
-    atrConstantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
+    atrConstantModelType: ConstantModelLike,
     multiplier: number
   ): [number, number, number];
 
+  /**
+   * Same as `keltnerChannel`, returned as a `BandPoint` with named fields instead of a positional array.
+   */
+  keltnerChannelPoint(
+    highs: number[],
+    lows: number[],
+    close: number[],
+    constantModelType: ConstantModelLike,
+    atrConstantModelType: ConstantModelLike,
+    multiplier: number
+  ): BandPoint;
+
   /**
    * Supertrend value for a full window.
    * @param highs High prices.
@@ -174,7 +1050,7 @@ export interface CandleIndicatorsSingle {
     highs: number[],
     lows: number[],
     close: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
     multiplier: number
   ): number;
 }
@@ -186,8 +1062,10 @@ export interface CandleIndicatorsSingle {
  * - Windowing: For inputs of length L and a window size N, the output length is L - N + 1 (when N applies).
  * - Start index: The first output corresponds to indices [0..N-1], the second to [1..N], and so on.
  * - Typed arrays: You may pass number[] or Float64Array (both are accepted).
+ * - Output type: One value per window is returned as a Float64Array, copied out of WASM memory in a single block.
+ *   Use Array.from(out) if a plain array is needed.
  * - OHLC alignment: Arrays for highs/lows/close must have equal length.
- * - Insufficient data: If the effective window exceeds input length, the result is an empty array.
+ * - Insufficient data: If the effective window exceeds input length, a TiEngineError with code "PeriodTooLong" is thrown.
  */
 export interface CandleIndicatorsBulk {
   /**
@@ -210,11 +1088,22 @@ export interface CandleIndicatorsBulk {
    */
   movingConstantEnvelopes(
     prices: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: FamilyModelLike,
     difference: number,
     period: number
   ): [number, number, number][];
 
+  /**
+   * Same as `movingConstantEnvelopes`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  movingConstantEnvelopesColumns(
+    prices: number[],
+    constantModelType: FamilyModelLike,
+    difference: number,
+    period: number
+  ): BandsColumns;
+
   /**
    * Rolling McGinley Dynamic envelopes (±percentage of McGinley value).
    *
@@ -241,6 +1130,17 @@ export interface CandleIndicatorsBulk {
     period: number
   ): [number, number, number][];
 
+  /**
+   * Same as `mcginleyDynamicEnvelopes`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  mcginleyDynamicEnvelopesColumns(
+    prices: number[],
+    difference: number,
+    previousMcGinleyDynamic: number,
+    period: number
+  ): McGinleyBandsColumns;
+
   /**
    * Rolling generalized bands (e.g., Bollinger) around a moving constant using a deviation model.
    *
@@ -271,12 +1171,24 @@ export interface CandleIndicatorsBulk {
    */
   movingConstantBands(
     prices: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: FamilyModelLike,
     deviationModel: DeviationModel,
     deviationMultiplier: number,
     period: number
   ): [number, number, number][];
 
+  /**
+   * Same as `movingConstantBands`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  movingConstantBandsColumns(
+    prices: number[],
+    constantModelType: FamilyModelLike,
+    deviationModel: DeviationModel,
+    deviationMultiplier: number,
+    period: number
+  ): BandsColumns;
+
   /**
    * Rolling McGinley Dynamic bands using a deviation model and multiplier.
    *
@@ -306,6 +1218,18 @@ export interface CandleIndicatorsBulk {
     period: number
   ): [number, number, number][];
 
+  /**
+   * Same as `mcginleyDynamicBands`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  mcginleyDynamicBandsColumns(
+    prices: number[],
+    deviationModel: DeviationModel,
+    deviationMultiplier: number,
+    previousMcGinleyDynamic: number,
+    period: number
+  ): McGinleyBandsColumns;
+
   /**
    * Rolling Ichimoku Cloud.
    *
@@ -337,6 +1261,19 @@ export interface CandleIndicatorsBulk {
     spanBPeriod: number
   ): [number, number, number, number, number][];
 
+  /**
+   * Same as `ichimokuCloud`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  ichimokuCloudColumns(
+    highs: number[],
+    lows: number[],
+    close: number[],
+    conversionPeriod: number,
+    basePeriod: number,
+    spanBPeriod: number
+  ): IchimokuCloudColumns;
+
   /**
    * Rolling Donchian Channels.
    *
@@ -361,6 +1298,16 @@ export interface CandleIndicatorsBulk {
     period: number
   ): [number, number, number][];
 
+  /**
+   * Same as `donchianChannels`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  donchianChannelsColumns(
+    highs: number[],
+    lows: number[],
+    period: number
+  ): BandsColumns;
+
   /**
    * Rolling Keltner Channel using ATR and a central moving constant.
    *
@@ -393,12 +1340,26 @@ export interface CandleIndicatorsBulk {
     highs: number[],
     lows: number[],
     close: number[],
-    constantModelType: ConstantModelType,
-    atrConstantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
+    atrConstantModelType: ConstantModelLike,
     multiplier: number,
     period: number
   ): [number, number, number][];
 
+  /**
+   * Same as `keltnerChannel`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  keltnerChannelColumns(
+    highs: number[],
+    lows: number[],
+    close: number[],
+    constantModelType: ConstantModelLike,
+    atrConstantModelType: ConstantModelLike,
+    multiplier: number,
+    period: number
+  ): BandsColumns;
+
   /**
    * Rolling Supertrend values.
    *
@@ -425,10 +1386,10 @@ export interface CandleIndicatorsBulk {
     highs: number[],
     lows: number[],
     close: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
     multiplier: number,
     period: number
-  ): number[];
+  ): Float64Array;
 }
 
 /**
@@ -509,6 +1470,19 @@ export interface ChartTrends {
     hardDurbinWatsonMin: number,
     hardDurbinWatsonMax: number
   ): [number, number, number, number][];
+
+  /**
+   * Same as `breakDownTrends`, configured with a TrendBreakConfig instead of nine positional numbers.
+   * @param prices Prices series.
+   * @param config Thresholds, e.g. `new TrendBreakConfig()` or `TrendBreakConfig.conservative()`.
+   * @returns Array of [startIndex, endIndex, slope, intercept].
+   * @throws If prices is empty, a multiplier is negative or a Durbin-Watson min exceeds its max.
+   *
+   * @example
+   * const config = TrendBreakConfig.aggressive().withMaxOutliers(1);
+   * const trends = chartTrends.breakDownTrendsWithConfig(prices, config);
+   */
+  breakDownTrendsWithConfig(prices: number[], config: TrendBreakConfig): [number, number, number, number][];
 }
 
 /**
@@ -522,13 +1496,11 @@ export interface CorrelationIndicatorsSingle {
    * @param constantModelType Central model for demeaning (SMA, EMA, etc.).
    * @param deviationModel Deviation model for scaling (StdDev, MAD, etc.).
    * @returns Correlation-like coefficient (unitless).
-   *
-   * Note: PersonalisedMovingAverage is not exposed in this JS API.
    */
   correlateAssetPrices(
     pricesAssetA: number[],
     pricesAssetB: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
     deviationModel: DeviationModel
   ): number;
 }
@@ -558,10 +1530,10 @@ export interface CorrelationIndicatorsBulk {
   correlateAssetPrices(
     pricesAssetA: number[],
     pricesAssetB: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
     deviationModel: DeviationModel,
     period: number
-  ): number[];
+  ): Float64Array;
 }
 
 /**
@@ -594,7 +1566,7 @@ export interface MomentumIndicatorsSingle {
    */
   relativeStrengthIndex(
     prices: number[],
-    constantModelType: ConstantModelType
+    constantModelType: FamilyModelLike
   ): number;
 
   /**
@@ -621,7 +1593,7 @@ export interface MomentumIndicatorsSingle {
    */
   slowStochastic(
     stochastics: number[],
-    constantModelType: ConstantModelType
+    constantModelType: ConstantModelLike
   ): number;
 
   /**
@@ -636,7 +1608,7 @@ export interface MomentumIndicatorsSingle {
    */
   slowestStochastic(
     slowStochastics: number[],
-    constantModelType: ConstantModelType
+    constantModelType: ConstantModelLike
   ): number;
 
   /**
@@ -708,7 +1680,7 @@ export interface MomentumIndicatorsSingle {
    */
   commodityChannelIndex(
     prices: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
     deviationModel: DeviationModel,
     constantMultiplier: number
   ): number;
@@ -733,6 +1705,16 @@ export interface MomentumIndicatorsSingle {
     constantMultiplier: number
   ): [number, number];
 
+  /**
+   * Same as `mcginleyDynamicCommodityChannelIndex`, returned as a `McGinleyCommodityChannelIndexPoint` with named fields instead of a positional array.
+   */
+  mcginleyDynamicCommodityChannelIndexPoint(
+    prices: number[],
+    previousMcginleyDynamic: number,
+    deviationModel: DeviationModel,
+    constantMultiplier: number
+  ): McGinleyCommodityChannelIndexPoint;
+
   /**
    * Calculates the MACD line for the full window.
    *
@@ -749,8 +1731,8 @@ export interface MomentumIndicatorsSingle {
   macdLine(
     prices: number[],
     shortPeriod: number,
-    shortPeriodModel: ConstantModelType,
-    longPeriodModel: ConstantModelType
+    shortPeriodModel: FamilyModelLike,
+    longPeriodModel: FamilyModelLike
   ): number;
 
   /**
@@ -763,7 +1745,7 @@ export interface MomentumIndicatorsSingle {
    * @returns Signal value.
    * @throws If macds is empty.
    */
-  signalLine(macds: number[], constantModelType: ConstantModelType): number;
+  signalLine(macds: number[], constantModelType: FamilyModelLike): number;
 
   /**
    * Calculates the McGinley Dynamic MACD for the full window.
@@ -786,6 +1768,16 @@ export interface MomentumIndicatorsSingle {
     previousLongMcginley: number
   ): [number, number, number];
 
+  /**
+   * Same as `mcginleyDynamicMacdLine`, returned as a `McGinleyMacdLinePoint` with named fields instead of a positional array.
+   */
+  mcginleyDynamicMacdLinePoint(
+    prices: number[],
+    shortPeriod: number,
+    previousShortMcginley: number,
+    previousLongMcginley: number
+  ): McGinleyMacdLinePoint;
+
   /**
    * Calculates the Chaikin Oscillator (CO) for the full window.
    *
@@ -813,10 +1805,24 @@ export interface MomentumIndicatorsSingle {
     volume: number[],
     shortPeriod: number,
     previousAccumulationDistribution: number,
-    shortPeriodModel: ConstantModelType,
-    longPeriodModel: ConstantModelType
+    shortPeriodModel: ConstantModelLike,
+    longPeriodModel: ConstantModelLike
   ): [number, number];
 
+  /**
+   * Same as `chaikinOscillator`, returned as a `ChaikinOscillatorPoint` with named fields instead of a positional array.
+   */
+  chaikinOscillatorPoint(
+    highs: number[],
+    lows: number[],
+    close: number[],
+    volume: number[],
+    shortPeriod: number,
+    previousAccumulationDistribution: number,
+    shortPeriodModel: ConstantModelLike,
+    longPeriodModel: ConstantModelLike
+  ): ChaikinOscillatorPoint;
+
   /**
    * Calculates the Percentage Price Oscillator (PPO) for the full window (%).
    *
@@ -832,7 +1838,7 @@ export interface MomentumIndicatorsSingle {
   percentagePriceOscillator(
     prices: number[],
     shortPeriod: number,
-    constantModelType: ConstantModelType
+    constantModelType: ConstantModelLike
   ): number;
 
   /**
@@ -869,9 +1875,9 @@ export interface MomentumIndicatorsBulk {
    */
   relativeStrengthIndex(
     prices: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: FamilyModelLike,
     period: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Rolling Stochastic Oscillator.
@@ -881,7 +1887,7 @@ export interface MomentumIndicatorsBulk {
    * @returns %K per window in [0, 100].
    * @throws If period > prices.length.
    */
-  stochasticOscillator(prices: number[], period: number): number[];
+  stochasticOscillator(prices: number[], period: number): Float64Array;
 
   /**
    * Rolling Slow Stochastic (smoothing of Stochastic Oscillator).
@@ -894,9 +1900,9 @@ export interface MomentumIndicatorsBulk {
    */
   slowStochastic(
     stochastics: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
     period: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Rolling Slowest Stochastic (smoothing of Slow Stochastic).
@@ -909,9 +1915,9 @@ export interface MomentumIndicatorsBulk {
    */
   slowestStochastic(
     slowStochastics: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
     period: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Rolling Williams %R.
@@ -928,7 +1934,7 @@ export interface MomentumIndicatorsBulk {
     low: number[],
     close: number[],
     period: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Rolling Money Flow Index (MFI).
@@ -939,7 +1945,7 @@ export interface MomentumIndicatorsBulk {
    * @returns MFI values per window.
    * @throws If period > prices.length or lengths mismatch.
    */
-  moneyFlowIndex(prices: number[], volume: number[], period: number): number[];
+  moneyFlowIndex(prices: number[], volume: number[], period: number): Float64Array;
 
   /**
    * Pairwise Rate of Change across the series.
@@ -948,7 +1954,7 @@ export interface MomentumIndicatorsBulk {
    * @returns Array of successive RoC values (length = prices.length - 1).
    * @throws If prices is empty.
    */
-  rateOfChange(prices: number[]): number[];
+  rateOfChange(prices: number[]): Float64Array;
 
   /**
    * Rolling On-Balance Volume (OBV).
@@ -963,7 +1969,7 @@ export interface MomentumIndicatorsBulk {
     prices: number[],
     volume: number[],
     previousOnBalanceVolume: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Rolling Commodity Channel Index (CCI).
@@ -978,11 +1984,11 @@ export interface MomentumIndicatorsBulk {
    */
   commodityChannelIndex(
     prices: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
     deviationModel: DeviationModel,
     constantMultiplier: number,
     period: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Rolling McGinley Dynamic CCI.
@@ -1006,6 +2012,18 @@ export interface MomentumIndicatorsBulk {
     period: number
   ): [number, number][];
 
+  /**
+   * Same as `mcginleyDynamicCommodityChannelIndex`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  mcginleyDynamicCommodityChannelIndexColumns(
+    prices: number[],
+    previousMcginleyDynamic: number,
+    deviationModel: DeviationModel,
+    constantMultiplier: number,
+    period: number
+  ): McGinleyCommodityChannelIndexColumns;
+
   /**
    * Rolling MACD line (short vs long window).
    *
@@ -1020,10 +2038,10 @@ export interface MomentumIndicatorsBulk {
   macdLine(
     prices: number[],
     shortPeriod: number,
-    shortPeriodModel: ConstantModelType,
+    shortPeriodModel: FamilyModelLike,
     longPeriod: number,
-    longPeriodModel: ConstantModelType
-  ): number[];
+    longPeriodModel: FamilyModelLike
+  ): Float64Array;
 
   /**
    * Rolling MACD signal line.
@@ -1036,9 +2054,9 @@ export interface MomentumIndicatorsBulk {
    */
   signalLine(
     macds: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: FamilyModelLike,
     period: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Rolling McGinley Dynamic MACD.
@@ -1062,6 +2080,18 @@ export interface MomentumIndicatorsBulk {
     previousLongMcginley: number
   ): [number, number, number][];
 
+  /**
+   * Same as `mcginleyDynamicMacdLine`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  mcginleyDynamicMacdLineColumns(
+    prices: number[],
+    shortPeriod: number,
+    previousShortMcginley: number,
+    longPeriod: number,
+    previousLongMcginley: number
+  ): McGinleyMacdLineColumns;
+
   /**
    * Rolling Chaikin Oscillator (CO).
    *
@@ -1088,10 +2118,26 @@ export interface MomentumIndicatorsBulk {
     shortPeriod: number,
     longPeriod: number,
     previousAccumulationDistribution: number,
-    shortPeriodModel: ConstantModelType,
-    longPeriodModel: ConstantModelType
+    shortPeriodModel: ConstantModelLike,
+    longPeriodModel: ConstantModelLike
   ): [number, number][];
 
+  /**
+   * Same as `chaikinOscillator`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  chaikinOscillatorColumns(
+    highs: number[],
+    lows: number[],
+    close: number[],
+    volume: number[],
+    shortPeriod: number,
+    longPeriod: number,
+    previousAccumulationDistribution: number,
+    shortPeriodModel: ConstantModelLike,
+    longPeriodModel: ConstantModelLike
+  ): ChaikinOscillatorColumns;
+
   /**
    * Rolling Percentage Price Oscillator (PPO) (%).
    *
@@ -1106,8 +2152,8 @@ export interface MomentumIndicatorsBulk {
     prices: number[],
     shortPeriod: number,
     longPeriod: number,
-    constantModelType: ConstantModelType
-  ): number[];
+    constantModelType: ConstantModelLike
+  ): Float64Array;
 
   /**
    * Rolling Chande Momentum Oscillator (CMO).
@@ -1117,7 +2163,7 @@ export interface MomentumIndicatorsBulk {
    * @returns CMO values per window (range [-100, 100]).
    * @throws If prices is empty or period > prices.length.
    */
-  chandeMomentumOscillator(prices: number[], period: number): number[];
+  chandeMomentumOscillator(prices: number[], period: number): Float64Array;
 }
 
 /**
@@ -1144,6 +2190,15 @@ export interface OtherIndicatorsSingle {
     investment: number
   ): [number, number];
 
+  /**
+   * Same as `returnOnInvestment`, returned as a `ReturnOnInvestmentPoint` with named fields instead of a positional array.
+   */
+  returnOnInvestmentPoint(
+    startPrice: number,
+    endPrice: number,
+    investment: number
+  ): ReturnOnInvestmentPoint;
+
   /**
    * Calculates the True Range (TR).
    *
@@ -1177,7 +2232,7 @@ export interface OtherIndicatorsSingle {
     close: number[],
     high: number[],
     low: number[],
-    constantModelType: ConstantModelType
+    constantModelType: ConstantModelLike
   ): number;
 
   /**
@@ -1214,6 +2269,12 @@ export interface OtherIndicatorsBulk {
    */
   returnOnInvestment(prices: number[], investment: number): [number, number][];
 
+  /**
+   * Same as `returnOnInvestment`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  returnOnInvestmentColumns(prices: number[], investment: number): ReturnOnInvestmentColumns;
+
   /**
    * Vectorized True Range for each bar.
    *
@@ -1223,7 +2284,7 @@ export interface OtherIndicatorsBulk {
    * @returns TR per bar.
    * @throws If arrays are empty or lengths differ.
    */
-  trueRange(close: number[], high: number[], low: number[]): number[];
+  trueRange(close: number[], high: number[], low: number[]): Float64Array;
 
   /**
    * Rolling Average True Range (ATR).
@@ -1240,9 +2301,9 @@ export interface OtherIndicatorsBulk {
     close: number[],
     high: number[],
     low: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
     period: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Vectorized Internal Bar Strength (IBS).
@@ -1253,7 +2314,7 @@ export interface OtherIndicatorsBulk {
    * @returns IBS per bar.
    * @throws If arrays are empty or lengths differ.
    */
-  internalBarStrength(high: number[], low: number[], close: number[]): number[];
+  internalBarStrength(high: number[], low: number[], close: number[]): Float64Array;
 
   /**
    * Positivity Indicator and its signal line.
@@ -1274,8 +2335,19 @@ export interface OtherIndicatorsBulk {
     open: number[],
     previousClose: number[],
     signalPeriod: number,
-    constantModelType: ConstantModelType
+    constantModelType: ConstantModelLike
   ): [number, number][];
+
+  /**
+   * Same as `positivityIndicator`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  positivityIndicatorColumns(
+    open: number[],
+    previousClose: number[],
+    signalPeriod: number,
+    constantModelType: ConstantModelLike
+  ): PositivityIndicatorColumns;
 }
 
 /**
@@ -1321,6 +2393,11 @@ export interface StandardIndicatorsSingle {
    */
   bollingerBands(prices: number[]): [number, number, number];
 
+  /**
+   * Same as `bollingerBands`, returned as a `BandPoint` with named fields instead of a positional array.
+   */
+  bollingerBandsPoint(prices: number[]): BandPoint;
+
   /**
    * Calculates standard MACD, signal, and histogram.
    * Defaults: MACD = EMA(12) - EMA(26); signal = EMA(9) of MACD.
@@ -1331,6 +2408,11 @@ export interface StandardIndicatorsSingle {
    */
   macd(prices: number[]): [number, number, number];
 
+  /**
+   * Same as `macd`, returned as a `MacdPoint` with named fields instead of a positional array.
+   */
+  macdPoint(prices: number[]): MacdPoint;
+
   /**
    * Calculates standard RSI (period 14) using Smoothed Moving Average.
    * @param prices Slice of prices. Must be exactly length 14.
@@ -1351,7 +2433,7 @@ export interface StandardIndicatorsBulk {
    * @returns SMA per window.
    * @throws If period > prices.length.
    */
-  simpleMovingAverage(prices: number[], period: number): number[];
+  simpleMovingAverage(prices: number[], period: number): Float64Array;
 
   /**
    * Rolling Smoothed MA.
@@ -1360,7 +2442,7 @@ export interface StandardIndicatorsBulk {
    * @returns Smoothed MA per window.
    * @throws If period > prices.length.
    */
-  smoothedMovingAverage(prices: number[], period: number): number[];
+  smoothedMovingAverage(prices: number[], period: number): Float64Array;
 
   /**
    * Rolling EMA.
@@ -1369,7 +2451,7 @@ export interface StandardIndicatorsBulk {
    * @returns EMA per window.
    * @throws If period > prices.length.
    */
-  exponentialMovingAverage(prices: number[], period: number): number[];
+  exponentialMovingAverage(prices: number[], period: number): Float64Array;
 
   /**
    * Rolling standard Bollinger Bands (SMA center, ±2 StdDev) with a 20-bar window.
@@ -1379,6 +2461,12 @@ export interface StandardIndicatorsBulk {
    */
   bollingerBands(prices: number[]): [number, number, number][];
 
+  /**
+   * Same as `bollingerBands`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  bollingerBandsColumns(prices: number[]): BandsColumns;
+
   /**
    * Rolling standard MACD (12/26 EMA, 9 EMA signal).
    * Produces one [macd, signal, histogram] per 34-bar window.
@@ -1388,13 +2476,19 @@ export interface StandardIndicatorsBulk {
    */
   macd(prices: number[]): [number, number, number][];
 
+  /**
+   * Same as `macd`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  macdColumns(prices: number[]): MacdColumns;
+
   /**
    * Rolling standard RSI (period 14, Smoothed MA).
    * @param prices Prices. Must be at least length 14.
    * @returns RSI per 14-bar window.
    * @throws If prices.length < 14.
    */
-  rsi(prices: number[]): number[];
+  rsi(prices: number[]): Float64Array;
 }
 
 /**
@@ -1453,7 +2547,7 @@ export interface StrengthIndicatorsSingle {
     high: number[],
     low: number[],
     close: number[],
-    constantModelType: ConstantModelType
+    constantModelType: ConstantModelLike
   ): number;
 }
 
@@ -1472,7 +2566,7 @@ export interface StrengthIndicatorsBulk {
     close: number[],
     volume: number[],
     previousAccumulationDistribution: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Positive Volume Index (PVI).
@@ -1484,7 +2578,7 @@ export interface StrengthIndicatorsBulk {
     close: number[],
     volume: number[],
     previousPositiveVolumeIndex: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Negative Volume Index (NVI).
@@ -1496,7 +2590,7 @@ export interface StrengthIndicatorsBulk {
     close: number[],
     volume: number[],
     previousNegativeVolumeIndex: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Rolling Relative Vigor Index (RVI) over a window.
@@ -1514,9 +2608,9 @@ export interface StrengthIndicatorsBulk {
     high: number[],
     low: number[],
     close: number[],
-    constantModelType: ConstantModelType,
+    constantModelType: ConstantModelLike,
     period: number
-  ): number[];
+  ): Float64Array;
 }
 
 /**
@@ -1557,6 +2651,11 @@ export interface TrendIndicatorsSingle {
    */
   aroonIndicator(highs: number[], lows: number[]): [number, number, number];
 
+  /**
+   * Same as `aroonIndicator`, returned as an `AroonPoint` with named fields instead of a positional array.
+   */
+  aroonIndicatorPoint(highs: number[], lows: number[]): AroonPoint;
+
   /**
    * Long Parabolic Time Price System (SAR) step.
    * sar = min(previousSar + af*(ep - previousSar), low).
@@ -1618,9 +2717,9 @@ export interface TrendIndicatorsSingle {
    */
   trueStrengthIndex(
     prices: number[],
-    firstConstantModel: ConstantModelType,
+    firstConstantModel: ConstantModelLike,
     firstPeriod: number,
-    secondConstantModel: ConstantModelType
+    secondConstantModel: ConstantModelLike
   ): number;
 }
 
@@ -1635,7 +2734,7 @@ export interface TrendIndicatorsBulk {
    * @returns Aroon Up per window.
    * @throws If period > highs.length.
    */
-  aroonUp(highs: number[], period: number): number[];
+  aroonUp(highs: number[], period: number): Float64Array;
 
   /**
    * Rolling Aroon Down.
@@ -1644,7 +2743,7 @@ export interface TrendIndicatorsBulk {
    * @returns Aroon Down per window.
    * @throws If period > lows.length.
    */
-  aroonDown(lows: number[], period: number): number[];
+  aroonDown(lows: number[], period: number): Float64Array;
 
   /**
    * Rolling Aroon Oscillator (element-wise aroonUp - aroonDown).
@@ -1653,7 +2752,7 @@ export interface TrendIndicatorsBulk {
    * @returns Oscillator series.
    * @throws If lengths differ.
    */
-  aroonOscillator(aroonUp: number[], aroonDown: number[]): number[];
+  aroonOscillator(aroonUp: number[], aroonDown: number[]): Float64Array;
 
   /**
    * Rolling Aroon Indicator.
@@ -1669,6 +2768,16 @@ export interface TrendIndicatorsBulk {
     period: number
   ): [number, number, number][];
 
+  /**
+   * Same as `aroonIndicator`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  aroonIndicatorColumns(
+    highs: number[],
+    lows: number[],
+    period: number
+  ): AroonIndicatorColumns;
+
   /**
    * Rolling Parabolic Time Price System (Wilder's SAR variant).
    * Handles trend switches and AF updates internally, returns SAR per bar.
@@ -1690,7 +2799,7 @@ export interface TrendIndicatorsBulk {
     accelerationFactorStep: number,
     startPosition: Position,
     previousSar: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Directional Movement System (+DI, -DI, ADX, ADXR).
@@ -1707,9 +2816,21 @@ export interface TrendIndicatorsBulk {
     lows: number[],
     close: number[],
     period: number,
-    constantModelType: ConstantModelType
+    constantModelType: ConstantModelLike
   ): [number, number, number, number][];
 
+  /**
+   * Same as `directionalMovementSystem`, with one Float64Array per output instead of one tuple per window.
+   * Columns are aligned by index and ready to pass to charting libraries.
+   */
+  directionalMovementSystemColumns(
+    highs: number[],
+    lows: number[],
+    close: number[],
+    period: number,
+    constantModelType: ConstantModelLike
+  ): DirectionalMovementSystemColumns;
+
   /**
    * Rolling Volume Price Trend (VPT).
    * @param prices Price series (length L).
@@ -1722,7 +2843,7 @@ export interface TrendIndicatorsBulk {
     prices: number[],
     volumes: number[],
     previousVolumePriceTrend: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Rolling True Strength Index (TSI).
@@ -1736,11 +2857,11 @@ export interface TrendIndicatorsBulk {
    */
   trueStrengthIndex(
     prices: number[],
-    firstConstantModel: ConstantModelType,
+    firstConstantModel: ConstantModelLike,
     firstPeriod: number,
-    secondConstantModel: ConstantModelType,
+    secondConstantModel: ConstantModelLike,
     secondPeriod: number
-  ): number[];
+  ): Float64Array;
 }
 
 /**
@@ -1773,7 +2894,7 @@ export interface VolatilityIndicatorsBulk {
    * @returns Ulcer Index per window.
    * @throws If period > prices.length.
    */
-  ulcerIndex(prices: number[], period: number): number[];
+  ulcerIndex(prices: number[], period: number): Float64Array;
 
   /**
    * Welles Wilder's volatility system.
@@ -1801,8 +2922,8 @@ export interface VolatilityIndicatorsBulk {
     close: number[],
     period: number,
     constantMultiplier: number,
-    constantModelType: ConstantModelType
-  ): number[];
+    constantModelType: ConstantModelLike
+  ): Float64Array;
 }
 
 /**
@@ -1812,11 +2933,22 @@ export interface MovingAverageSingle {
   /**
    * Moving Average over the full window with the selected type.
    * @param prices Prices.
-   * @param maType Moving average type (Simple, Smoothed, Exponential).
+   * @param maType Moving average type (see `MovingAverageType`).
+   * @param volume Volume per price (same length), required by `VolumeWeighted` and rejected by
+   *   the other types.
    * @returns Average value.
-   * @throws If prices is empty.
+   * @throws If prices is empty, or volume is missing, unexpected or of another length.
+   */
+  movingAverage(prices: number[], maType: MovingAverageLike, volume?: number[]): number;
+
+  /**
+   * Volume-weighted moving average: sum(price * volume) / sum(volume) over the full window.
+   * @param prices Prices.
+   * @param volume Volume per price (same length).
+   * @returns Average value, NaN if the volumes add up to 0.
+   * @throws If prices is empty or the lengths differ.
    */
-  movingAverage(prices: number[], maType: MovingAverageType): number;
+  volumeWeightedMovingAverage(prices: number[], volume: number[]): number;
 
   /**
    * McGinley Dynamic (single step or full-window last value semantics per Rust implementation).
@@ -1831,6 +2963,78 @@ export interface MovingAverageSingle {
     previousMcginleyDynamic: number,
     period: number
   ): number;
+
+  /**
+   * Kaufman Adaptive Moving Average (KAMA): the previous KAMA moved toward the latest price by
+   * (ER * (2 / (fastPeriod + 1) - 2 / (slowPeriod + 1)) + 2 / (slowPeriod + 1))^2, where the
+   * efficiency ratio ER = |last - first| / sum(|price changes|) over `prices` (0 if flat).
+   * @param prices Prices, oldest first; an efficiency ratio period of n takes n + 1 prices.
+   * @param previousKama Previous KAMA (0.0 if none, which returns the latest price).
+   * @param fastPeriod Fastest EMA period (> 0, typically 2).
+   * @param slowPeriod Slowest EMA period (> fastPeriod, typically 30).
+   * @returns KAMA after the latest price.
+   * @throws If prices has fewer than 2 values, fastPeriod == 0 or fastPeriod >= slowPeriod.
+   */
+  kaufmanAdaptiveMovingAverage(
+    prices: number[],
+    previousKama: number,
+    fastPeriod: number,
+    slowPeriod: number
+  ): number;
+
+  /**
+   * Fractal Adaptive Moving Average (FRAMA, Ehlers): the previous FRAMA moved toward the latest
+   * price by alpha = exp(-4.6 * (D - 1)), kept within [0.01, 1], where D is the fractal dimension
+   * of `prices` from the ranges of its two halves (1 for a flat window, so alpha = 1).
+   * @param prices Prices, oldest first (even length: the window is split in two halves).
+   * @param previousFrama Previous FRAMA (0.0 if none, which returns the latest price).
+   * @returns FRAMA after the latest price.
+   * @throws If prices is empty or has an odd length.
+   */
+  fractalAdaptiveMovingAverage(prices: number[], previousFrama: number): number;
+
+  /**
+   * Variable Index Dynamic Average (VIDYA, Chande): the previous VIDYA moved toward the latest
+   * price by 2 / (period + 1) * |CMO| / 100, where CMO is the Chande momentum oscillator of the
+   * price changes over `prices` (0 if flat).
+   * @param prices Prices, oldest first; a CMO period of n takes n + 1 prices.
+   * @param previousVidya Previous VIDYA (0.0 if none, which returns the latest price).
+   * @param period Smoothing period (> 0).
+   * @returns VIDYA after the latest price.
+   * @throws If prices has fewer than 2 values or period == 0.
+   */
+  variableIndexDynamicAverage(
+    prices: number[],
+    previousVidya: number,
+    period: number
+  ): number;
+
+  /**
+   * Arnaud Legoux Moving Average (ALMA) over the full window: Gaussian weights centred at
+   * offset * (n - 1) (0 = oldest, 1 = newest) with a width of n / sigma.
+   * @param prices Prices, oldest first.
+   * @param offset Position of the weight peak in [0, 1] (typically 0.85).
+   * @param sigma Sharpness (> 0, typically 6; larger is narrower).
+   * @returns ALMA value.
+   * @throws If prices is empty, offset is outside [0, 1] or sigma <= 0.
+   */
+  arnaudLegouxMovingAverage(
+    prices: number[],
+    offset: number,
+    sigma: number
+  ): number;
+
+  /**
+   * Tillson T3 over the full window: c1 * e6 + c2 * e5 + c3 * e4 + c4 * e3 of six cascaded EMAs
+   * (each over the previous one, RustTI's EMA of the window length), with the coefficients of
+   * `volumeFactor` a: c1 = -a^3, c2 = 3a^2 + 3a^3, c3 = -6a^2 - 3a - 3a^3,
+   * c4 = 1 + 3a + 3a^2 + a^3. Like DEMA and TEMA it can overshoot the prices.
+   * @param prices Prices, oldest first.
+   * @param volumeFactor Volume factor a in [0, 1] (typically 0.7; 0 is the triple EMA cascade).
+   * @returns T3 value.
+   * @throws If prices is empty or volumeFactor is outside [0, 1].
+   */
+  tillsonT3(prices: number[], volumeFactor: number): number;
 }
 
 /**
@@ -1840,16 +3044,33 @@ export interface MovingAverageBulk {
   /**
    * Rolling Moving Average.
    * @param prices Prices.
-   * @param maType Moving average type (Simple, Smoothed, Exponential).
+   * @param maType Moving average type (see `MovingAverageType`).
    * @param period Window length.
+   * @param volume Volume per price (same length), required by `VolumeWeighted` and rejected by
+   *   the other types.
    * @returns Average value per window.
-   * @throws If period > prices.length.
+   * @throws If period > prices.length, or volume is missing, unexpected or of another length.
    */
   movingAverage(
     prices: number[],
-    maType: MovingAverageType,
+    maType: MovingAverageLike,
+    period: number,
+    volume?: number[]
+  ): Float64Array;
+
+  /**
+   * Rolling volume-weighted moving average.
+   * @param prices Prices.
+   * @param volume Volume per price (same length).
+   * @param period Window length.
+   * @returns Average value per window, NaN where the volumes add up to 0.
+   * @throws If the lengths differ or period > prices.length.
+   */
+  volumeWeightedMovingAverage(
+    prices: number[],
+    volume: number[],
     period: number
-  ): number[];
+  ): Float64Array;
 
   /**
    * Rolling McGinley Dynamic, chaining previous value through the window sequence.
@@ -1863,7 +3084,89 @@ export interface MovingAverageBulk {
     prices: number[],
     previousMcginleyDynamic: number,
     period: number
-  ): number[];
+  ): Float64Array;
+
+  /**
+   * Rolling Kaufman Adaptive Moving Average, chaining the previous KAMA through the windows of
+   * `period + 1` prices (see `MovingAverageSingle.kaufmanAdaptiveMovingAverage`).
+   * @param prices Prices.
+   * @param previousKama Seed KAMA (0.0 if none, so the first value is the price).
+   * @param period Efficiency ratio period (> 0, typically 10).
+   * @param fastPeriod Fastest EMA period (> 0, typically 2).
+   * @param slowPeriod Slowest EMA period (> fastPeriod, typically 30).
+   * @returns One KAMA per price after the first `period` (length prices.length - period).
+   * @throws If period == 0, prices.length <= period, fastPeriod == 0 or
+   * fastPeriod >= slowPeriod.
+   */
+  kaufmanAdaptiveMovingAverage(
+    prices: number[],
+    previousKama: number,
+    period: number,
+    fastPeriod: number,
+    slowPeriod: number
+  ): Float64Array;
+
+  /**
+   * Rolling Fractal Adaptive Moving Average, chaining the previous FRAMA through the windows of
+   * `period` prices (see `MovingAverageSingle.fractalAdaptiveMovingAverage`).
+   * @param prices Prices.
+   * @param previousFrama Seed FRAMA (0.0 if none, so the first value is the price).
+   * @param period Window length (even and > 0, typically 16).
+   * @returns FRAMA values per window.
+   * @throws If period is 0 or odd, or period > prices.length.
+   */
+  fractalAdaptiveMovingAverage(
+    prices: number[],
+    previousFrama: number,
+    period: number
+  ): Float64Array;
+
+  /**
+   * Rolling Variable Index Dynamic Average, chaining the previous VIDYA through the windows of
+   * `cmoPeriod + 1` prices (see `MovingAverageSingle.variableIndexDynamicAverage`).
+   * @param prices Prices.
+   * @param previousVidya Seed VIDYA (0.0 if none, so the first value is the price).
+   * @param period Smoothing period (> 0, typically 14).
+   * @param cmoPeriod Chande momentum oscillator period (> 0, typically 9).
+   * @returns One VIDYA per price after the first `cmoPeriod` (length prices.length - cmoPeriod).
+   * @throws If period or cmoPeriod is 0, or prices.length <= cmoPeriod.
+   */
+  variableIndexDynamicAverage(
+    prices: number[],
+    previousVidya: number,
+    period: number,
+    cmoPeriod: number
+  ): Float64Array;
+
+  /**
+   * Rolling Arnaud Legoux Moving Average.
+   * @param prices Prices.
+   * @param period Window length (typically 9).
+   * @param offset Position of the weight peak in [0, 1] (typically 0.85).
+   * @param sigma Sharpness (> 0, typically 6).
+   * @returns ALMA value per window.
+   * @throws If period > prices.length, offset is outside [0, 1] or sigma <= 0.
+   */
+  arnaudLegouxMovingAverage(
+    prices: number[],
+    period: number,
+    offset: number,
+    sigma: number
+  ): Float64Array;
+
+  /**
+   * Rolling Tillson T3 (see `MovingAverageSingle.tillsonT3`).
+   * @param prices Prices.
+   * @param period Window length (typically 5).
+   * @param volumeFactor Volume factor in [0, 1] (typically 0.7).
+   * @returns T3 value per window.
+   * @throws If period > prices.length or volumeFactor is outside [0, 1].
+   */
+  tillsonT3(
+    prices: number[],
+    period: number,
+    volumeFactor: number
+  ): Float64Array;
 }
 
 export const candleIndicators: {
@@ -2,28 +2,144 @@
 import init, * as wasm from "./dist/bundler/ti_engine.js";
 
 // Re-export enums
-export const { ConstantModelType, DeviationModel, Position, MovingAverageType } = wasm;
+export const { ConstantModelType, ConstantModelFamily, DeviationModel, Position, MovingAverageType } = wasm;
+
+// Named-field results returned by the single `*Point` functions
+export const {
+  BandPoint,
+  McGinleyBandPoint,
+  IchimokuPoint,
+  McGinleyCommodityChannelIndexPoint,
+  McGinleyMacdLinePoint,
+  ChaikinOscillatorPoint,
+  ReturnOnInvestmentPoint,
+  MacdPoint,
+  AroonPoint,
+  DirectionalMovementPoint,
+  VolumeFlowPoint,
+} = wasm;
+
+// Option object for chartTrends.breakDownTrendsWithConfig
+export const { TrendBreakConfig } = wasm;
+
+// Candles copied into WASM memory once, with a method per bulk indicator
+export const { OhlcvSeries } = wasm;
+
+// Custom-alpha model accepted wherever a ConstantModelType or MovingAverageType is
+export const { PersonalisedModel } = wasm;
+
+// Stateful moving averages: push(price) per tick, same values as the bulk functions
+export const {
+  SimpleMovingAverageStream,
+  ExponentialMovingAverageStream,
+  SmoothedMovingAverageStream,
+  MedianStream,
+  ModeStream,
+  McGinleyDynamicStream,
+  KaufmanAdaptiveMovingAverageStream,
+  FractalAdaptiveMovingAverageStream,
+  VariableIndexDynamicAverageStream,
+  ArnaudLegouxMovingAverageStream,
+  TillsonT3Stream,
+} = wasm;
+
+// Stateful momentum oscillators: push(bar) per tick, same values as momentumIndicators.bulk
+export const {
+  RelativeStrengthIndexStream,
+  StochasticOscillatorStream,
+  SlowStochasticStream,
+  SlowestStochasticStream,
+  WilliamsPercentRStream,
+  MoneyFlowIndexStream,
+  RateOfChangeStream,
+  OnBalanceVolumeStream,
+  CommodityChannelIndexStream,
+  McGinleyDynamicCommodityChannelIndexStream,
+  MacdLineStream,
+  McGinleyDynamicMacdLineStream,
+  ChaikinOscillatorStream,
+  PercentagePriceOscillatorStream,
+  ChandeMomentumOscillatorStream,
+} = wasm;
+
+// Stateful channels and bands: push(bar) per tick, same points as candleIndicators.bulk
+export const {
+  MovingConstantEnvelopesStream,
+  McGinleyDynamicEnvelopesStream,
+  MovingConstantBandsStream,
+  McGinleyDynamicBandsStream,
+  IchimokuCloudStream,
+  DonchianChannelsStream,
+  KeltnerChannelStream,
+  SupertrendStream,
+} = wasm;
+
+// Stateful trend indicators: push(bar) per tick, same values as trendIndicators.bulk
+export const {
+  ParabolicSarStream,
+  DirectionalMovementSystemStream,
+} = wasm;
+
+// Running volume totals (OBV, A/D, PVI, NVI, VPT): push(bar) per tick, snapshot()/fromSnapshot to resume
+export const { VolumeFlowStream } = wasm;
+
+// NaN-padded, input-aligned bulk output (global setting)
+export const { setAlignedOutput, isAlignedOutput } = wasm;
+
+// Missing-value (NaN) policy of bulk functions: global, or scoped with withMissingData(policy, () => ...)
+export const { MissingData, setMissingData, getMissingData, withMissingData } = wasm;
+
+// Warm-up length of a bulk indicator, e.g. lookback("momentumIndicators.macdLine", { longPeriod: 26 })
+export const { lookback } = wasm;
+
+// Indicator metadata (inputs, parameters, defaults, ranges, outputs) for generated UIs
+export const { listIndicators, describeIndicator } = wasm;
+
+// Many bulk indicators over one copy of the candles, from declarative specs (objects or JSON)
+export const { computeBatch } = wasm;
+
+// One bulk indicator over a range of one or two parameters, as a flat parameter x bar matrix
+export const { sweep } = wasm;
+
+// One bulk indicator over many symbols (flat symbols x bars matrix or per-symbol arrays)
+export const { computePanel } = wasm;
 
 // Natural JS/TS namespace mirroring rust_ti::candle_indicators::{single, bulk}
 export const candleIndicators = {
   single: {
     movingConstantEnvelopes: wasm.candle_single_movingConstantEnvelopes,
+    movingConstantEnvelopesPoint:
+      wasm.candle_single_movingConstantEnvelopesPoint,
     mcginleyDynamicEnvelopes: wasm.candle_single_mcginleyDynamicEnvelopes,
+    mcginleyDynamicEnvelopesPoint:
+      wasm.candle_single_mcginleyDynamicEnvelopesPoint,
     movingConstantBands: wasm.candle_single_movingConstantBands,
+    movingConstantBandsPoint: wasm.candle_single_movingConstantBandsPoint,
     mcginleyDynamicBands: wasm.candle_single_mcginleyDynamicBands,
+    mcginleyDynamicBandsPoint: wasm.candle_single_mcginleyDynamicBandsPoint,
     ichimokuCloud: wasm.candle_single_ichimokuCloud,
+    ichimokuCloudPoint: wasm.candle_single_ichimokuCloudPoint,
     donchianChannels: wasm.candle_single_donchianChannels,
+    donchianChannelsPoint: wasm.candle_single_donchianChannelsPoint,
     keltnerChannel: wasm.candle_single_keltnerChannel,
+    keltnerChannelPoint: wasm.candle_single_keltnerChannelPoint,
     supertrend: wasm.candle_single_supertrend
   },
   bulk: {
     movingConstantEnvelopes: wasm.candle_bulk_movingConstantEnvelopes,
+    movingConstantEnvelopesColumns: wasm.candle_bulk_movingConstantEnvelopesColumns,
     mcginleyDynamicEnvelopes: wasm.candle_bulk_mcginleyDynamicEnvelopes,
+    mcginleyDynamicEnvelopesColumns: wasm.candle_bulk_mcginleyDynamicEnvelopesColumns,
     movingConstantBands: wasm.candle_bulk_movingConstantBands,
+    movingConstantBandsColumns: wasm.candle_bulk_movingConstantBandsColumns,
     mcginleyDynamicBands: wasm.candle_bulk_mcginleyDynamicBands,
+    mcginleyDynamicBandsColumns: wasm.candle_bulk_mcginleyDynamicBandsColumns,
     ichimokuCloud: wasm.candle_bulk_ichimokuCloud,
+    ichimokuCloudColumns: wasm.candle_bulk_ichimokuCloudColumns,
     donchianChannels: wasm.candle_bulk_donchianChannels,
+    donchianChannelsColumns: wasm.candle_bulk_donchianChannelsColumns,
     keltnerChannel: wasm.candle_bulk_keltnerChannel,
+    keltnerChannelColumns: wasm.candle_bulk_keltnerChannelColumns,
     supertrend: wasm.candle_bulk_supertrend
   }
 };
@@ -35,7 +151,8 @@ export const chartTrends = {
   peakTrend: wasm.chart_trends_peakTrend,
   valleyTrend: wasm.chart_trends_valleyTrend,
   overallTrend: wasm.chart_trends_overallTrend,
-  breakDownTrends: wasm.chart_trends_breakDownTrends
+  breakDownTrends: wasm.chart_trends_breakDownTrends,
+  breakDownTrendsWithConfig: wasm.chart_trends_breakDownTrendsWithConfig
 };
 
 export const correlationIndicators = {
@@ -60,10 +177,15 @@ export const momentumIndicators = {
     commodityChannelIndex: wasm.momentum_single_commodityChannelIndex,
     mcginleyDynamicCommodityChannelIndex:
       wasm.momentum_single_mcginleyDynamicCommodityChannelIndex,
+    mcginleyDynamicCommodityChannelIndexPoint:
+      wasm.momentum_single_mcginleyDynamicCommodityChannelIndexPoint,
     macdLine: wasm.momentum_single_macdLine,
     signalLine: wasm.momentum_single_signalLine,
     mcginleyDynamicMacdLine: wasm.momentum_single_mcginleyDynamicMacdLine,
+    mcginleyDynamicMacdLinePoint:
+      wasm.momentum_single_mcginleyDynamicMacdLinePoint,
     chaikinOscillator: wasm.momentum_single_chaikinOscillator,
+    chaikinOscillatorPoint: wasm.momentum_single_chaikinOscillatorPoint,
     percentagePriceOscillator: wasm.momentum_single_percentagePriceOscillator,
     chandeMomentumOscillator: wasm.momentum_single_chandeMomentumOscillator,
   },
@@ -79,10 +201,14 @@ export const momentumIndicators = {
     commodityChannelIndex: wasm.momentum_bulk_commodityChannelIndex,
     mcginleyDynamicCommodityChannelIndex:
       wasm.momentum_bulk_mcginleyDynamicCommodityChannelIndex,
+    mcginleyDynamicCommodityChannelIndexColumns:
+      wasm.momentum_bulk_mcginleyDynamicCommodityChannelIndexColumns,
     macdLine: wasm.momentum_bulk_macdLine,
     signalLine: wasm.momentum_bulk_signalLine,
     mcginleyDynamicMacdLine: wasm.momentum_bulk_mcginleyDynamicMacdLine,
+    mcginleyDynamicMacdLineColumns: wasm.momentum_bulk_mcginleyDynamicMacdLineColumns,
     chaikinOscillator: wasm.momentum_bulk_chaikinOscillator,
+    chaikinOscillatorColumns: wasm.momentum_bulk_chaikinOscillatorColumns,
     percentagePriceOscillator: wasm.momentum_bulk_percentagePriceOscillator,
     chandeMomentumOscillator: wasm.momentum_bulk_chandeMomentumOscillator,
   },
@@ -91,16 +217,19 @@ export const momentumIndicators = {
 export const otherIndicators = {
   single: {
     returnOnInvestment: wasm.other_single_returnOnInvestment,
+    returnOnInvestmentPoint: wasm.other_single_returnOnInvestmentPoint,
     trueRange: wasm.other_single_trueRange,
     averageTrueRange: wasm.other_single_averageTrueRange,
     internalBarStrength: wasm.other_single_internalBarStrength,
   },
   bulk: {
     returnOnInvestment: wasm.other_bulk_returnOnInvestment,
+    returnOnInvestmentColumns: wasm.other_bulk_returnOnInvestmentColumns,
     trueRange: wasm.other_bulk_trueRange,
     averageTrueRange: wasm.other_bulk_averageTrueRange,
     internalBarStrength: wasm.other_bulk_internalBarStrength,
     positivityIndicator: wasm.other_bulk_positivityIndicator,
+    positivityIndicatorColumns: wasm.other_bulk_positivityIndicatorColumns,
   },
 };
 
@@ -110,7 +239,9 @@ export const standardIndicators = {
     smoothedMovingAverage: wasm.standard_single_smoothedMovingAverage,
     exponentialMovingAverage: wasm.standard_single_exponentialMovingAverage,
     bollingerBands: wasm.standard_single_bollingerBands,
+    bollingerBandsPoint: wasm.standard_single_bollingerBandsPoint,
     macd: wasm.standard_single_macd,
+    macdPoint: wasm.standard_single_macdPoint,
     rsi: wasm.standard_single_rsi,
   },
   bulk: {
@@ -118,7 +249,9 @@ export const standardIndicators = {
     smoothedMovingAverage: wasm.standard_bulk_smoothedMovingAverage,
     exponentialMovingAverage: wasm.standard_bulk_exponentialMovingAverage,
     bollingerBands: wasm.standard_bulk_bollingerBands,
+    bollingerBandsColumns: wasm.standard_bulk_bollingerBandsColumns,
     macd: wasm.standard_bulk_macd,
+    macdColumns: wasm.standard_bulk_macdColumns,
     rsi: wasm.standard_bulk_rsi,
   },
 };
@@ -143,6 +276,7 @@ export const trendIndicators = {
     aroonDown: wasm.trend_single_aroonDown,
     aroonOscillator: wasm.trend_single_aroonOscillator,
     aroonIndicator: wasm.trend_single_aroonIndicator,
+    aroonIndicatorPoint: wasm.trend_single_aroonIndicatorPoint,
     longParabolicTimePriceSystem: wasm.trend_single_longParabolicTimePriceSystem,
     shortParabolicTimePriceSystem: wasm.trend_single_shortParabolicTimePriceSystem,
     volumePriceTrend: wasm.trend_single_volumePriceTrend,
@@ -153,8 +287,10 @@ export const trendIndicators = {
     aroonDown: wasm.trend_bulk_aroonDown,
     aroonOscillator: wasm.trend_bulk_aroonOscillator,
     aroonIndicator: wasm.trend_bulk_aroonIndicator,
+    aroonIndicatorColumns: wasm.trend_bulk_aroonIndicatorColumns,
     parabolicTimePriceSystem: wasm.trend_bulk_parabolicTimePriceSystem,
     directionalMovementSystem: wasm.trend_bulk_directionalMovementSystem,
+    directionalMovementSystemColumns: wasm.trend_bulk_directionalMovementSystemColumns,
     volumePriceTrend: wasm.trend_bulk_volumePriceTrend,
     trueStrengthIndex: wasm.trend_bulk_trueStrengthIndex,
   },
@@ -174,10 +310,22 @@ export const movingAverage = {
   single: {
     movingAverage: wasm.ma_single_movingAverage,
     mcginleyDynamic: wasm.ma_single_mcginleyDynamic,
+    volumeWeightedMovingAverage: wasm.ma_single_volumeWeightedMovingAverage,
+    kaufmanAdaptiveMovingAverage: wasm.ma_single_kaufmanAdaptiveMovingAverage,
+    fractalAdaptiveMovingAverage: wasm.ma_single_fractalAdaptiveMovingAverage,
+    variableIndexDynamicAverage: wasm.ma_single_variableIndexDynamicAverage,
+    arnaudLegouxMovingAverage: wasm.ma_single_arnaudLegouxMovingAverage,
+    tillsonT3: wasm.ma_single_tillsonT3,
   },
   bulk: {
     movingAverage: wasm.ma_bulk_movingAverage,
     mcginleyDynamic: wasm.ma_bulk_mcginleyDynamic,
+    volumeWeightedMovingAverage: wasm.ma_bulk_volumeWeightedMovingAverage,
+    kaufmanAdaptiveMovingAverage: wasm.ma_bulk_kaufmanAdaptiveMovingAverage,
+    fractalAdaptiveMovingAverage: wasm.ma_bulk_fractalAdaptiveMovingAverage,
+    variableIndexDynamicAverage: wasm.ma_bulk_variableIndexDynamicAverage,
+    arnaudLegouxMovingAverage: wasm.ma_bulk_arnaudLegouxMovingAverage,
+    tillsonT3: wasm.ma_bulk_tillsonT3,
   },
 };
 
@@ -0,0 +1,139 @@
+use crate::averages::{exponential, weighted};
+
+// Adaptive moving averages.
+//
+// Kaufman's adaptive moving average (KAMA), Ehlers' fractal adaptive moving average (FRAMA) and
+// Chande's variable index dynamic average (VIDYA) are recursive like the McGinley dynamic: each
+// value moves the previous one toward the latest price by a factor read from the latest window,
+// and a previous value of 0 starts from the price. Their bulk functions chain the value through
+// the windows the way RustTI's `mcginley_dynamic` does.
+//
+// The Arnaud Legoux moving average (ALMA) and Tillson's T3 are functions of one window, like the
+// families in `averages`: T3 cascades six of RustTI's EMAs over the prefixes of the window.
+
+// `previous` moved toward `price` by `alpha`, or `price` when there is no previous value
+fn smooth(price: f64, previous: f64, alpha: f64) -> f64 {
+    if previous == 0.0 {
+        return price;
+    }
+    previous + alpha * (price - previous)
+}
+
+/// One step per window of `period` prices, starting from `previous` (0 for none).
+pub(crate) fn chain(
+    prices: &[f64],
+    period: usize,
+    previous: f64,
+    step: impl Fn(&[f64], f64) -> f64,
+) -> Vec<f64> {
+    let mut value = previous;
+    prices
+        .windows(period)
+        .map(|window| {
+            value = step(window, value);
+            value
+        })
+        .collect()
+}
+
+/// KAMA after the last of `prices` (at least 2): the efficiency ratio of the window scales the
+/// smoothing between the EMA constants of `fast_period` and `slow_period`, squared.
+pub(crate) fn kaufman(
+    prices: &[f64],
+    previous: f64,
+    fast_period: usize,
+    slow_period: usize,
+) -> f64 {
+    let latest = prices[prices.len() - 1];
+    let direction = (latest - prices[0]).abs();
+    let volatility: f64 = prices
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).abs())
+        .sum();
+    let efficiency = if volatility == 0.0 {
+        0.0
+    } else {
+        direction / volatility
+    };
+    let fast = 2.0 / (fast_period as f64 + 1.0);
+    let slow = 2.0 / (slow_period as f64 + 1.0);
+    smooth(
+        latest,
+        previous,
+        (efficiency * (fast - slow) + slow).powi(2),
+    )
+}
+
+/// FRAMA after the last of `prices` (an even number of them): the fractal dimension D of the two
+/// halves gives alpha = exp(-4.6 (D - 1)), kept within [0.01, 1]. A flat window has D = 1.
+pub(crate) fn fractal(prices: &[f64], previous: f64) -> f64 {
+    let half = prices.len() / 2;
+    let slope = |values: &[f64]| {
+        let (low, high) = values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), value| {
+                (low.min(*value), high.max(*value))
+            });
+        (high - low) / values.len() as f64
+    };
+    let halves = slope(&prices[..half]) + slope(&prices[half..]);
+    let whole = slope(prices);
+    let dimension = if halves > 0.0 && whole > 0.0 {
+        (halves.ln() - whole.ln()) / std::f64::consts::LN_2
+    } else {
+        1.0
+    };
+    let alpha = (-4.6 * (dimension - 1.0)).exp().clamp(0.01, 1.0);
+    smooth(prices[prices.len() - 1], previous, alpha)
+}
+
+/// VIDYA after the last of `prices` (at least 2): the EMA constant of `period` scaled by the
+/// absolute Chande momentum oscillator of the window, as a fraction (0 for a flat window).
+pub(crate) fn variable_index(prices: &[f64], previous: f64, period: usize) -> f64 {
+    let mut gains = 0.0;
+    let mut losses = 0.0;
+    for pair in prices.windows(2) {
+        let change = pair[1] - pair[0];
+        if change > 0.0 {
+            gains += change;
+        } else {
+            losses -= change;
+        }
+    }
+    let momentum = if gains + losses == 0.0 {
+        0.0
+    } else {
+        ((gains - losses) / (gains + losses)).abs()
+    };
+    let alpha = 2.0 / (period as f64 + 1.0) * momentum;
+    smooth(prices[prices.len() - 1], previous, alpha)
+}
+
+/// ALMA of `prices` (not empty): Gaussian weights centred `offset` of the way from the oldest to
+/// the newest value, with a width of n / `sigma`.
+pub(crate) fn arnaud_legoux(prices: &[f64], offset: f64, sigma: f64) -> f64 {
+    let length = prices.len() as f64;
+    let centre = offset * (length - 1.0);
+    let width = length / sigma;
+    weighted(prices, |index| {
+        (-(index as f64 - centre).powi(2) / (2.0 * width * width)).exp()
+    })
+}
+
+/// Tillson's T3 of `prices` (not empty): c1 e6 + c2 e5 + c3 e4 + c4 e3 over six cascaded EMAs,
+/// with coefficients from `volume_factor`.
+pub(crate) fn tillson_t3(prices: &[f64], volume_factor: f64) -> f64 {
+    let length = prices.len();
+    let mut cascade = Vec::with_capacity(6);
+    let mut values = prices.to_vec();
+    for _ in 0..6 {
+        values = exponential(&values, length);
+        cascade.push(values[length - 1]);
+    }
+    let a = volume_factor;
+    let (a2, a3) = (a * a, a * a * a);
+    -a3 * cascade[5]
+        + (3.0 * a2 + 3.0 * a3) * cascade[4]
+        + (-6.0 * a2 - 3.0 * a - 3.0 * a3) * cascade[3]
+        + (1.0 + 3.0 * a + a3 + 3.0 * a2) * cascade[2]
+}
@@ -0,0 +1,61 @@
+use std::cell::Cell;
+use wasm_bindgen::prelude::*;
+
+// Input-aligned bulk output.
+//
+// RustTI bulk functions return one value per complete window, so each indicator's output is
+// shorter than its input by a different amount. With alignment enabled every bulk binding
+// left-pads its result with NaN so output index `i` is the value at input bar `i`.
+// WASM is single-threaded, so the setting is a plain thread-local.
+
+thread_local! {
+    static ALIGNED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Left-pads every bulk output with NaN to the length of its input when `aligned` is true.
+#[wasm_bindgen(js_name = setAlignedOutput)]
+pub fn set_aligned_output(aligned: bool) {
+    ALIGNED.with(|a| a.set(aligned));
+}
+
+/// Whether bulk outputs are currently padded to the length of their input.
+#[wasm_bindgen(js_name = isAlignedOutput)]
+pub fn is_aligned_output() -> bool {
+    ALIGNED.with(|a| a.get())
+}
+
+/// Value used for bars before an indicator's first complete window.
+pub(crate) trait Padding: Copy {
+    const PAD: Self;
+}
+
+impl Padding for f64 {
+    const PAD: Self = f64::NAN;
+}
+
+impl Padding for (f64, f64) {
+    const PAD: Self = (f64::NAN, f64::NAN);
+}
+
+impl Padding for (f64, f64, f64) {
+    const PAD: Self = (f64::NAN, f64::NAN, f64::NAN);
+}
+
+impl Padding for (f64, f64, f64, f64) {
+    const PAD: Self = (f64::NAN, f64::NAN, f64::NAN, f64::NAN);
+}
+
+impl Padding for (f64, f64, f64, f64, f64) {
+    const PAD: Self = (f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN);
+}
+
+/// Left-pads `output` to `length` when alignment is enabled, otherwise returns it unchanged.
+pub(crate) fn aligned<T: Padding>(output: Vec<T>, length: usize) -> Vec<T> {
+    if !is_aligned_output() || output.len() >= length {
+        return output;
+    }
+    let mut padded = Vec::with_capacity(length);
+    padded.resize(length - output.len(), T::PAD);
+    padded.extend(output);
+    padded
+}
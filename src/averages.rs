@@ -0,0 +1,314 @@
+use rust_ti::basic_indicators::single::{
+    absolute_deviation, cauchy_iqr_scale, laplace_std_equivalent, log_standard_deviation, median,
+    mode, standard_deviation,
+};
+use rust_ti::moving_average::single::moving_average;
+use rust_ti::volatility_indicators::single::ulcer_index;
+use rust_ti::{AbsDevConfig, CentralPoint, DeviationAggregate, MovingAverageType};
+
+// Moving average families that RustTI does not provide.
+//
+// RustTI's moving averages are functions of one window: its EMA over n values weights them by
+// (1 - alpha)^age with alpha = 2 / (n + 1) and divides by the sum of the weights. The weighted,
+// Hull, double and triple exponential, zero-lag and triangular averages follow the same rule, so
+// every indicator keeps a lookback of `period - 1` and a stream can run them over its window.
+// Their inner averages (the EMA of the EMA, the raw Hull series) are taken over the prefixes of
+// the window, so the oldest value of the window seeds them. Hull, DEMA, TEMA and ZLEMA therefore
+// differ from their usual recursive forms, which run over the whole history and need longer
+// lookbacks; index.d.ts documents the difference.
+//
+// A `ConstantModel` is a RustTI constant model or one of these families. The indicators that take
+// the families (moving-constant envelopes and bands, RSI, MACD and its signal line) call RustTI's
+// single function for its own models and repeat its formula otherwise. RustTI's bulk functions
+// run the single function over every window, so their bindings do the same with these.
+
+/// Moving average family computed by ti_engine over a window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Family {
+    Weighted,
+    Hull,
+    DoubleExponential,
+    TripleExponential,
+    ZeroLagExponential,
+    Triangular,
+}
+
+impl Family {
+    /// Average of `values` (not empty), oldest first.
+    pub(crate) fn average(self, values: &[f64]) -> f64 {
+        let length = values.len();
+        match self {
+            Family::Weighted => linear(values),
+            Family::Triangular => weighted(values, |index| (index + 1).min(length - index) as f64),
+            Family::Hull => hull(values),
+            Family::DoubleExponential => {
+                let single = exponential(values, length);
+                let double = exponential(&single, length);
+                2.0 * single[length - 1] - double[length - 1]
+            }
+            Family::TripleExponential => {
+                let single = exponential(values, length);
+                let double = exponential(&single, length);
+                let triple = exponential(&double, length);
+                3.0 * single[length - 1] - 3.0 * double[length - 1] + triple[length - 1]
+            }
+            Family::ZeroLagExponential => {
+                // Every value plus its change over half the window
+                let lag = (length - 1) / 2;
+                let adjusted: Vec<f64> = values[lag..]
+                    .iter()
+                    .zip(values)
+                    .map(|(value, lagged)| 2.0 * value - lagged)
+                    .collect();
+                exponential(&adjusted, length)[adjusted.len() - 1]
+            }
+        }
+    }
+}
+
+// `values` weighted by `weight(index)`, oldest first
+pub(crate) fn weighted(values: &[f64], weight: impl Fn(usize) -> f64) -> f64 {
+    let mut sum = 0.0;
+    let mut total = 0.0;
+    for (index, value) in values.iter().enumerate() {
+        let weight = weight(index);
+        sum += value * weight;
+        total += weight;
+    }
+    sum / total
+}
+
+// Weights 1 (oldest) to n (newest)
+fn linear(values: &[f64]) -> f64 {
+    weighted(values, |index| (index + 1) as f64)
+}
+
+// RustTI's EMA over every prefix of `values`, with the alpha of a window of `length` values
+pub(crate) fn exponential(values: &[f64], length: usize) -> Vec<f64> {
+    let decay = 1.0 - 2.0 / (length as f64 + 1.0);
+    let mut sum = 0.0;
+    let mut total = 0.0;
+    values
+        .iter()
+        .map(|value| {
+            sum = value + decay * sum;
+            total = 1.0 + decay * total;
+            sum / total
+        })
+        .collect()
+}
+
+// Weighted average of the last sqrt(n) values of 2 * WMA(n / 2) - WMA(n)
+fn hull(values: &[f64]) -> f64 {
+    let length = values.len();
+    let half = (length / 2).max(1);
+    let root = ((length as f64).sqrt() as usize).max(1);
+    let raw: Vec<f64> = (length - root..length)
+        .map(|end| {
+            let prefix = &values[..=end];
+            2.0 * linear(&prefix[prefix.len().saturating_sub(half)..]) - linear(prefix)
+        })
+        .collect();
+    linear(&raw)
+}
+
+/// Volume-weighted average of `prices` (NaN if the volumes add up to 0).
+pub(crate) fn volume_weighted(prices: &[f64], volumes: &[f64]) -> f64 {
+    let mut sum = 0.0;
+    let mut total = 0.0;
+    for (price, volume) in prices.iter().zip(volumes) {
+        sum += price * volume;
+        total += volume;
+    }
+    if total == 0.0 {
+        return f64::NAN;
+    }
+    sum / total
+}
+
+/// Resolved `MovingAverageLike`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum MovingAverage {
+    RustTi(MovingAverageType),
+    Family(Family),
+    VolumeWeighted,
+}
+
+impl MovingAverage {
+    /// The average of `values` (not empty); `volumes` holds one volume per value for
+    /// `VolumeWeighted` and is ignored otherwise.
+    pub(crate) fn average(self, values: &[f64], volumes: &[f64]) -> f64 {
+        match self {
+            MovingAverage::RustTi(model) => moving_average(values, model),
+            MovingAverage::Family(family) => family.average(values),
+            MovingAverage::VolumeWeighted => volume_weighted(values, volumes),
+        }
+    }
+}
+
+/// Resolved `FamilyModelLike`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ConstantModel {
+    RustTi(rust_ti::ConstantModelType),
+    Family(Family),
+}
+
+impl From<rust_ti::ConstantModelType> for ConstantModel {
+    fn from(model: rust_ti::ConstantModelType) -> Self {
+        ConstantModel::RustTi(model)
+    }
+}
+
+impl ConstantModel {
+    /// The model over `values` (not empty), as RustTI's indicators take their moving constant.
+    pub(crate) fn average(self, values: &[f64]) -> f64 {
+        use rust_ti::ConstantModelType::*;
+        match self {
+            ConstantModel::RustTi(SimpleMovingAverage) => {
+                moving_average(values, MovingAverageType::Simple)
+            }
+            ConstantModel::RustTi(SmoothedMovingAverage) => {
+                moving_average(values, MovingAverageType::Smoothed)
+            }
+            ConstantModel::RustTi(ExponentialMovingAverage) => {
+                moving_average(values, MovingAverageType::Exponential)
+            }
+            ConstantModel::RustTi(PersonalisedMovingAverage {
+                alpha_num,
+                alpha_den,
+            }) => moving_average(
+                values,
+                MovingAverageType::Personalised {
+                    alpha_num,
+                    alpha_den,
+                },
+            ),
+            ConstantModel::RustTi(SimpleMovingMedian) => median(values),
+            ConstantModel::RustTi(SimpleMovingMode) => mode(values),
+            ConstantModel::Family(family) => family.average(values),
+        }
+    }
+}
+
+/// `rust_ti::candle_indicators::single::moving_constant_envelopes` for any model.
+pub(crate) fn moving_constant_envelopes(
+    prices: &[f64],
+    model: ConstantModel,
+    difference: f64,
+) -> (f64, f64, f64) {
+    let moving_constant = match model {
+        ConstantModel::RustTi(model) => {
+            return rust_ti::candle_indicators::single::moving_constant_envelopes(
+                prices, model, difference,
+            )
+        }
+        ConstantModel::Family(family) => family.average(prices),
+    };
+    (
+        moving_constant * (1.0 - (difference / 100.0)),
+        moving_constant,
+        moving_constant * (1.0 + (difference / 100.0)),
+    )
+}
+
+/// `rust_ti::candle_indicators::single::moving_constant_bands` for any model.
+pub(crate) fn moving_constant_bands(
+    prices: &[f64],
+    model: ConstantModel,
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+) -> (f64, f64, f64) {
+    let moving_constant = match model {
+        ConstantModel::RustTi(model) => {
+            return rust_ti::candle_indicators::single::moving_constant_bands(
+                prices,
+                model,
+                deviation_model.into(),
+                deviation_multiplier,
+            )
+        }
+        ConstantModel::Family(family) => family.average(prices),
+    };
+    let deviation = deviation(prices, deviation_model);
+    (
+        moving_constant - (deviation * deviation_multiplier),
+        moving_constant,
+        moving_constant + (deviation * deviation_multiplier),
+    )
+}
+
+// The deviation RustTI's moving-constant bands take for `deviation_model`
+fn deviation(prices: &[f64], deviation_model: crate::DeviationModel) -> f64 {
+    use crate::DeviationModel::*;
+    let absolute =
+        |center, aggregate| absolute_deviation(prices, AbsDevConfig { center, aggregate });
+    match deviation_model {
+        StandardDeviation => standard_deviation(prices),
+        MeanAbsoluteDeviation => absolute(CentralPoint::Mean, DeviationAggregate::Mean),
+        MedianAbsoluteDeviation => absolute(CentralPoint::Median, DeviationAggregate::Median),
+        ModeAbsoluteDeviation => absolute(CentralPoint::Mode, DeviationAggregate::Mode),
+        UlcerIndex => ulcer_index(prices),
+        LogStandardDeviation => log_standard_deviation(prices),
+        LaplaceStdEquivalent => laplace_std_equivalent(prices),
+        CauchyIQRScale => cauchy_iqr_scale(prices),
+    }
+}
+
+/// `rust_ti::momentum_indicators::single::relative_strength_index` for any model.
+pub(crate) fn relative_strength_index(prices: &[f64], model: ConstantModel) -> f64 {
+    let family = match model {
+        ConstantModel::RustTi(model) => {
+            return rust_ti::momentum_indicators::single::relative_strength_index(prices, model)
+        }
+        ConstantModel::Family(family) => family,
+    };
+    let mut gains = Vec::with_capacity(prices.len());
+    let mut losses = Vec::with_capacity(prices.len());
+    for pair in prices.windows(2) {
+        let change = pair[1] - pair[0];
+        if change > 0.0 {
+            gains.push(change);
+        } else if change < 0.0 {
+            losses.push(pair[0] - pair[1]);
+        }
+    }
+    if gains.is_empty() {
+        return 0.0;
+    }
+    if losses.is_empty() {
+        return 100.0;
+    }
+    let average_gains = family.average(&gains);
+    let average_loss = family.average(&losses);
+    if average_loss == 0.0 {
+        0.0
+    } else {
+        100.0 - (100.0 / (1.0 + (average_gains / average_loss)))
+    }
+}
+
+/// `rust_ti::momentum_indicators::single::macd_line` for any models.
+pub(crate) fn macd_line(
+    prices: &[f64],
+    short_period: usize,
+    short_period_model: ConstantModel,
+    long_period_model: ConstantModel,
+) -> f64 {
+    if let (ConstantModel::RustTi(short), ConstantModel::RustTi(long)) =
+        (short_period_model, long_period_model)
+    {
+        return rust_ti::momentum_indicators::single::macd_line(prices, short_period, short, long);
+    }
+    short_period_model.average(&prices[prices.len() - short_period..])
+        - long_period_model.average(prices)
+}
+
+/// `rust_ti::momentum_indicators::single::signal_line` for any model.
+pub(crate) fn signal_line(macds: &[f64], model: ConstantModel) -> f64 {
+    match model {
+        ConstantModel::RustTi(model) => {
+            rust_ti::momentum_indicators::single::signal_line(macds, model)
+        }
+        ConstantModel::Family(family) => family.average(macds),
+    }
+}
@@ -0,0 +1,1087 @@
+use crate::columns::columns;
+use crate::error::{ErrorCode, IndicatorError, IndicatorResult};
+use crate::missing::MissingData;
+use crate::registry::{IndicatorSpec, ParamDefault, ParamType};
+use js_sys::{Array, ArrayBuffer, Float64Array, Object, Reflect, JSON};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::{JsCast, JsValue};
+
+// Declarative batch computation.
+//
+// `computeBatch(series, specs)` runs a list of bulk indicators over one copy of the candles and
+// returns `{ [id]: result }`. Specs are plain objects (or a JSON string of them) deserialized
+// with serde into `Spec`:
+//
+//     { id?, indicator: "momentumIndicators.macdLine", params?: { shortPeriod: 12, ... }, inputs? }
+//
+// Missing params fall back to the registry defaults; enum params take the enum value or its
+// variant name. `inputs` overrides where each input array comes from: a column name or the `id`
+// of an earlier single-series result (e.g. a signal line over a MACD line). An optional
+// `missingData` policy applies to that spec only.
+
+pub(crate) const COLUMNS: [&str; 5] = ["open", "high", "low", "close", "volume"];
+
+/// Borrowed candle columns; missing columns only fail for indicators that need them.
+pub(crate) struct BatchSeries<'a> {
+    pub(crate) open: Option<&'a [f64]>,
+    pub(crate) high: Option<&'a [f64]>,
+    pub(crate) low: Option<&'a [f64]>,
+    pub(crate) close: Option<&'a [f64]>,
+    pub(crate) volume: Option<&'a [f64]>,
+}
+
+impl<'a> BatchSeries<'a> {
+    pub(crate) fn borrow(columns: &'a [Option<Vec<f64>>; 5]) -> Self {
+        let [open, high, low, close, volume] = columns;
+        Self {
+            open: open.as_deref(),
+            high: high.as_deref(),
+            low: low.as_deref(),
+            close: close.as_deref(),
+            volume: volume.as_deref(),
+        }
+    }
+
+    /// Number of bars, i.e. the length of the longest column.
+    pub(crate) fn length(&self) -> usize {
+        COLUMNS
+            .iter()
+            .filter_map(|name| self.column(name))
+            .map(|c| c.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn column(&self, name: &str) -> Option<&'a [f64]> {
+        match name {
+            "open" => self.open,
+            "high" => self.high,
+            "low" => self.low,
+            "close" => self.close,
+            "volume" => self.volume,
+            _ => None,
+        }
+    }
+}
+
+/// Runs every spec in `specs` (an array or a JSON string) over `series`, keyed by id.
+///
+/// `series` is a plain object of `open`, `high`, `low`, `close` and `volume` arrays (any subset)
+/// or an `OhlcvSeries`; `OhlcvSeries.computeBatch(specs)` skips the copy entirely.
+#[wasm_bindgen(js_name = computeBatch)]
+pub fn compute_batch(series: &JsValue, specs: &JsValue) -> IndicatorResult<Object> {
+    let columns = copy_columns(series)?;
+    run(&BatchSeries::borrow(&columns), specs)
+}
+
+// Copies the candle columns present on `series`; a bare array is taken as the close column
+pub(crate) fn copy_columns(series: &JsValue) -> IndicatorResult<[Option<Vec<f64>>; 5]> {
+    let mut columns: [Option<Vec<f64>>; 5] = Default::default();
+    if is_numbers(series) {
+        columns[3] = Some(Float64Array::new(series).to_vec());
+        return Ok(columns);
+    }
+    for (column, name) in columns.iter_mut().zip(COLUMNS) {
+        let value = get(series, name);
+        if !value.is_undefined() && !value.is_null() {
+            *column = Some(numbers(name, &value)?);
+        }
+    }
+    Ok(columns)
+}
+
+// An array or typed array, which `Float64Array::new` copies element by element
+fn is_numbers(value: &JsValue) -> bool {
+    Array::is_array(value) || ArrayBuffer::is_view(value)
+}
+
+/// Copies the array or typed array `value`; anything else (a number would size a zero-filled
+/// array) is an `InvalidParameter` for `argument`.
+pub(crate) fn numbers(argument: &'static str, value: &JsValue) -> IndicatorResult<Vec<f64>> {
+    if !is_numbers(value) {
+        return Err(IndicatorError::new(
+            ErrorCode::InvalidParameter,
+            argument,
+            format!("{} must be an array of numbers", argument),
+        ));
+    }
+    Ok(Float64Array::new(value).to_vec())
+}
+
+/// One `computeBatch` spec. `params` and `missingData` stay JS values: each parameter is read
+/// against its registry type when the indicator runs.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Spec {
+    pub(crate) indicator: String,
+    #[serde(default)]
+    pub(crate) id: Option<String>,
+    #[serde(default, with = "serde_wasm_bindgen::preserve")]
+    pub(crate) params: JsValue,
+    #[serde(default)]
+    pub(crate) inputs: Option<Vec<String>>,
+    #[serde(default, with = "serde_wasm_bindgen::preserve")]
+    pub(crate) missing_data: JsValue,
+}
+
+/// `value`, or the value of the JSON string `value`.
+pub(crate) fn parse_json(argument: &'static str, value: &JsValue) -> IndicatorResult<JsValue> {
+    match value.as_string() {
+        Some(json) => JSON::parse(&json).map_err(|_| {
+            IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                argument,
+                format!("{} is not valid JSON", argument),
+            )
+        }),
+        None => Ok(value.clone()),
+    }
+}
+
+/// Deserializes `value`, or the JSON string `value`, as the `argument` request.
+pub(crate) fn read_json<T: DeserializeOwned>(
+    argument: &'static str,
+    value: &JsValue,
+) -> IndicatorResult<T> {
+    serde_wasm_bindgen::from_value(parse_json(argument, value)?).map_err(|e| {
+        IndicatorError::new(
+            ErrorCode::InvalidParameter,
+            argument,
+            format!("{} is invalid: {}", argument, e),
+        )
+    })
+}
+
+pub(crate) fn run(series: &BatchSeries, specs: &JsValue) -> IndicatorResult<Object> {
+    let specs = parse_json("specs", specs)?;
+    if !Array::is_array(&specs) {
+        return Err(IndicatorError::new(
+            ErrorCode::InvalidParameter,
+            "specs",
+            "specs must be an array of indicator specifications".to_string(),
+        ));
+    }
+    let out = Object::new();
+    // Single-series results, available as inputs to later specs
+    let mut results: Vec<(String, Vec<f64>)> = Vec::new();
+    for (index, spec) in specs.unchecked_into::<Array>().iter().enumerate() {
+        let (id, output) = read_json::<Spec>("spec", &spec)
+            .and_then(|spec| run_spec(series, &results, &spec))
+            .map_err(|e| e.with_value("spec", index as f64))?;
+        if Reflect::has(&out, &JsValue::from_str(&id)).unwrap_or(false) {
+            return Err(IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                "id",
+                format!("id ({}) is used by more than one spec", id),
+            )
+            .with_value("spec", index as f64));
+        }
+        let value: JsValue = match output {
+            Output::Series(values) => {
+                let array = Float64Array::from(values.as_slice());
+                results.push((id.clone(), values));
+                array.into()
+            }
+            Output::Columns(object) => object.into(),
+        };
+        let _ = Reflect::set(&out, &JsValue::from_str(&id), &value);
+    }
+    Ok(out)
+}
+
+pub(crate) enum Output {
+    Series(Vec<f64>),
+    Columns(Object),
+}
+
+pub(crate) fn get(target: &JsValue, key: &str) -> JsValue {
+    if !target.is_object() {
+        return JsValue::UNDEFINED;
+    }
+    Reflect::get(target, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED)
+}
+
+pub(crate) fn run_spec(
+    series: &BatchSeries,
+    results: &[(String, Vec<f64>)],
+    spec: &Spec,
+) -> IndicatorResult<(String, Output)> {
+    let job = Job::new(spec, series, results)?;
+    let id = spec.id.clone().unwrap_or_else(|| spec.indicator.clone());
+    let output = match spec.policy()? {
+        Some(policy) => crate::missing::with_policy(policy, || job.run())?,
+        None => job.run()?,
+    };
+    Ok((id, output))
+}
+
+impl Spec {
+    /// The spec's own `missingData` policy, if it has one.
+    pub(crate) fn policy(&self) -> IndicatorResult<Option<MissingData>> {
+        if self.missing_data.is_undefined() || self.missing_data.is_null() {
+            return Ok(None);
+        }
+        crate::missing::from_js(&self.missing_data).map(Some)
+    }
+}
+
+/// One spec bound to its series, runnable any number of times.
+pub(crate) struct Job<'a> {
+    info: &'static IndicatorSpec,
+    indicator: String,
+    params: JsValue,
+    // Parameters set after parsing (swept values), read before `params`
+    overrides: Vec<(String, JsValue)>,
+    sources: Option<Vec<String>>,
+    // Input arrays resolved ahead of time, used instead of `sources` and the columns
+    prepared: Option<Vec<&'a [f64]>>,
+    series: &'a BatchSeries<'a>,
+    results: &'a [(String, Vec<f64>)],
+}
+
+impl<'a> Job<'a> {
+    pub(crate) fn new(
+        spec: &Spec,
+        series: &'a BatchSeries<'a>,
+        results: &'a [(String, Vec<f64>)],
+    ) -> IndicatorResult<Self> {
+        Ok(Self {
+            info: crate::registry::find(&spec.indicator)?,
+            indicator: spec.indicator.clone(),
+            params: spec.params.clone(),
+            overrides: Vec::new(),
+            sources: spec.inputs.clone(),
+            prepared: None,
+            series,
+            results,
+        })
+    }
+
+    pub(crate) fn info(&self) -> &'static IndicatorSpec {
+        self.info
+    }
+
+    /// Sets parameter `name` for the following runs, over the spec's `params`.
+    pub(crate) fn set(&mut self, name: &str, value: JsValue) {
+        match self.overrides.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value,
+            None => self.overrides.push((name.to_string(), value)),
+        }
+    }
+
+    /// Runs every following run over `inputs` (one per registry bulk input) instead of
+    /// resolving them again.
+    pub(crate) fn prepare(&mut self, inputs: Vec<&'a [f64]>) {
+        self.prepared = Some(inputs);
+    }
+
+    pub(crate) fn run(&self) -> IndicatorResult<Output> {
+        let job = self;
+        let indicator = self.indicator.as_str();
+        Ok(match indicator {
+            "candleIndicators.movingConstantEnvelopes" => {
+                let [prices] = job.inputs()?;
+                Output::Columns(
+                    crate::candle_indicators::candle_bulk_moving_constant_envelopes_columns(
+                        prices,
+                        job.family_model("constantModelType")?,
+                        job.number("difference")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "candleIndicators.mcginleyDynamicEnvelopes" => {
+                let [prices] = job.inputs()?;
+                Output::Columns(
+                    crate::candle_indicators::candle_bulk_mcginley_dynamic_envelopes_columns(
+                        prices,
+                        job.number("difference")?,
+                        job.number("previousMcginleyDynamic")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "candleIndicators.movingConstantBands" => {
+                let [prices] = job.inputs()?;
+                Output::Columns(
+                    crate::candle_indicators::candle_bulk_moving_constant_bands_columns(
+                        prices,
+                        job.family_model("constantModelType")?,
+                        job.deviation_model("deviationModel")?,
+                        job.number("deviationMultiplier")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "candleIndicators.mcginleyDynamicBands" => {
+                let [prices] = job.inputs()?;
+                Output::Columns(
+                    crate::candle_indicators::candle_bulk_mcginley_dynamic_bands_columns(
+                        prices,
+                        job.deviation_model("deviationModel")?,
+                        job.number("deviationMultiplier")?,
+                        job.number("previousMcginleyDynamic")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "candleIndicators.ichimokuCloud" => {
+                let [highs, lows, close] = job.inputs()?;
+                Output::Columns(
+                    crate::candle_indicators::candle_bulk_ichimoku_cloud_columns(
+                        highs,
+                        lows,
+                        close,
+                        job.integer("conversionPeriod")?,
+                        job.integer("basePeriod")?,
+                        job.integer("spanBPeriod")?,
+                    )?,
+                )
+            }
+            "candleIndicators.donchianChannels" => {
+                let [highs, lows] = job.inputs()?;
+                Output::Columns(
+                    crate::candle_indicators::candle_bulk_donchian_channels_columns(
+                        highs,
+                        lows,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "candleIndicators.keltnerChannel" => {
+                let [highs, lows, close] = job.inputs()?;
+                Output::Columns(
+                    crate::candle_indicators::candle_bulk_keltner_channel_columns(
+                        highs,
+                        lows,
+                        close,
+                        job.constant_model("constantModelType")?,
+                        job.constant_model("atrConstantModelType")?,
+                        job.number("multiplier")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "candleIndicators.supertrend" => {
+                let [highs, lows, close] = job.inputs()?;
+                Output::Series(crate::candle_indicators::candle_bulk_supertrend(
+                    highs,
+                    lows,
+                    close,
+                    job.constant_model("constantModelType")?,
+                    job.number("multiplier")?,
+                    job.integer("period")?,
+                )?)
+            }
+            "correlationIndicators.correlateAssetPrices" => {
+                let [prices_asset_a, prices_asset_b] = job.inputs()?;
+                Output::Series(
+                    crate::correlation_indicators::correlation_bulk_correlate_asset_prices(
+                        prices_asset_a,
+                        prices_asset_b,
+                        job.constant_model("constantModelType")?,
+                        job.deviation_model("deviationModel")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "momentumIndicators.relativeStrengthIndex" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::momentum_indicators::momentum_bulk_relative_strength_index(
+                        prices,
+                        job.family_model("constantModelType")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "momentumIndicators.stochasticOscillator" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::momentum_indicators::momentum_bulk_stochastic_oscillator(
+                        prices,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "momentumIndicators.slowStochastic" => {
+                let [stochastics] = job.inputs()?;
+                Output::Series(crate::momentum_indicators::momentum_bulk_slow_stochastic(
+                    stochastics,
+                    job.constant_model("constantModelType")?,
+                    job.integer("period")?,
+                )?)
+            }
+            "momentumIndicators.slowestStochastic" => {
+                let [slow_stochastics] = job.inputs()?;
+                Output::Series(
+                    crate::momentum_indicators::momentum_bulk_slowest_stochastic(
+                        slow_stochastics,
+                        job.constant_model("constantModelType")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "momentumIndicators.williamsPercentR" => {
+                let [high, low, close] = job.inputs()?;
+                Output::Series(
+                    crate::momentum_indicators::momentum_bulk_williams_percent_r(
+                        high,
+                        low,
+                        close,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "momentumIndicators.moneyFlowIndex" => {
+                let [prices, volume] = job.inputs()?;
+                Output::Series(crate::momentum_indicators::momentum_bulk_money_flow_index(
+                    prices,
+                    volume,
+                    job.integer("period")?,
+                )?)
+            }
+            "momentumIndicators.rateOfChange" => {
+                let [prices] = job.inputs()?;
+                Output::Series(crate::momentum_indicators::momentum_bulk_rate_of_change(
+                    prices,
+                )?)
+            }
+            "momentumIndicators.onBalanceVolume" => {
+                let [prices, volume] = job.inputs()?;
+                Output::Series(crate::momentum_indicators::momentum_bulk_on_balance_volume(
+                    prices,
+                    volume,
+                    job.number("previousOnBalanceVolume")?,
+                )?)
+            }
+            "momentumIndicators.commodityChannelIndex" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::momentum_indicators::momentum_bulk_commodity_channel_index(
+                        prices,
+                        job.constant_model("constantModelType")?,
+                        job.deviation_model("deviationModel")?,
+                        job.number("constantMultiplier")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "momentumIndicators.mcginleyDynamicCommodityChannelIndex" => {
+                let [prices] = job.inputs()?;
+                Output::Columns(crate::momentum_indicators::momentum_bulk_mcginley_dynamic_commodity_channel_index_columns(prices, job.number("previousMcginleyDynamic")?, job.deviation_model("deviationModel")?, job.number("constantMultiplier")?, job.integer("period")?)?)
+            }
+            "momentumIndicators.macdLine" => {
+                let [prices] = job.inputs()?;
+                Output::Series(crate::momentum_indicators::momentum_bulk_macd_line(
+                    prices,
+                    job.integer("shortPeriod")?,
+                    job.family_model("shortPeriodModel")?,
+                    job.integer("longPeriod")?,
+                    job.family_model("longPeriodModel")?,
+                )?)
+            }
+            "momentumIndicators.signalLine" => {
+                let [macds] = job.inputs()?;
+                Output::Series(crate::momentum_indicators::momentum_bulk_signal_line(
+                    macds,
+                    job.family_model("constantModelType")?,
+                    job.integer("period")?,
+                )?)
+            }
+            "momentumIndicators.mcginleyDynamicMacdLine" => {
+                let [prices] = job.inputs()?;
+                Output::Columns(
+                    crate::momentum_indicators::momentum_bulk_mcginley_dynamic_macd_line_columns(
+                        prices,
+                        job.integer("shortPeriod")?,
+                        job.number("previousShortMcginley")?,
+                        job.integer("longPeriod")?,
+                        job.number("previousLongMcginley")?,
+                    )?,
+                )
+            }
+            "momentumIndicators.chaikinOscillator" => {
+                let [highs, lows, close, volume] = job.inputs()?;
+                Output::Columns(
+                    crate::momentum_indicators::momentum_bulk_chaikin_oscillator_columns(
+                        highs,
+                        lows,
+                        close,
+                        volume,
+                        job.integer("shortPeriod")?,
+                        job.integer("longPeriod")?,
+                        job.number("previousAccumulationDistribution")?,
+                        job.constant_model("shortPeriodModel")?,
+                        job.constant_model("longPeriodModel")?,
+                    )?,
+                )
+            }
+            "momentumIndicators.percentagePriceOscillator" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::momentum_indicators::momentum_bulk_percentage_price_oscillator(
+                        prices,
+                        job.integer("shortPeriod")?,
+                        job.integer("longPeriod")?,
+                        job.constant_model("constantModelType")?,
+                    )?,
+                )
+            }
+            "momentumIndicators.chandeMomentumOscillator" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::momentum_indicators::momentum_bulk_chande_momentum_oscillator(
+                        prices,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "movingAverage.movingAverage" => {
+                let [prices] = job.inputs()?;
+                let ma_type = job.moving_average("maType")?;
+                // Only VolumeWeighted takes the volume
+                let volume = match ma_type.as_f64() {
+                    Some(n) if n == crate::MovingAverageType::VolumeWeighted as u32 as f64 => {
+                        job.optional(0)?.map(<[f64]>::to_vec)
+                    }
+                    _ => None,
+                };
+                Output::Series(crate::moving_average::ma_bulk_moving_average(
+                    prices,
+                    ma_type,
+                    job.integer("period")?,
+                    volume,
+                )?)
+            }
+            "movingAverage.mcginleyDynamic" => {
+                let [prices] = job.inputs()?;
+                Output::Series(crate::moving_average::ma_bulk_mcginley_dynamic(
+                    prices,
+                    job.number("previousMcginleyDynamic")?,
+                    job.integer("period")?,
+                )?)
+            }
+            "movingAverage.volumeWeightedMovingAverage" => {
+                let [prices, volume] = job.inputs()?;
+                Output::Series(
+                    crate::moving_average::ma_bulk_volume_weighted_moving_average(
+                        prices,
+                        volume,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "movingAverage.kaufmanAdaptiveMovingAverage" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::moving_average::ma_bulk_kaufman_adaptive_moving_average(
+                        prices,
+                        job.number("previousKama")?,
+                        job.integer("period")?,
+                        job.integer("fastPeriod")?,
+                        job.integer("slowPeriod")?,
+                    )?,
+                )
+            }
+            "movingAverage.fractalAdaptiveMovingAverage" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::moving_average::ma_bulk_fractal_adaptive_moving_average(
+                        prices,
+                        job.number("previousFrama")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "movingAverage.variableIndexDynamicAverage" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::moving_average::ma_bulk_variable_index_dynamic_average(
+                        prices,
+                        job.number("previousVidya")?,
+                        job.integer("period")?,
+                        job.integer("cmoPeriod")?,
+                    )?,
+                )
+            }
+            "movingAverage.arnaudLegouxMovingAverage" => {
+                let [prices] = job.inputs()?;
+                Output::Series(crate::moving_average::ma_bulk_arnaud_legoux_moving_average(
+                    prices,
+                    job.integer("period")?,
+                    job.number("offset")?,
+                    job.number("sigma")?,
+                )?)
+            }
+            "movingAverage.tillsonT3" => {
+                let [prices] = job.inputs()?;
+                Output::Series(crate::moving_average::ma_bulk_tillson_t3(
+                    prices,
+                    job.integer("period")?,
+                    job.number("volumeFactor")?,
+                )?)
+            }
+            "otherIndicators.returnOnInvestment" => {
+                let [prices] = job.inputs()?;
+                Output::Columns(
+                    crate::other_indicators::other_bulk_return_on_investment_columns(
+                        prices,
+                        job.number("investment")?,
+                    )?,
+                )
+            }
+            "otherIndicators.trueRange" => {
+                let [close, high, low] = job.inputs()?;
+                Output::Series(crate::other_indicators::other_bulk_true_range(
+                    close, high, low,
+                )?)
+            }
+            "otherIndicators.averageTrueRange" => {
+                let [close, high, low] = job.inputs()?;
+                Output::Series(crate::other_indicators::other_bulk_average_true_range(
+                    close,
+                    high,
+                    low,
+                    job.constant_model("constantModelType")?,
+                    job.integer("period")?,
+                )?)
+            }
+            "otherIndicators.internalBarStrength" => {
+                let [high, low, close] = job.inputs()?;
+                Output::Series(crate::other_indicators::other_bulk_internal_bar_strength(
+                    high, low, close,
+                )?)
+            }
+            "standardIndicators.simpleMovingAverage" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::standard_indicators::standard_bulk_simple_moving_average(
+                        prices,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "standardIndicators.smoothedMovingAverage" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::standard_indicators::standard_bulk_smoothed_moving_average(
+                        prices,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "standardIndicators.exponentialMovingAverage" => {
+                let [prices] = job.inputs()?;
+                Output::Series(
+                    crate::standard_indicators::standard_bulk_exponential_moving_average(
+                        prices,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "standardIndicators.bollingerBands" => {
+                let [prices] = job.inputs()?;
+                Output::Columns(
+                    crate::standard_indicators::standard_bulk_bollinger_bands_columns(prices)?,
+                )
+            }
+            "standardIndicators.macd" => {
+                let [prices] = job.inputs()?;
+                Output::Columns(crate::standard_indicators::standard_bulk_macd_columns(
+                    prices,
+                )?)
+            }
+            "standardIndicators.rsi" => {
+                let [prices] = job.inputs()?;
+                Output::Series(crate::standard_indicators::standard_bulk_rsi(prices)?)
+            }
+            "strengthIndicators.accumulationDistribution" => {
+                let [high, low, close, volume] = job.inputs()?;
+                Output::Series(
+                    crate::strength_indicators::strength_bulk_accumulation_distribution(
+                        high,
+                        low,
+                        close,
+                        volume,
+                        job.number("previousAccumulationDistribution")?,
+                    )?,
+                )
+            }
+            "strengthIndicators.positiveVolumeIndex" => {
+                let [close, volume] = job.inputs()?;
+                Output::Series(
+                    crate::strength_indicators::strength_bulk_positive_volume_index(
+                        close,
+                        volume,
+                        job.number("previousPositiveVolumeIndex")?,
+                    )?,
+                )
+            }
+            "strengthIndicators.negativeVolumeIndex" => {
+                let [close, volume] = job.inputs()?;
+                Output::Series(
+                    crate::strength_indicators::strength_bulk_negative_volume_index(
+                        close,
+                        volume,
+                        job.number("previousNegativeVolumeIndex")?,
+                    )?,
+                )
+            }
+            "strengthIndicators.relativeVigorIndex" => {
+                let [open, high, low, close] = job.inputs()?;
+                Output::Series(
+                    crate::strength_indicators::strength_bulk_relative_vigor_index(
+                        open,
+                        high,
+                        low,
+                        close,
+                        job.constant_model("constantModelType")?,
+                        job.integer("period")?,
+                    )?,
+                )
+            }
+            "trendIndicators.aroonUp" => {
+                let [highs] = job.inputs()?;
+                Output::Series(crate::trend_indicators::trend_bulk_aroon_up(
+                    highs,
+                    job.integer("period")?,
+                )?)
+            }
+            "trendIndicators.aroonDown" => {
+                let [lows] = job.inputs()?;
+                Output::Series(crate::trend_indicators::trend_bulk_aroon_down(
+                    lows,
+                    job.integer("period")?,
+                )?)
+            }
+            "trendIndicators.aroonOscillator" => {
+                let [aroon_up, aroon_down] = job.inputs()?;
+                Output::Series(crate::trend_indicators::trend_bulk_aroon_oscillator(
+                    aroon_up, aroon_down,
+                )?)
+            }
+            "trendIndicators.aroonIndicator" => {
+                let [highs, lows] = job.inputs()?;
+                Output::Columns(crate::trend_indicators::trend_bulk_aroon_indicator_columns(
+                    highs,
+                    lows,
+                    job.integer("period")?,
+                )?)
+            }
+            "trendIndicators.parabolicTimePriceSystem" => {
+                let [highs, lows] = job.inputs()?;
+                Output::Series(
+                    crate::trend_indicators::trend_bulk_parabolic_time_price_system(
+                        highs,
+                        lows,
+                        job.number("accelerationFactorStart")?,
+                        job.number("accelerationFactorMax")?,
+                        job.number("accelerationFactorStep")?,
+                        job.position("startPosition")?,
+                        job.number("previousSar")?,
+                    )?,
+                )
+            }
+            "trendIndicators.directionalMovementSystem" => {
+                let [highs, lows, close] = job.inputs()?;
+                Output::Columns(
+                    crate::trend_indicators::trend_bulk_directional_movement_system_columns(
+                        highs,
+                        lows,
+                        close,
+                        job.integer("period")?,
+                        job.constant_model("constantModelType")?,
+                    )?,
+                )
+            }
+            "trendIndicators.volumePriceTrend" => {
+                let [prices, volumes] = job.inputs()?;
+                Output::Series(crate::trend_indicators::trend_bulk_volume_price_trend(
+                    prices,
+                    volumes,
+                    job.number("previousVolumePriceTrend")?,
+                )?)
+            }
+            "trendIndicators.trueStrengthIndex" => {
+                let [prices] = job.inputs()?;
+                Output::Series(crate::trend_indicators::trend_bulk_true_strength_index(
+                    prices,
+                    job.constant_model("firstConstantModel")?,
+                    job.integer("firstPeriod")?,
+                    job.constant_model("secondConstantModel")?,
+                    job.integer("secondPeriod")?,
+                )?)
+            }
+            "volatilityIndicators.ulcerIndex" => {
+                let [prices] = job.inputs()?;
+                Output::Series(crate::volatility_indicators::volatility_bulk_ulcer_index(
+                    prices,
+                    job.integer("period")?,
+                )?)
+            }
+            "volatilityIndicators.volatilitySystem" => {
+                let [high, low, close] = job.inputs()?;
+                Output::Series(
+                    crate::volatility_indicators::volatility_bulk_volatility_system(
+                        high,
+                        low,
+                        close,
+                        job.integer("period")?,
+                        job.number("constantMultiplier")?,
+                        job.constant_model("constantModelType")?,
+                    )?,
+                )
+            }
+
+            "otherIndicators.positivityIndicator" => {
+                let signal_period = job.integer("signalPeriod")?;
+                let constant_model_type = job.constant_model("constantModelType")?;
+                let data = if job.sources.is_some() {
+                    let [open, previous_close] = job.inputs()?;
+                    crate::other_indicators::positivity_indicator_rows(
+                        open,
+                        previous_close,
+                        signal_period,
+                        constant_model_type,
+                    )?
+                } else {
+                    // Candles: each open against the previous bar's close
+                    crate::other_indicators::positivity_indicator_bar_rows(
+                        job.column("open")?,
+                        job.column("close")?,
+                        signal_period,
+                        constant_model_type,
+                    )?
+                };
+                Output::Columns(columns(
+                    ["positivityIndicator", "signal"],
+                    data.into_iter().map(|(pi, sig)| [pi, sig]),
+                ))
+            }
+            _ => {
+                return Err(IndicatorError::new(
+                    ErrorCode::InvalidParameter,
+                    "indicator",
+                    format!("indicator ({}) has no bulk function", indicator),
+                ))
+            }
+        })
+    }
+
+    // Input arrays named by the registry's bulk signature
+    fn inputs<const N: usize>(&self) -> IndicatorResult<[&'a [f64]; N]> {
+        let inputs = match &self.prepared {
+            Some(inputs) => inputs.clone(),
+            None => self.resolve()?,
+        };
+        debug_assert!(inputs.len() >= N, "bulk inputs of {}", self.info.name);
+        Ok(std::array::from_fn(|i| {
+            inputs.get(i).copied().unwrap_or(&[])
+        }))
+    }
+
+    // Optional input array `index` of the registry's bulk signature, if it was resolved
+    fn optional(&self, index: usize) -> IndicatorResult<Option<&'a [f64]>> {
+        let inputs = match &self.prepared {
+            Some(inputs) => inputs.clone(),
+            None => self.resolve()?,
+        };
+        let required = self.info.bulk.map_or(0, |bulk| bulk.inputs.len());
+        Ok(inputs.get(required + index).copied())
+    }
+
+    /// The input arrays of the registry's bulk signature: `inputs` overrides in order, otherwise
+    /// the column matching the input name. Optional inputs follow while they have a source or a
+    /// column of their name.
+    pub(crate) fn resolve(&self) -> IndicatorResult<Vec<&'a [f64]>> {
+        let (names, optional) = self
+            .info
+            .bulk
+            .map_or((&[][..], &[][..]), |bulk| (bulk.inputs, bulk.optional));
+        let mut out = Vec::with_capacity(names.len() + optional.len());
+        for (i, name) in names.iter().chain(optional).copied().enumerate() {
+            out.push(match self.sources.as_ref().and_then(|s| s.get(i)) {
+                Some(source) => self.source(source)?,
+                None if i >= names.len() => match self.series.column(name) {
+                    Some(column) => column,
+                    None => break,
+                },
+                None => match name {
+                    "prices" | "close" => self.column("close")?,
+                    "highs" | "high" => self.column("high")?,
+                    "lows" | "low" => self.column("low")?,
+                    "open" => self.column("open")?,
+                    "volume" => self.column("volume")?,
+                    // One volume per price change
+                    "volumes" => self.column("volume")?.get(1..).unwrap_or(&[]),
+                    _ => {
+                        return Err(IndicatorError::new(
+                            ErrorCode::InvalidParameter,
+                            "inputs",
+                            format!("{} is not a candle column, name its source in inputs", name),
+                        ))
+                    }
+                },
+            });
+        }
+        Ok(out)
+    }
+
+    fn column(&self, name: &str) -> IndicatorResult<&'a [f64]> {
+        self.series.column(name).ok_or_else(|| {
+            IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                "series",
+                format!("series.{} is required by {}", name, self.info.name),
+            )
+        })
+    }
+
+    fn source(&self, source: &str) -> IndicatorResult<&'a [f64]> {
+        if COLUMNS.contains(&source) {
+            return self.column(source);
+        }
+        self.results
+            .iter()
+            .find(|(id, _)| id == source)
+            .map(|(_, values)| values.as_slice())
+            .ok_or_else(|| {
+                IndicatorError::new(
+                    ErrorCode::InvalidParameter,
+                    "inputs",
+                    format!(
+                        "input ({}) is neither a column nor an earlier single-series result",
+                        source
+                    ),
+                )
+            })
+    }
+
+    // -------- PARAMETERS --------
+
+    fn value(&self, name: &'static str) -> IndicatorResult<JsValue> {
+        if let Some((_, value)) = self.overrides.iter().find(|(n, _)| n == name) {
+            return Ok(value.clone());
+        }
+        let value = get(&self.params, name);
+        if !value.is_undefined() && !value.is_null() {
+            return Ok(value);
+        }
+        let default = self
+            .info
+            .params
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| p.default);
+        match default {
+            Some(ParamDefault::Number(v)) => Ok(JsValue::from_f64(v)),
+            Some(ParamDefault::Choice(v)) => Ok(JsValue::from_str(v)),
+            _ => Err(IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                name,
+                format!("{} is required", name),
+            )),
+        }
+    }
+
+    fn number(&self, name: &'static str) -> IndicatorResult<f64> {
+        let value = self.value(name)?;
+        value.as_f64().ok_or_else(|| {
+            IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                name,
+                format!("{} must be a number", name),
+            )
+        })
+    }
+
+    fn integer(&self, name: &'static str) -> IndicatorResult<usize> {
+        let value = self.number(name)?;
+        if !(value >= 0.0 && value.fract() == 0.0 && value <= u32::MAX as f64) {
+            return Err(IndicatorError::invalid_parameter(
+                name,
+                value,
+                "must be a whole number",
+            ));
+        }
+        Ok(value as usize)
+    }
+
+    // Index of an enum variant given as its JS value or its name
+    fn choice(
+        &self,
+        name: &'static str,
+        kind: ParamType,
+        value: &JsValue,
+    ) -> IndicatorResult<usize> {
+        let choices = kind.choices();
+        let index = match (value.as_f64(), value.as_string()) {
+            (Some(n), _) => {
+                (n.fract() == 0.0 && n >= 0.0 && n < choices.len() as f64).then_some(n as usize)
+            }
+            (_, Some(s)) => choices.iter().position(|c| *c == s),
+            _ => None,
+        };
+        index.ok_or_else(|| {
+            IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                name,
+                format!("{} must be one of {}", name, choices.join(", ")),
+            )
+        })
+    }
+
+    fn constant_model(&self, name: &'static str) -> IndicatorResult<crate::ConstantModelLike> {
+        let value = self.value(name)?;
+        if value.is_object() {
+            // Personalised model, validated by the binding
+            return Ok(value.unchecked_into());
+        }
+        let index = self.choice(name, ParamType::ConstantModelType, &value)?;
+        Ok(JsValue::from_f64(index as f64).unchecked_into())
+    }
+
+    fn family_model(&self, name: &'static str) -> IndicatorResult<crate::FamilyModelLike> {
+        let value = self.value(name)?;
+        if value.is_object() {
+            return Ok(value.unchecked_into());
+        }
+        // The family values follow on from the ConstantModelType values
+        let index = self.choice(name, ParamType::ConstantModelFamily, &value)?;
+        Ok(JsValue::from_f64(index as f64).unchecked_into())
+    }
+
+    fn moving_average(&self, name: &'static str) -> IndicatorResult<crate::MovingAverageLike> {
+        let value = self.value(name)?;
+        if value.is_object() {
+            return Ok(value.unchecked_into());
+        }
+        let index = self.choice(name, ParamType::MovingAverageType, &value)?;
+        Ok(JsValue::from_f64(index as f64).unchecked_into())
+    }
+
+    fn deviation_model(&self, name: &'static str) -> IndicatorResult<crate::DeviationModel> {
+        use crate::DeviationModel::*;
+        let value = self.value(name)?;
+        let index = self.choice(name, ParamType::DeviationModel, &value)?;
+        Ok([
+            StandardDeviation,
+            MeanAbsoluteDeviation,
+            MedianAbsoluteDeviation,
+            ModeAbsoluteDeviation,
+            UlcerIndex,
+            LogStandardDeviation,
+            LaplaceStdEquivalent,
+            CauchyIQRScale,
+        ][index])
+    }
+
+    fn position(&self, name: &'static str) -> IndicatorResult<crate::Position> {
+        let value = self.value(name)?;
+        let index = self.choice(name, ParamType::Position, &value)?;
+        Ok([crate::Position::Long, crate::Position::Short][index])
+    }
+}
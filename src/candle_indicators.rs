@@ -1,175 +1,411 @@
-use js_sys::Array;
+use crate::columns::columns;
+use crate::error::{
+    check_deviation_model, check_not_empty, check_period, check_same_length, IndicatorResult,
+};
+use crate::missing::{bulk, bulk_seeded};
+use crate::points::{BandPoint, IchimokuPoint, McGinleyBandPoint};
+use js_sys::{Array, Object};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsValue;
 
+// (spanA, spanB, base, conversion, displacedClose)
+type IchimokuRow = (f64, f64, f64, f64, f64);
+
 // ------------- SINGLE -------------
+fn moving_constant_envelopes_value(
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
+    difference: f64,
+) -> IndicatorResult<(f64, f64, f64)> {
+    check_not_empty("prices", prices)?;
+    let constant_model = crate::constant_model("constantModelType", &constant_model_type)?;
+    Ok(crate::averages::moving_constant_envelopes(
+        prices,
+        constant_model,
+        difference,
+    ))
+}
+
 #[wasm_bindgen(js_name = candle_single_movingConstantEnvelopes)]
 pub fn candle_single_moving_constant_envelopes(
-    prices: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
     difference: f64,
-) -> Array {
-    let (l, m, u) = rust_ti::candle_indicators::single::moving_constant_envelopes(
-        &prices,
-        constant_model_type.into(),
-        difference,
-    );
+) -> IndicatorResult<Array> {
+    let (l, m, u) = moving_constant_envelopes_value(prices, constant_model_type, difference)?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(l));
     arr.push(&JsValue::from_f64(m));
     arr.push(&JsValue::from_f64(u));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = candle_single_movingConstantEnvelopesPoint)]
+pub fn candle_single_moving_constant_envelopes_point(
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
+    difference: f64,
+) -> IndicatorResult<BandPoint> {
+    Ok(moving_constant_envelopes_value(prices, constant_model_type, difference)?.into())
+}
+
+fn mcginley_dynamic_envelopes_value(
+    prices: &[f64],
+    difference: f64,
+    previous_mcginley_dynamic: f64,
+) -> IndicatorResult<(f64, f64, f64)> {
+    check_not_empty("prices", prices)?;
+    Ok(
+        rust_ti::candle_indicators::single::mcginley_dynamic_envelopes(
+            prices,
+            difference,
+            previous_mcginley_dynamic,
+        ),
+    )
 }
 
 #[wasm_bindgen(js_name = candle_single_mcginleyDynamicEnvelopes)]
 pub fn candle_single_mcginley_dynamic_envelopes(
-    prices: Vec<f64>,
+    prices: &[f64],
     difference: f64,
     previous_mcginley_dynamic: f64,
-) -> Array {
-    let (l, m, u) = rust_ti::candle_indicators::single::mcginley_dynamic_envelopes(
-        &prices,
-        difference,
-        previous_mcginley_dynamic,
-    );
+) -> IndicatorResult<Array> {
+    let (l, m, u) =
+        mcginley_dynamic_envelopes_value(prices, difference, previous_mcginley_dynamic)?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(l));
     arr.push(&JsValue::from_f64(m));
     arr.push(&JsValue::from_f64(u));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = candle_single_mcginleyDynamicEnvelopesPoint)]
+pub fn candle_single_mcginley_dynamic_envelopes_point(
+    prices: &[f64],
+    difference: f64,
+    previous_mcginley_dynamic: f64,
+) -> IndicatorResult<McGinleyBandPoint> {
+    Ok(mcginley_dynamic_envelopes_value(prices, difference, previous_mcginley_dynamic)?.into())
+}
+
+fn moving_constant_bands_value(
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+) -> IndicatorResult<(f64, f64, f64)> {
+    check_not_empty("prices", prices)?;
+    check_deviation_model(deviation_model, prices.len(), prices)?;
+    let constant_model = crate::constant_model("constantModelType", &constant_model_type)?;
+    Ok(crate::averages::moving_constant_bands(
+        prices,
+        constant_model,
+        deviation_model,
+        deviation_multiplier,
+    ))
 }
 
 #[wasm_bindgen(js_name = candle_single_movingConstantBands)]
 pub fn candle_single_moving_constant_bands(
-    prices: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
     deviation_model: crate::DeviationModel,
     deviation_multiplier: f64,
-) -> Array {
-    let (l, m, u) = rust_ti::candle_indicators::single::moving_constant_bands(
-        &prices,
-        constant_model_type.into(),
-        deviation_model.into(),
+) -> IndicatorResult<Array> {
+    let (l, m, u) = moving_constant_bands_value(
+        prices,
+        constant_model_type,
+        deviation_model,
         deviation_multiplier,
-    );
+    )?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(l));
     arr.push(&JsValue::from_f64(m));
     arr.push(&JsValue::from_f64(u));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = candle_single_movingConstantBandsPoint)]
+pub fn candle_single_moving_constant_bands_point(
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+) -> IndicatorResult<BandPoint> {
+    Ok(moving_constant_bands_value(
+        prices,
+        constant_model_type,
+        deviation_model,
+        deviation_multiplier,
+    )?
+    .into())
+}
+
+fn mcginley_dynamic_bands_value(
+    prices: &[f64],
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+    previous_mcginley_dynamic: f64,
+) -> IndicatorResult<(f64, f64, f64)> {
+    check_not_empty("prices", prices)?;
+    check_deviation_model(deviation_model, prices.len(), prices)?;
+    Ok(rust_ti::candle_indicators::single::mcginley_dynamic_bands(
+        prices,
+        deviation_model.into(),
+        deviation_multiplier,
+        previous_mcginley_dynamic,
+    ))
 }
 
 #[wasm_bindgen(js_name = candle_single_mcginleyDynamicBands)]
 pub fn candle_single_mcginley_dynamic_bands(
-    prices: Vec<f64>,
+    prices: &[f64],
     deviation_model: crate::DeviationModel,
     deviation_multiplier: f64,
     previous_mcginley_dynamic: f64,
-) -> Array {
-    let (l, m, u) = rust_ti::candle_indicators::single::mcginley_dynamic_bands(
-        &prices,
-        deviation_model.into(),
+) -> IndicatorResult<Array> {
+    let (l, m, u) = mcginley_dynamic_bands_value(
+        prices,
+        deviation_model,
         deviation_multiplier,
         previous_mcginley_dynamic,
-    );
+    )?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(l));
     arr.push(&JsValue::from_f64(m));
     arr.push(&JsValue::from_f64(u));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = candle_single_mcginleyDynamicBandsPoint)]
+pub fn candle_single_mcginley_dynamic_bands_point(
+    prices: &[f64],
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+    previous_mcginley_dynamic: f64,
+) -> IndicatorResult<McGinleyBandPoint> {
+    Ok(mcginley_dynamic_bands_value(
+        prices,
+        deviation_model,
+        deviation_multiplier,
+        previous_mcginley_dynamic,
+    )?
+    .into())
+}
+
+fn ichimoku_cloud_value(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    conversion_period: usize,
+    base_period: usize,
+    span_b_period: usize,
+) -> IndicatorResult<IchimokuRow> {
+    check_same_length("highs", highs.len(), "lows", lows.len())?;
+    check_same_length("highs", highs.len(), "close", close.len())?;
+    check_period("conversionPeriod", conversion_period, highs.len())?;
+    check_period("basePeriod", base_period, highs.len())?;
+    check_period("spanBPeriod", span_b_period, highs.len())?;
+    Ok(rust_ti::candle_indicators::single::ichimoku_cloud(
+        highs,
+        lows,
+        close,
+        conversion_period,
+        base_period,
+        span_b_period,
+    ))
 }
 
 #[wasm_bindgen(js_name = candle_single_ichimokuCloud)]
 pub fn candle_single_ichimoku_cloud(
-    highs: Vec<f64>,
-    lows: Vec<f64>,
-    close: Vec<f64>,
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
     conversion_period: usize,
     base_period: usize,
     span_b_period: usize,
-) -> Array {
-    let (a, b, base, conv, displaced_close) = rust_ti::candle_indicators::single::ichimoku_cloud(
-        &highs,
-        &lows,
-        &close,
+) -> IndicatorResult<Array> {
+    let (a, b, base, conv, displaced_close) = ichimoku_cloud_value(
+        highs,
+        lows,
+        close,
         conversion_period,
         base_period,
         span_b_period,
-    );
+    )?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(a));
     arr.push(&JsValue::from_f64(b));
     arr.push(&JsValue::from_f64(base));
     arr.push(&JsValue::from_f64(conv));
     arr.push(&JsValue::from_f64(displaced_close));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = candle_single_ichimokuCloudPoint)]
+pub fn candle_single_ichimoku_cloud_point(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    conversion_period: usize,
+    base_period: usize,
+    span_b_period: usize,
+) -> IndicatorResult<IchimokuPoint> {
+    Ok(ichimoku_cloud_value(
+        highs,
+        lows,
+        close,
+        conversion_period,
+        base_period,
+        span_b_period,
+    )?
+    .into())
+}
+
+fn donchian_channels_value(highs: &[f64], lows: &[f64]) -> IndicatorResult<(f64, f64, f64)> {
+    check_not_empty("highs", highs)?;
+    check_same_length("highs", highs.len(), "lows", lows.len())?;
+    Ok(rust_ti::candle_indicators::single::donchian_channels(
+        highs, lows,
+    ))
 }
 
 #[wasm_bindgen(js_name = candle_single_donchianChannels)]
-pub fn candle_single_donchian_channels(highs: Vec<f64>, lows: Vec<f64>) -> Array {
-    let (l, m, u) = rust_ti::candle_indicators::single::donchian_channels(&highs, &lows);
+pub fn candle_single_donchian_channels(highs: &[f64], lows: &[f64]) -> IndicatorResult<Array> {
+    let (l, m, u) = donchian_channels_value(highs, lows)?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(l));
     arr.push(&JsValue::from_f64(m));
     arr.push(&JsValue::from_f64(u));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = candle_single_donchianChannelsPoint)]
+pub fn candle_single_donchian_channels_point(
+    highs: &[f64],
+    lows: &[f64],
+) -> IndicatorResult<BandPoint> {
+    Ok(donchian_channels_value(highs, lows)?.into())
+}
+
+fn keltner_channel_value(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+    atr_constant_model_type: crate::ConstantModelLike,
+    multiplier: f64,
+) -> IndicatorResult<(f64, f64, f64)> {
+    check_not_empty("highs", highs)?;
+    check_same_length("highs", highs.len(), "lows", lows.len())?;
+    check_same_length("highs", highs.len(), "close", close.len())?;
+    let constant_model_type =
+        crate::constant_model_type("constantModelType", &constant_model_type)?;
+    let atr_constant_model_type =
+        crate::constant_model_type("atrConstantModelType", &atr_constant_model_type)?;
+    Ok(rust_ti::candle_indicators::single::keltner_channel(
+        highs,
+        lows,
+        close,
+        constant_model_type,
+        atr_constant_model_type,
+        multiplier,
+    ))
 }
 
 #[wasm_bindgen(js_name = candle_single_keltnerChannel)]
 pub fn candle_single_keltner_channel(
-    highs: Vec<f64>,
-    lows: Vec<f64>,
-    close: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
-    atr_constant_model_type: crate::ConstantModelType,
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+    atr_constant_model_type: crate::ConstantModelLike,
     multiplier: f64,
-) -> Array {
-    let (l, m, u) = rust_ti::candle_indicators::single::keltner_channel(
-        &highs,
-        &lows,
-        &close,
-        constant_model_type.into(),
-        atr_constant_model_type.into(),
+) -> IndicatorResult<Array> {
+    let (l, m, u) = keltner_channel_value(
+        highs,
+        lows,
+        close,
+        constant_model_type,
+        atr_constant_model_type,
         multiplier,
-    );
+    )?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(l));
     arr.push(&JsValue::from_f64(m));
     arr.push(&JsValue::from_f64(u));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = candle_single_keltnerChannelPoint)]
+pub fn candle_single_keltner_channel_point(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+    atr_constant_model_type: crate::ConstantModelLike,
+    multiplier: f64,
+) -> IndicatorResult<BandPoint> {
+    Ok(keltner_channel_value(
+        highs,
+        lows,
+        close,
+        constant_model_type,
+        atr_constant_model_type,
+        multiplier,
+    )?
+    .into())
 }
 
 #[wasm_bindgen(js_name = candle_single_supertrend)]
 pub fn candle_single_supertrend(
-    highs: Vec<f64>,
-    lows: Vec<f64>,
-    close: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    constant_model_type: crate::ConstantModelLike,
     multiplier: f64,
-) -> f64 {
-    rust_ti::candle_indicators::single::supertrend(
-        &highs,
-        &lows,
-        &close,
-        constant_model_type.into(),
+) -> IndicatorResult<f64> {
+    check_not_empty("highs", highs)?;
+    check_same_length("highs", highs.len(), "lows", lows.len())?;
+    check_same_length("highs", highs.len(), "close", close.len())?;
+    let constant_model_type =
+        crate::constant_model_type("constantModelType", &constant_model_type)?;
+    Ok(rust_ti::candle_indicators::single::supertrend(
+        highs,
+        lows,
+        close,
+        constant_model_type,
         multiplier,
-    )
+    ))
 }
 
 // ------------- BULK -------------
+fn moving_constant_envelopes_rows(
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
+    difference: f64,
+    period: usize,
+) -> IndicatorResult<Vec<(f64, f64, f64)>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        let constant_model = crate::constant_model("constantModelType", &constant_model_type)?;
+        Ok(prices
+            .windows(period)
+            .map(|window| {
+                crate::averages::moving_constant_envelopes(window, constant_model, difference)
+            })
+            .collect())
+    })
+}
+
 #[wasm_bindgen(js_name = candle_bulk_movingConstantEnvelopes)]
 pub fn candle_bulk_moving_constant_envelopes(
-    prices: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
     difference: f64,
     period: usize,
-) -> Array {
-    let data = rust_ti::candle_indicators::bulk::moving_constant_envelopes(
-        &prices,
-        constant_model_type.into(),
-        difference,
-        period,
-    );
+) -> IndicatorResult<Array> {
+    let data = moving_constant_envelopes_rows(prices, constant_model_type, difference, period)?;
     let outer = Array::new();
     for (l, m, u) in data {
         let inner = Array::new();
@@ -178,22 +414,57 @@ pub fn candle_bulk_moving_constant_envelopes(
         inner.push(&JsValue::from_f64(u));
         outer.push(&inner);
     }
-    outer
+    Ok(outer)
+}
+
+#[wasm_bindgen(js_name = candle_bulk_movingConstantEnvelopesColumns)]
+pub fn candle_bulk_moving_constant_envelopes_columns(
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
+    difference: f64,
+    period: usize,
+) -> IndicatorResult<Object> {
+    let data = moving_constant_envelopes_rows(prices, constant_model_type, difference, period)?;
+    Ok(columns(
+        ["lower", "middle", "upper"],
+        data.into_iter().map(|(l, m, u)| [l, m, u]),
+    ))
+}
+
+fn mcginley_dynamic_envelopes_rows(
+    prices: &[f64],
+    difference: f64,
+    previous_mcginley_dynamic: f64,
+    period: usize,
+) -> IndicatorResult<Vec<(f64, f64, f64)>> {
+    bulk_seeded(
+        ["prices"],
+        [prices],
+        previous_mcginley_dynamic,
+        |(_, mcginley, _)| *mcginley,
+        |[prices], previous_mcginley_dynamic| {
+            check_period("period", period, prices.len())?;
+            Ok(
+                rust_ti::candle_indicators::bulk::mcginley_dynamic_envelopes(
+                    prices,
+                    difference,
+                    previous_mcginley_dynamic,
+                    period,
+                ),
+            )
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = candle_bulk_mcginleyDynamicEnvelopes)]
 pub fn candle_bulk_mcginley_dynamic_envelopes(
-    prices: Vec<f64>,
+    prices: &[f64],
     difference: f64,
     previous_mcginley_dynamic: f64,
     period: usize,
-) -> Array {
-    let data = rust_ti::candle_indicators::bulk::mcginley_dynamic_envelopes(
-        &prices,
-        difference,
-        previous_mcginley_dynamic,
-        period,
-    );
+) -> IndicatorResult<Array> {
+    let data =
+        mcginley_dynamic_envelopes_rows(prices, difference, previous_mcginley_dynamic, period)?;
     let outer = Array::new();
     for (l, m, u) in data {
         let inner = Array::new();
@@ -202,24 +473,64 @@ pub fn candle_bulk_mcginley_dynamic_envelopes(
         inner.push(&JsValue::from_f64(u));
         outer.push(&inner);
     }
-    outer
+    Ok(outer)
+}
+
+#[wasm_bindgen(js_name = candle_bulk_mcginleyDynamicEnvelopesColumns)]
+pub fn candle_bulk_mcginley_dynamic_envelopes_columns(
+    prices: &[f64],
+    difference: f64,
+    previous_mcginley_dynamic: f64,
+    period: usize,
+) -> IndicatorResult<Object> {
+    let data =
+        mcginley_dynamic_envelopes_rows(prices, difference, previous_mcginley_dynamic, period)?;
+    Ok(columns(
+        ["lower", "mcginley", "upper"],
+        data.into_iter().map(|(l, m, u)| [l, m, u]),
+    ))
+}
+
+fn moving_constant_bands_rows(
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+    period: usize,
+) -> IndicatorResult<Vec<(f64, f64, f64)>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        check_deviation_model(deviation_model, period, prices)?;
+        let constant_model = crate::constant_model("constantModelType", &constant_model_type)?;
+        Ok(prices
+            .windows(period)
+            .map(|window| {
+                crate::averages::moving_constant_bands(
+                    window,
+                    constant_model,
+                    deviation_model,
+                    deviation_multiplier,
+                )
+            })
+            .collect())
+    })
 }
 
 #[wasm_bindgen(js_name = candle_bulk_movingConstantBands)]
 pub fn candle_bulk_moving_constant_bands(
-    prices: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
     deviation_model: crate::DeviationModel,
     deviation_multiplier: f64,
     period: usize,
-) -> Array {
-    let data = rust_ti::candle_indicators::bulk::moving_constant_bands(
-        &prices,
-        constant_model_type.into(),
-        deviation_model.into(),
+) -> IndicatorResult<Array> {
+    let data = moving_constant_bands_rows(
+        prices,
+        constant_model_type,
+        deviation_model,
         deviation_multiplier,
         period,
-    );
+    )?;
     let outer = Array::new();
     for (l, m, u) in data {
         let inner = Array::new();
@@ -228,24 +539,71 @@ pub fn candle_bulk_moving_constant_bands(
         inner.push(&JsValue::from_f64(u));
         outer.push(&inner);
     }
-    outer
+    Ok(outer)
+}
+
+#[wasm_bindgen(js_name = candle_bulk_movingConstantBandsColumns)]
+pub fn candle_bulk_moving_constant_bands_columns(
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+    period: usize,
+) -> IndicatorResult<Object> {
+    let data = moving_constant_bands_rows(
+        prices,
+        constant_model_type,
+        deviation_model,
+        deviation_multiplier,
+        period,
+    )?;
+    Ok(columns(
+        ["lower", "middle", "upper"],
+        data.into_iter().map(|(l, m, u)| [l, m, u]),
+    ))
+}
+
+fn mcginley_dynamic_bands_rows(
+    prices: &[f64],
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+    previous_mcginley_dynamic: f64,
+    period: usize,
+) -> IndicatorResult<Vec<(f64, f64, f64)>> {
+    bulk_seeded(
+        ["prices"],
+        [prices],
+        previous_mcginley_dynamic,
+        |(_, mcginley, _)| *mcginley,
+        |[prices], previous_mcginley_dynamic| {
+            check_period("period", period, prices.len())?;
+            check_deviation_model(deviation_model, period, prices)?;
+            Ok(rust_ti::candle_indicators::bulk::mcginley_dynamic_bands(
+                prices,
+                deviation_model.into(),
+                deviation_multiplier,
+                previous_mcginley_dynamic,
+                period,
+            ))
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = candle_bulk_mcginleyDynamicBands)]
 pub fn candle_bulk_mcginley_dynamic_bands(
-    prices: Vec<f64>,
+    prices: &[f64],
     deviation_model: crate::DeviationModel,
     deviation_multiplier: f64,
     previous_mcginley_dynamic: f64,
     period: usize,
-) -> Array {
-    let data = rust_ti::candle_indicators::bulk::mcginley_dynamic_bands(
-        &prices,
-        deviation_model.into(),
+) -> IndicatorResult<Array> {
+    let data = mcginley_dynamic_bands_rows(
+        prices,
+        deviation_model,
         deviation_multiplier,
         previous_mcginley_dynamic,
         period,
-    );
+    )?;
     let outer = Array::new();
     for (l, m, u) in data {
         let inner = Array::new();
@@ -254,26 +612,76 @@ pub fn candle_bulk_mcginley_dynamic_bands(
         inner.push(&JsValue::from_f64(u));
         outer.push(&inner);
     }
-    outer
+    Ok(outer)
+}
+
+#[wasm_bindgen(js_name = candle_bulk_mcginleyDynamicBandsColumns)]
+pub fn candle_bulk_mcginley_dynamic_bands_columns(
+    prices: &[f64],
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+    previous_mcginley_dynamic: f64,
+    period: usize,
+) -> IndicatorResult<Object> {
+    let data = mcginley_dynamic_bands_rows(
+        prices,
+        deviation_model,
+        deviation_multiplier,
+        previous_mcginley_dynamic,
+        period,
+    )?;
+    Ok(columns(
+        ["lower", "mcginley", "upper"],
+        data.into_iter().map(|(l, m, u)| [l, m, u]),
+    ))
+}
+
+fn ichimoku_cloud_rows(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    conversion_period: usize,
+    base_period: usize,
+    span_b_period: usize,
+) -> IndicatorResult<Vec<IchimokuRow>> {
+    bulk(
+        ["highs", "lows", "close"],
+        [highs, lows, close],
+        |[highs, lows, close]| {
+            check_same_length("highs", highs.len(), "lows", lows.len())?;
+            check_same_length("highs", highs.len(), "close", close.len())?;
+            check_period("conversionPeriod", conversion_period, highs.len())?;
+            check_period("basePeriod", base_period, highs.len())?;
+            check_period("spanBPeriod", span_b_period, highs.len())?;
+            Ok(rust_ti::candle_indicators::bulk::ichimoku_cloud(
+                highs,
+                lows,
+                close,
+                conversion_period,
+                base_period,
+                span_b_period,
+            ))
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = candle_bulk_ichimokuCloud)]
 pub fn candle_bulk_ichimoku_cloud(
-    highs: Vec<f64>,
-    lows: Vec<f64>,
-    close: Vec<f64>,
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
     conversion_period: usize,
     base_period: usize,
     span_b_period: usize,
-) -> Array {
-    let data = rust_ti::candle_indicators::bulk::ichimoku_cloud(
-        &highs,
-        &lows,
-        &close,
+) -> IndicatorResult<Array> {
+    let data = ichimoku_cloud_rows(
+        highs,
+        lows,
+        close,
         conversion_period,
         base_period,
         span_b_period,
-    );
+    )?;
     let outer = Array::new();
     for (a, b, base, conv, displaced_close) in data {
         let inner = Array::new();
@@ -284,12 +692,54 @@ pub fn candle_bulk_ichimoku_cloud(
         inner.push(&JsValue::from_f64(displaced_close));
         outer.push(&inner);
     }
-    outer
+    Ok(outer)
+}
+
+#[wasm_bindgen(js_name = candle_bulk_ichimokuCloudColumns)]
+pub fn candle_bulk_ichimoku_cloud_columns(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    conversion_period: usize,
+    base_period: usize,
+    span_b_period: usize,
+) -> IndicatorResult<Object> {
+    let data = ichimoku_cloud_rows(
+        highs,
+        lows,
+        close,
+        conversion_period,
+        base_period,
+        span_b_period,
+    )?;
+    Ok(columns(
+        ["spanA", "spanB", "base", "conversion", "displacedClose"],
+        data.into_iter()
+            .map(|(a, b, base, conv, displaced_close)| [a, b, base, conv, displaced_close]),
+    ))
+}
+
+fn donchian_channels_rows(
+    highs: &[f64],
+    lows: &[f64],
+    period: usize,
+) -> IndicatorResult<Vec<(f64, f64, f64)>> {
+    bulk(["highs", "lows"], [highs, lows], |[highs, lows]| {
+        check_same_length("highs", highs.len(), "lows", lows.len())?;
+        check_period("period", period, highs.len())?;
+        Ok(rust_ti::candle_indicators::bulk::donchian_channels(
+            highs, lows, period,
+        ))
+    })
 }
 
 #[wasm_bindgen(js_name = candle_bulk_donchianChannels)]
-pub fn candle_bulk_donchian_channels(highs: Vec<f64>, lows: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::candle_indicators::bulk::donchian_channels(&highs, &lows, period);
+pub fn candle_bulk_donchian_channels(
+    highs: &[f64],
+    lows: &[f64],
+    period: usize,
+) -> IndicatorResult<Array> {
+    let data = donchian_channels_rows(highs, lows, period)?;
     let outer = Array::new();
     for (l, m, u) in data {
         let inner = Array::new();
@@ -298,28 +748,74 @@ pub fn candle_bulk_donchian_channels(highs: Vec<f64>, lows: Vec<f64>, period: us
         inner.push(&JsValue::from_f64(u));
         outer.push(&inner);
     }
-    outer
+    Ok(outer)
+}
+
+#[wasm_bindgen(js_name = candle_bulk_donchianChannelsColumns)]
+pub fn candle_bulk_donchian_channels_columns(
+    highs: &[f64],
+    lows: &[f64],
+    period: usize,
+) -> IndicatorResult<Object> {
+    let data = donchian_channels_rows(highs, lows, period)?;
+    Ok(columns(
+        ["lower", "middle", "upper"],
+        data.into_iter().map(|(l, m, u)| [l, m, u]),
+    ))
+}
+
+fn keltner_channel_rows(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+    atr_constant_model_type: crate::ConstantModelLike,
+    multiplier: f64,
+    period: usize,
+) -> IndicatorResult<Vec<(f64, f64, f64)>> {
+    bulk(
+        ["highs", "lows", "close"],
+        [highs, lows, close],
+        |[highs, lows, close]| {
+            check_same_length("highs", highs.len(), "lows", lows.len())?;
+            check_same_length("highs", highs.len(), "close", close.len())?;
+            check_period("period", period, highs.len())?;
+            let constant_model_type =
+                crate::constant_model_type("constantModelType", &constant_model_type)?;
+            let atr_constant_model_type =
+                crate::constant_model_type("atrConstantModelType", &atr_constant_model_type)?;
+            Ok(rust_ti::candle_indicators::bulk::keltner_channel(
+                highs,
+                lows,
+                close,
+                constant_model_type,
+                atr_constant_model_type,
+                multiplier,
+                period,
+            ))
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = candle_bulk_keltnerChannel)]
 pub fn candle_bulk_keltner_channel(
-    highs: Vec<f64>,
-    lows: Vec<f64>,
-    close: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
-    atr_constant_model_type: crate::ConstantModelType,
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+    atr_constant_model_type: crate::ConstantModelLike,
     multiplier: f64,
     period: usize,
-) -> Array {
-    let data = rust_ti::candle_indicators::bulk::keltner_channel(
-        &highs,
-        &lows,
-        &close,
-        constant_model_type.into(),
-        atr_constant_model_type.into(),
+) -> IndicatorResult<Array> {
+    let data = keltner_channel_rows(
+        highs,
+        lows,
+        close,
+        constant_model_type,
+        atr_constant_model_type,
         multiplier,
         period,
-    );
+    )?;
     let outer = Array::new();
     for (l, m, u) in data {
         let inner = Array::new();
@@ -328,29 +824,60 @@ pub fn candle_bulk_keltner_channel(
         inner.push(&JsValue::from_f64(u));
         outer.push(&inner);
     }
-    outer
+    Ok(outer)
 }
 
-#[wasm_bindgen(js_name = candle_bulk_supertrend)]
-pub fn candle_bulk_supertrend(
-    highs: Vec<f64>,
-    lows: Vec<f64>,
-    close: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+#[wasm_bindgen(js_name = candle_bulk_keltnerChannelColumns)]
+pub fn candle_bulk_keltner_channel_columns(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+    atr_constant_model_type: crate::ConstantModelLike,
     multiplier: f64,
     period: usize,
-) -> Array {
-    let data = rust_ti::candle_indicators::bulk::supertrend(
-        &highs,
-        &lows,
-        &close,
-        constant_model_type.into(),
+) -> IndicatorResult<Object> {
+    let data = keltner_channel_rows(
+        highs,
+        lows,
+        close,
+        constant_model_type,
+        atr_constant_model_type,
         multiplier,
         period,
-    );
-    let outer = Array::new();
-    for v in data {
-        outer.push(&JsValue::from_f64(v));
-    }
-    outer
+    )?;
+    Ok(columns(
+        ["lower", "middle", "upper"],
+        data.into_iter().map(|(l, m, u)| [l, m, u]),
+    ))
+}
+
+#[wasm_bindgen(js_name = candle_bulk_supertrend)]
+pub fn candle_bulk_supertrend(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+    multiplier: f64,
+    period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(
+        ["highs", "lows", "close"],
+        [highs, lows, close],
+        |[highs, lows, close]| {
+            check_same_length("highs", highs.len(), "lows", lows.len())?;
+            check_same_length("highs", highs.len(), "close", close.len())?;
+            check_period("period", period, highs.len())?;
+            let constant_model_type =
+                crate::constant_model_type("constantModelType", &constant_model_type)?;
+            Ok(rust_ti::candle_indicators::bulk::supertrend(
+                highs,
+                lows,
+                close,
+                constant_model_type,
+                multiplier,
+                period,
+            ))
+        },
+    )
 }
@@ -0,0 +1,805 @@
+use crate::averages::ConstantModel;
+use crate::error::{check_deviation_model, check_period_nonzero, IndicatorResult};
+use crate::points::{BandPoint, IchimokuPoint, McGinleyBandPoint};
+use crate::state::stream_state;
+use crate::stream::{extend, open_bar, MovingConstant, RollingExtreme, Window};
+use crate::Position;
+use rust_ti::candle_indicators::single;
+use rust_ti::moving_average::single::mcginley_dynamic;
+use rust_ti::other_indicators::single::true_range;
+use wasm_bindgen::prelude::*;
+
+// Streaming channels and bands.
+//
+// Each class takes one bar per `push` and returns the point the matching `candleIndicators.bulk`
+// function returns for that bar, with NaN fields during warm-up. Envelopes, Keltner channels and
+// Supertrend keep moving constants of the prices, typical prices and true ranges; Donchian,
+// Ichimoku and Supertrend keep rolling maxima and minima; the McGinley variants carry the previous
+// McGinley dynamic, and Supertrend its final bands and trend. The deviation of the moving-constant
+// and McGinley bands is taken over the window kept in WASM memory by RustTI's single function, as
+// the bulk functions do for every bar.
+
+const NAN_BAND: (f64, f64, f64) = (f64::NAN, f64::NAN, f64::NAN);
+
+/// Moving-constant envelopes of the latest `period` prices, matching
+/// `candleIndicators.bulk.movingConstantEnvelopes`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct MovingConstantEnvelopesStream {
+    model: MovingConstant,
+    difference: f64,
+    value: BandPoint,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl MovingConstantEnvelopesStream {
+    /// Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        constant_model_type: crate::FamilyModelLike,
+        difference: f64,
+        period: usize,
+    ) -> IndicatorResult<MovingConstantEnvelopesStream> {
+        check_period_nonzero("period", period)?;
+        let constant_model = crate::constant_model("constantModelType", &constant_model_type)?;
+        Ok(Self {
+            model: MovingConstant::new(constant_model, period),
+            difference,
+            value: NAN_BAND.into(),
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest envelopes (NaN until `period` prices).
+    pub fn push(&mut self, price: f64) -> BandPoint {
+        self.revert_open_bar();
+        let moving_constant = self.model.push(price);
+        if self.model.is_full() {
+            self.value = (
+                moving_constant * (1.0 - (self.difference / 100.0)),
+                moving_constant,
+                moving_constant * (1.0 + (self.difference / 100.0)),
+            )
+                .into();
+        }
+        self.value
+    }
+
+    /// Latest point returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> BandPoint {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.model.period()
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.model.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.model.clear();
+        self.value = NAN_BAND.into();
+    }
+}
+
+open_bar!(MovingConstantEnvelopesStream, (price: f64) -> BandPoint);
+extend!(MovingConstantEnvelopesStream, (prices) -> js_sys::Object);
+stream_state!(MovingConstantEnvelopesStream {
+    model,
+    difference,
+    value,
+    committed
+});
+
+/// McGinley dynamic envelopes, matching `candleIndicators.bulk.mcginleyDynamicEnvelopes`: NaN for
+/// the first `period - 1` prices, then one O(1) update per price.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct McGinleyDynamicEnvelopesStream {
+    difference: f64,
+    seed: f64,
+    period: usize,
+    pushed: usize,
+    value: McGinleyBandPoint,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl McGinleyDynamicEnvelopesStream {
+    /// `previousMcginleyDynamic` seeds the first value like the bulk argument (0 to start from
+    /// the price). Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        difference: f64,
+        previous_mcginley_dynamic: f64,
+        period: usize,
+    ) -> IndicatorResult<McGinleyDynamicEnvelopesStream> {
+        check_period_nonzero("period", period)?;
+        Ok(Self {
+            difference,
+            seed: previous_mcginley_dynamic,
+            period,
+            pushed: 0,
+            value: NAN_BAND.into(),
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest envelopes (NaN until `period` prices).
+    pub fn push(&mut self, price: f64) -> McGinleyBandPoint {
+        self.revert_open_bar();
+        self.pushed += 1;
+        if self.pushed < self.period {
+            return self.value;
+        }
+        let previous = if self.pushed == self.period {
+            self.seed
+        } else {
+            self.value.mcginley
+        };
+        // RustTI's single function, which only reads the window's last price and length
+        let mcginley = mcginley_dynamic(price, previous, self.period);
+        self.value = (
+            mcginley * (1.0 - (self.difference / 100.0)),
+            mcginley,
+            mcginley * (1.0 + (self.difference / 100.0)),
+        )
+            .into();
+        self.value
+    }
+
+    /// Latest point returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> McGinleyBandPoint {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.pushed >= self.period
+    }
+
+    /// Forgets every pushed price; the next value starts from the constructor seed again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.pushed = 0;
+        self.value = NAN_BAND.into();
+    }
+}
+
+open_bar!(McGinleyDynamicEnvelopesStream, (price: f64) -> McGinleyBandPoint);
+extend!(McGinleyDynamicEnvelopesStream, (prices) -> js_sys::Object);
+stream_state!(McGinleyDynamicEnvelopesStream {
+    difference,
+    seed,
+    period,
+    pushed,
+    value,
+    committed
+});
+
+/// Moving-constant bands of the latest `period` prices, matching
+/// `candleIndicators.bulk.movingConstantBands`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct MovingConstantBandsStream {
+    prices: Window,
+    constant_model_type: ConstantModel,
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+    value: BandPoint,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl MovingConstantBandsStream {
+    /// Throws unless `period` is greater than 0 (at least 4 for `CauchyIQRScale`).
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        constant_model_type: crate::FamilyModelLike,
+        deviation_model: crate::DeviationModel,
+        deviation_multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<MovingConstantBandsStream> {
+        check_period_nonzero("period", period)?;
+        check_deviation_model(deviation_model, period, &[])?;
+        Ok(Self {
+            prices: Window::new(period),
+            constant_model_type: crate::constant_model("constantModelType", &constant_model_type)?,
+            deviation_model,
+            deviation_multiplier,
+            value: NAN_BAND.into(),
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest bands (NaN until `period` prices).
+    ///
+    /// Throws, without adding the price, if it is not positive under `LogStandardDeviation`.
+    pub fn push(&mut self, price: f64) -> IndicatorResult<BandPoint> {
+        self.revert_open_bar();
+        check_deviation_model(self.deviation_model, self.prices.period(), &[price])?;
+        self.prices.push(price);
+        if self.prices.is_full() {
+            self.value = crate::averages::moving_constant_bands(
+                self.prices.as_slice(),
+                self.constant_model_type,
+                self.deviation_model,
+                self.deviation_multiplier,
+            )
+            .into();
+        }
+        Ok(self.value)
+    }
+
+    /// Latest point returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> BandPoint {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period()
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.value = NAN_BAND.into();
+    }
+}
+
+open_bar!(MovingConstantBandsStream, (price: f64) -> IndicatorResult<BandPoint>);
+extend!(MovingConstantBandsStream, (prices) -> js_sys::Object);
+stream_state!(MovingConstantBandsStream {
+    prices,
+    constant_model_type,
+    deviation_model,
+    deviation_multiplier,
+    value,
+    committed
+});
+
+/// McGinley dynamic bands of the latest `period` prices, matching
+/// `candleIndicators.bulk.mcginleyDynamicBands`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct McGinleyDynamicBandsStream {
+    prices: Window,
+    deviation_model: crate::DeviationModel,
+    deviation_multiplier: f64,
+    seed: f64,
+    value: McGinleyBandPoint,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl McGinleyDynamicBandsStream {
+    /// `previousMcginleyDynamic` seeds the first value like the bulk argument (0 to start from
+    /// the price). Throws unless `period` is greater than 0 (at least 4 for `CauchyIQRScale`).
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        deviation_model: crate::DeviationModel,
+        deviation_multiplier: f64,
+        previous_mcginley_dynamic: f64,
+        period: usize,
+    ) -> IndicatorResult<McGinleyDynamicBandsStream> {
+        check_period_nonzero("period", period)?;
+        check_deviation_model(deviation_model, period, &[])?;
+        Ok(Self {
+            prices: Window::new(period),
+            deviation_model,
+            deviation_multiplier,
+            seed: previous_mcginley_dynamic,
+            value: NAN_BAND.into(),
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest bands (NaN until `period` prices).
+    ///
+    /// Throws, without adding the price, if it is not positive under `LogStandardDeviation`.
+    pub fn push(&mut self, price: f64) -> IndicatorResult<McGinleyBandPoint> {
+        self.revert_open_bar();
+        check_deviation_model(self.deviation_model, self.prices.period(), &[price])?;
+        let first = !self.prices.is_full();
+        self.prices.push(price);
+        if self.prices.is_full() {
+            let previous = if first {
+                self.seed
+            } else {
+                self.value.mcginley
+            };
+            self.value = single::mcginley_dynamic_bands(
+                self.prices.as_slice(),
+                self.deviation_model.into(),
+                self.deviation_multiplier,
+                previous,
+            )
+            .into();
+        }
+        Ok(self.value)
+    }
+
+    /// Latest point returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> McGinleyBandPoint {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period()
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price; the next value starts from the constructor seed again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.value = NAN_BAND.into();
+    }
+}
+
+open_bar!(McGinleyDynamicBandsStream, (price: f64) -> IndicatorResult<McGinleyBandPoint>);
+extend!(McGinleyDynamicBandsStream, (prices) -> js_sys::Object);
+stream_state!(McGinleyDynamicBandsStream {
+    prices,
+    deviation_model,
+    deviation_multiplier,
+    seed,
+    value,
+    committed
+});
+
+/// Ichimoku cloud, matching `candleIndicators.bulk.ichimokuCloud`: NaN lines until the longest of
+/// the three periods has been pushed.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct IchimokuCloudStream {
+    // Highest high and lowest low over the conversion, base and span B periods
+    highs: [RollingExtreme; 3],
+    lows: [RollingExtreme; 3],
+    // Closes back to the one `basePeriod - 1` bars ago
+    close: Window,
+    periods: [usize; 3],
+    pushed: usize,
+    value: IchimokuPoint,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl IchimokuCloudStream {
+    /// Throws unless every period is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        conversion_period: usize,
+        base_period: usize,
+        span_b_period: usize,
+    ) -> IndicatorResult<IchimokuCloudStream> {
+        check_period_nonzero("conversionPeriod", conversion_period)?;
+        check_period_nonzero("basePeriod", base_period)?;
+        check_period_nonzero("spanBPeriod", span_b_period)?;
+        let periods = [conversion_period, base_period, span_b_period];
+        Ok(Self {
+            highs: periods.map(RollingExtreme::maximum),
+            lows: periods.map(RollingExtreme::minimum),
+            close: Window::new(base_period),
+            periods,
+            pushed: 0,
+            value: Self::nan(),
+            committed: None,
+        })
+    }
+
+    /// Adds the next bar and returns the latest cloud (NaN until the longest period).
+    pub fn push(&mut self, high: f64, low: f64, close: f64) -> IchimokuPoint {
+        self.revert_open_bar();
+        let mut lines = [0.0; 3];
+        for (line, (highs, lows)) in lines
+            .iter_mut()
+            .zip(self.highs.iter_mut().zip(&mut self.lows))
+        {
+            *line = (highs.push(high) + lows.push(low)) / 2.0;
+        }
+        self.close.push(close);
+        self.pushed += 1;
+        if self.is_ready() {
+            let [conversion, base, span_b] = lines;
+            self.value = IchimokuPoint {
+                span_a: (conversion + base) / 2.0,
+                span_b,
+                base,
+                conversion,
+                lagging_close: self.close.as_slice()[0],
+            };
+        }
+        self.value
+    }
+
+    /// Latest point returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> IchimokuPoint {
+        self.value
+    }
+
+    #[wasm_bindgen(getter, js_name = conversionPeriod)]
+    pub fn conversion_period(&self) -> usize {
+        self.periods[0]
+    }
+
+    #[wasm_bindgen(getter, js_name = basePeriod)]
+    pub fn base_period(&self) -> usize {
+        self.periods[1]
+    }
+
+    #[wasm_bindgen(getter, js_name = spanBPeriod)]
+    pub fn span_b_period(&self) -> usize {
+        self.periods[2]
+    }
+
+    /// Whether the longest of the three periods has been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.periods.iter().all(|period| self.pushed >= *period)
+    }
+
+    /// Forgets every pushed bar.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        for extreme in self.highs.iter_mut().chain(&mut self.lows) {
+            extreme.clear();
+        }
+        self.close.clear();
+        self.pushed = 0;
+        self.value = Self::nan();
+    }
+
+    fn nan() -> IchimokuPoint {
+        (f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN).into()
+    }
+}
+
+open_bar!(IchimokuCloudStream, (high: f64, low: f64, close: f64) -> IchimokuPoint);
+extend!(IchimokuCloudStream, (highs, lows, close) -> js_sys::Object);
+stream_state!(IchimokuCloudStream {
+    highs,
+    lows,
+    close,
+    periods,
+    pushed,
+    value,
+    committed
+});
+
+/// Donchian channels of the latest `period` bars, matching
+/// `candleIndicators.bulk.donchianChannels`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct DonchianChannelsStream {
+    max_high: RollingExtreme,
+    min_low: RollingExtreme,
+    period: usize,
+    value: BandPoint,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl DonchianChannelsStream {
+    /// Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(period: usize) -> IndicatorResult<DonchianChannelsStream> {
+        check_period_nonzero("period", period)?;
+        Ok(Self {
+            max_high: RollingExtreme::maximum(period),
+            min_low: RollingExtreme::minimum(period),
+            period,
+            value: NAN_BAND.into(),
+            committed: None,
+        })
+    }
+
+    /// Adds the next high and low and returns the latest channels (NaN until `period` bars).
+    pub fn push(&mut self, high: f64, low: f64) -> BandPoint {
+        self.revert_open_bar();
+        let max_price = self.max_high.push(high);
+        let min_price = self.min_low.push(low);
+        if self.max_high.is_full() {
+            self.value = (min_price, (max_price + min_price) / 2.0, max_price).into();
+        }
+        self.value
+    }
+
+    /// Latest point returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> BandPoint {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Whether `period` bars have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.max_high.is_full()
+    }
+
+    /// Forgets every pushed bar.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.max_high.clear();
+        self.min_low.clear();
+        self.value = NAN_BAND.into();
+    }
+}
+
+open_bar!(DonchianChannelsStream, (high: f64, low: f64) -> BandPoint);
+extend!(DonchianChannelsStream, (highs, lows) -> js_sys::Object);
+stream_state!(DonchianChannelsStream {
+    max_high,
+    min_low,
+    period,
+    value,
+    committed
+});
+
+/// Keltner channel of the latest `period` bars, matching `candleIndicators.bulk.keltnerChannel`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct KeltnerChannelStream {
+    // Moving constant of the typical price (high + low + close) / 3
+    typical: MovingConstant,
+    // Average true range; RustTI takes each bar's true range without the previous close
+    atr: MovingConstant,
+    multiplier: f64,
+    value: BandPoint,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl KeltnerChannelStream {
+    /// Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        constant_model_type: crate::ConstantModelLike,
+        atr_constant_model_type: crate::ConstantModelLike,
+        multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<KeltnerChannelStream> {
+        check_period_nonzero("period", period)?;
+        let constant_model_type =
+            crate::constant_model_type("constantModelType", &constant_model_type)?;
+        let atr_constant_model_type =
+            crate::constant_model_type("atrConstantModelType", &atr_constant_model_type)?;
+        Ok(Self {
+            typical: MovingConstant::new(constant_model_type, period),
+            atr: MovingConstant::new(atr_constant_model_type, period),
+            multiplier,
+            value: NAN_BAND.into(),
+            committed: None,
+        })
+    }
+
+    /// Adds the next bar and returns the latest channel (NaN until `period` bars).
+    pub fn push(&mut self, high: f64, low: f64, close: f64) -> BandPoint {
+        self.revert_open_bar();
+        let atr = self.atr.push(true_range(close, high, low));
+        let mc = self.typical.push((high + low + close) / 3.0);
+        if self.typical.is_full() {
+            let constant = atr * self.multiplier;
+            self.value = (mc - constant, mc, mc + constant).into();
+        }
+        self.value
+    }
+
+    /// Latest point returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> BandPoint {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.typical.period()
+    }
+
+    /// Whether `period` bars have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.typical.is_full()
+    }
+
+    /// Forgets every pushed bar.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.typical.clear();
+        self.atr.clear();
+        self.value = NAN_BAND.into();
+    }
+}
+
+open_bar!(KeltnerChannelStream, (high: f64, low: f64, close: f64) -> BandPoint);
+extend!(KeltnerChannelStream, (highs, lows, close) -> js_sys::Object);
+stream_state!(KeltnerChannelStream {
+    typical,
+    atr,
+    multiplier,
+    value,
+    committed
+});
+
+/// Supertrend of the latest `period` bars, built on `candleIndicators.bulk.supertrend`.
+///
+/// RustTI's Supertrend is the midpoint of the window's highest high and lowest low plus
+/// `multiplier` average true ranges, with no state from one bar to the next. The stream takes it
+/// as the basic upper band (and the midpoint minus as many ranges as the basic lower band), then
+/// carries the final bands and the trend between bars: the upper band only falls and the lower
+/// band only rises until the previous close crosses them, and the trend turns long when the close
+/// rises above the upper band and short when it falls below the lower band.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct SupertrendStream {
+    max_high: RollingExtreme,
+    min_low: RollingExtreme,
+    atr: MovingConstant,
+    multiplier: f64,
+    previous_close: f64,
+    // Trend of the latest bar; the first full window starts short, on the upper band
+    position: Position,
+    value: BandPoint,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl SupertrendStream {
+    /// Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        constant_model_type: crate::ConstantModelLike,
+        multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<SupertrendStream> {
+        check_period_nonzero("period", period)?;
+        let constant_model_type =
+            crate::constant_model_type("constantModelType", &constant_model_type)?;
+        Ok(Self {
+            max_high: RollingExtreme::maximum(period),
+            min_low: RollingExtreme::minimum(period),
+            atr: MovingConstant::new(constant_model_type, period),
+            multiplier,
+            previous_close: f64::NAN,
+            position: Position::Short,
+            value: NAN_BAND.into(),
+            committed: None,
+        })
+    }
+
+    /// Adds the next bar and returns the final lower band, the window midpoint and the final
+    /// upper band (NaN until `period` bars). On a bar where the upper band is not carried over,
+    /// it is the bulk Supertrend for that bar.
+    pub fn push(&mut self, high: f64, low: f64, close: f64) -> BandPoint {
+        self.revert_open_bar();
+        let atr = self.atr.push(true_range(close, high, low));
+        let max_high = self.max_high.push(high);
+        let min_low = self.min_low.push(low);
+        if self.atr.is_full() {
+            let middle = (max_high + min_low) / 2.0;
+            let basic_upper = middle + (self.multiplier * atr);
+            let basic_lower = middle - (self.multiplier * atr);
+            let previous = self.value;
+            // The previous bands are NaN on the first full window, which takes the basic ones
+            let upper = if previous.upper.is_nan()
+                || basic_upper < previous.upper
+                || self.previous_close > previous.upper
+            {
+                basic_upper
+            } else {
+                previous.upper
+            };
+            let lower = if previous.lower.is_nan()
+                || basic_lower > previous.lower
+                || self.previous_close < previous.lower
+            {
+                basic_lower
+            } else {
+                previous.lower
+            };
+            self.position = match self.position {
+                Position::Short if close > upper => Position::Long,
+                Position::Long if close < lower => Position::Short,
+                position => position,
+            };
+            self.value = (lower, middle, upper).into();
+        }
+        self.previous_close = close;
+        self.value
+    }
+
+    /// Latest point returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> BandPoint {
+        self.value
+    }
+
+    /// Trend of the latest bar: long on the lower band, short on the upper band.
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Supertrend line of the latest bar: the lower band in a long trend, the upper band in a
+    /// short one (NaN until `period` bars).
+    #[wasm_bindgen(getter)]
+    pub fn supertrend(&self) -> f64 {
+        match self.position {
+            Position::Long => self.value.lower,
+            Position::Short => self.value.upper,
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.atr.period()
+    }
+
+    /// Whether `period` bars have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.atr.is_full()
+    }
+
+    /// Forgets every pushed bar.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.max_high.clear();
+        self.min_low.clear();
+        self.atr.clear();
+        self.previous_close = f64::NAN;
+        self.position = Position::Short;
+        self.value = NAN_BAND.into();
+    }
+}
+
+open_bar!(SupertrendStream, (high: f64, low: f64, close: f64) -> BandPoint);
+extend!(SupertrendStream, (highs, lows, close) -> js_sys::Object);
+stream_state!(SupertrendStream {
+    max_high,
+    min_low,
+    atr,
+    multiplier,
+    previous_close,
+    position,
+    value,
+    committed
+});
@@ -1,3 +1,6 @@
+use crate::error::{
+    check_non_negative, check_not_empty, check_period, check_range, IndicatorResult,
+};
 use js_sys::Array;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsValue;
@@ -6,8 +9,13 @@ use wasm_bindgen::JsValue;
 
 // peaks: Vec<(f64, usize)> -> Array<[value, index]>
 #[wasm_bindgen(js_name = chart_trends_peaks)]
-pub fn chart_trends_peaks(prices: Vec<f64>, period: usize, closest_neighbor: usize) -> Array {
-    let pairs = rust_ti::chart_trends::peaks(&prices, period, closest_neighbor);
+pub fn chart_trends_peaks(
+    prices: &[f64],
+    period: usize,
+    closest_neighbor: usize,
+) -> IndicatorResult<Array> {
+    check_period("period", period, prices.len())?;
+    let pairs = rust_ti::chart_trends::peaks(prices, period, closest_neighbor);
     let outer = Array::new();
     for (val, idx) in pairs {
         let inner = Array::new();
@@ -15,13 +23,18 @@ pub fn chart_trends_peaks(prices: Vec<f64>, period: usize, closest_neighbor: usi
         inner.push(&JsValue::from_f64(idx as f64));
         outer.push(&inner);
     }
-    outer
+    Ok(outer)
 }
 
 // valleys: Vec<(f64, usize)> -> Array<[value, index]>
 #[wasm_bindgen(js_name = chart_trends_valleys)]
-pub fn chart_trends_valleys(prices: Vec<f64>, period: usize, closest_neighbor: usize) -> Array {
-    let pairs = rust_ti::chart_trends::valleys(&prices, period, closest_neighbor);
+pub fn chart_trends_valleys(
+    prices: &[f64],
+    period: usize,
+    closest_neighbor: usize,
+) -> IndicatorResult<Array> {
+    check_period("period", period, prices.len())?;
+    let pairs = rust_ti::chart_trends::valleys(prices, period, closest_neighbor);
     let outer = Array::new();
     for (val, idx) in pairs {
         let inner = Array::new();
@@ -29,37 +42,40 @@ pub fn chart_trends_valleys(prices: Vec<f64>, period: usize, closest_neighbor: u
         inner.push(&JsValue::from_f64(idx as f64));
         outer.push(&inner);
     }
-    outer
+    Ok(outer)
 }
 
 // peak_trend: (f64, f64) -> [slope, intercept]
 #[wasm_bindgen(js_name = chart_trends_peakTrend)]
-pub fn chart_trends_peak_trend(prices: Vec<f64>, period: usize) -> Array {
-    let (slope, intercept) = rust_ti::chart_trends::peak_trend(&prices, period);
+pub fn chart_trends_peak_trend(prices: &[f64], period: usize) -> IndicatorResult<Array> {
+    check_period("period", period, prices.len())?;
+    let (slope, intercept) = rust_ti::chart_trends::peak_trend(prices, period);
     let arr = Array::new();
     arr.push(&JsValue::from_f64(slope));
     arr.push(&JsValue::from_f64(intercept));
-    arr
+    Ok(arr)
 }
 
 // valley_trend: (f64, f64) -> [slope, intercept]
 #[wasm_bindgen(js_name = chart_trends_valleyTrend)]
-pub fn chart_trends_valley_trend(prices: Vec<f64>, period: usize) -> Array {
-    let (slope, intercept) = rust_ti::chart_trends::valley_trend(&prices, period);
+pub fn chart_trends_valley_trend(prices: &[f64], period: usize) -> IndicatorResult<Array> {
+    check_period("period", period, prices.len())?;
+    let (slope, intercept) = rust_ti::chart_trends::valley_trend(prices, period);
     let arr = Array::new();
     arr.push(&JsValue::from_f64(slope));
     arr.push(&JsValue::from_f64(intercept));
-    arr
+    Ok(arr)
 }
 
 // overall_trend: (f64, f64) -> [slope, intercept]
 #[wasm_bindgen(js_name = chart_trends_overallTrend)]
-pub fn chart_trends_overall_trend(prices: Vec<f64>) -> Array {
-    let (slope, intercept) = rust_ti::chart_trends::overall_trend(&prices);
+pub fn chart_trends_overall_trend(prices: &[f64]) -> IndicatorResult<Array> {
+    check_not_empty("prices", prices)?;
+    let (slope, intercept) = rust_ti::chart_trends::overall_trend(prices);
     let arr = Array::new();
     arr.push(&JsValue::from_f64(slope));
     arr.push(&JsValue::from_f64(intercept));
-    arr
+    Ok(arr)
 }
 
 // break_down_trends: Vec<(usize, usize, f64, f64)> -> Array<[start, end, slope, intercept]>
@@ -67,7 +83,7 @@ pub fn chart_trends_overall_trend(prices: Vec<f64>) -> Array {
 #[allow(clippy::too_many_arguments)]
 #[wasm_bindgen(js_name = chart_trends_breakDownTrends)]
 pub fn chart_trends_break_down_trends(
-    prices: Vec<f64>,
+    prices: &[f64],
     max_outliers: usize,
     soft_adj_r_squared_minimum: f64,
     hard_adj_r_squared_minimum: f64,
@@ -77,20 +93,36 @@ pub fn chart_trends_break_down_trends(
     soft_durbin_watson_max: f64,
     hard_durbin_watson_min: f64,
     hard_durbin_watson_max: f64,
-) -> Array {
-    let config = rust_ti::chart_trends::TrendBreakConfig {
-        max_outliers,
-        soft_adj_r_squared_minimum,
-        hard_adj_r_squared_minimum,
-        soft_rmse_multiplier,
-        hard_rmse_multiplier,
-        soft_durbin_watson_min,
-        soft_durbin_watson_max,
-        hard_durbin_watson_min,
-        hard_durbin_watson_max,
+) -> IndicatorResult<Array> {
+    let config = TrendBreakConfig {
+        inner: rust_ti::chart_trends::TrendBreakConfig {
+            max_outliers,
+            soft_adj_r_squared_minimum,
+            hard_adj_r_squared_minimum,
+            soft_rmse_multiplier,
+            hard_rmse_multiplier,
+            soft_durbin_watson_min,
+            soft_durbin_watson_max,
+            hard_durbin_watson_min,
+            hard_durbin_watson_max,
+        },
     };
+    break_down_trends(prices, &config)
+}
+
+// Same as breakDownTrends, configured through a TrendBreakConfig instance
+#[wasm_bindgen(js_name = chart_trends_breakDownTrendsWithConfig)]
+pub fn chart_trends_break_down_trends_with_config(
+    prices: &[f64],
+    config: &TrendBreakConfig,
+) -> IndicatorResult<Array> {
+    break_down_trends(prices, config)
+}
 
-    let segments = rust_ti::chart_trends::break_down_trends(&prices, config);
+fn break_down_trends(prices: &[f64], config: &TrendBreakConfig) -> IndicatorResult<Array> {
+    check_not_empty("prices", prices)?;
+    config.validate()?;
+    let segments = rust_ti::chart_trends::break_down_trends(prices, config.inner);
     let outer = Array::new();
     for (start, end, slope, intercept) in segments {
         let inner = Array::new();
@@ -100,5 +132,180 @@ pub fn chart_trends_break_down_trends(
         inner.push(&JsValue::from_f64(intercept));
         outer.push(&inner);
     }
-    outer
+    Ok(outer)
+}
+
+/// Thresholds used by `breakDownTrendsWithConfig` to decide where one trend ends.
+///
+/// `new TrendBreakConfig()` starts from the RustTI defaults. Setters are builder-style and
+/// return a new config, so presets can be tweaked without being modified:
+/// `TrendBreakConfig.conservative().withMaxOutliers(3)`.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TrendBreakConfig {
+    inner: rust_ti::chart_trends::TrendBreakConfig,
+}
+
+#[wasm_bindgen]
+impl TrendBreakConfig {
+    /// RustTI defaults: 1 outlier, R² 0.25/0.05, RMSE x1.3/x2.0, Durbin-Watson 1.0-3.0/0.7-3.3.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> TrendBreakConfig {
+        Self::default()
+    }
+
+    /// Tolerates more noise before breaking: fewer, longer trends.
+    pub fn conservative() -> TrendBreakConfig {
+        Self {
+            inner: rust_ti::chart_trends::TrendBreakConfig {
+                max_outliers: 2,
+                soft_adj_r_squared_minimum: 0.15,
+                hard_adj_r_squared_minimum: 0.02,
+                soft_rmse_multiplier: 1.5,
+                hard_rmse_multiplier: 2.5,
+                soft_durbin_watson_min: 0.8,
+                soft_durbin_watson_max: 3.2,
+                hard_durbin_watson_min: 0.5,
+                hard_durbin_watson_max: 3.5,
+            },
+        }
+    }
+
+    /// Breaks as soon as the fit deteriorates: more, shorter trends.
+    pub fn aggressive() -> TrendBreakConfig {
+        Self {
+            inner: rust_ti::chart_trends::TrendBreakConfig {
+                max_outliers: 0,
+                soft_adj_r_squared_minimum: 0.35,
+                hard_adj_r_squared_minimum: 0.1,
+                soft_rmse_multiplier: 1.15,
+                hard_rmse_multiplier: 1.6,
+                soft_durbin_watson_min: 1.2,
+                soft_durbin_watson_max: 2.8,
+                hard_durbin_watson_min: 0.9,
+                hard_durbin_watson_max: 3.1,
+            },
+        }
+    }
+
+    /// Throws if a multiplier is negative or a Durbin-Watson minimum exceeds its maximum.
+    pub fn validate(&self) -> IndicatorResult<()> {
+        let c = &self.inner;
+        check_non_negative("softRmseMultiplier", c.soft_rmse_multiplier)?;
+        check_non_negative("hardRmseMultiplier", c.hard_rmse_multiplier)?;
+        check_range(
+            "softDurbinWatsonMin",
+            c.soft_durbin_watson_min,
+            "softDurbinWatsonMax",
+            c.soft_durbin_watson_max,
+        )?;
+        check_range(
+            "hardDurbinWatsonMin",
+            c.hard_durbin_watson_min,
+            "hardDurbinWatsonMax",
+            c.hard_durbin_watson_max,
+        )
+    }
+
+    #[wasm_bindgen(getter, js_name = maxOutliers)]
+    pub fn max_outliers(&self) -> usize {
+        self.inner.max_outliers
+    }
+
+    #[wasm_bindgen(getter, js_name = softAdjRSquaredMinimum)]
+    pub fn soft_adj_r_squared_minimum(&self) -> f64 {
+        self.inner.soft_adj_r_squared_minimum
+    }
+
+    #[wasm_bindgen(getter, js_name = hardAdjRSquaredMinimum)]
+    pub fn hard_adj_r_squared_minimum(&self) -> f64 {
+        self.inner.hard_adj_r_squared_minimum
+    }
+
+    #[wasm_bindgen(getter, js_name = softRmseMultiplier)]
+    pub fn soft_rmse_multiplier(&self) -> f64 {
+        self.inner.soft_rmse_multiplier
+    }
+
+    #[wasm_bindgen(getter, js_name = hardRmseMultiplier)]
+    pub fn hard_rmse_multiplier(&self) -> f64 {
+        self.inner.hard_rmse_multiplier
+    }
+
+    #[wasm_bindgen(getter, js_name = softDurbinWatsonMin)]
+    pub fn soft_durbin_watson_min(&self) -> f64 {
+        self.inner.soft_durbin_watson_min
+    }
+
+    #[wasm_bindgen(getter, js_name = softDurbinWatsonMax)]
+    pub fn soft_durbin_watson_max(&self) -> f64 {
+        self.inner.soft_durbin_watson_max
+    }
+
+    #[wasm_bindgen(getter, js_name = hardDurbinWatsonMin)]
+    pub fn hard_durbin_watson_min(&self) -> f64 {
+        self.inner.hard_durbin_watson_min
+    }
+
+    #[wasm_bindgen(getter, js_name = hardDurbinWatsonMax)]
+    pub fn hard_durbin_watson_max(&self) -> f64 {
+        self.inner.hard_durbin_watson_max
+    }
+
+    #[wasm_bindgen(js_name = withMaxOutliers)]
+    pub fn with_max_outliers(&self, value: usize) -> TrendBreakConfig {
+        let mut next = *self;
+        next.inner.max_outliers = value;
+        next
+    }
+
+    #[wasm_bindgen(js_name = withSoftAdjRSquaredMinimum)]
+    pub fn with_soft_adj_r_squared_minimum(&self, value: f64) -> TrendBreakConfig {
+        let mut next = *self;
+        next.inner.soft_adj_r_squared_minimum = value;
+        next
+    }
+
+    #[wasm_bindgen(js_name = withHardAdjRSquaredMinimum)]
+    pub fn with_hard_adj_r_squared_minimum(&self, value: f64) -> TrendBreakConfig {
+        let mut next = *self;
+        next.inner.hard_adj_r_squared_minimum = value;
+        next
+    }
+
+    #[wasm_bindgen(js_name = withSoftRmseMultiplier)]
+    pub fn with_soft_rmse_multiplier(&self, value: f64) -> IndicatorResult<TrendBreakConfig> {
+        check_non_negative("softRmseMultiplier", value)?;
+        let mut next = *self;
+        next.inner.soft_rmse_multiplier = value;
+        Ok(next)
+    }
+
+    #[wasm_bindgen(js_name = withHardRmseMultiplier)]
+    pub fn with_hard_rmse_multiplier(&self, value: f64) -> IndicatorResult<TrendBreakConfig> {
+        check_non_negative("hardRmseMultiplier", value)?;
+        let mut next = *self;
+        next.inner.hard_rmse_multiplier = value;
+        Ok(next)
+    }
+
+    /// Sets both soft Durbin-Watson bounds at once so they can be checked together.
+    #[wasm_bindgen(js_name = withSoftDurbinWatson)]
+    pub fn with_soft_durbin_watson(&self, min: f64, max: f64) -> IndicatorResult<TrendBreakConfig> {
+        check_range("softDurbinWatsonMin", min, "softDurbinWatsonMax", max)?;
+        let mut next = *self;
+        next.inner.soft_durbin_watson_min = min;
+        next.inner.soft_durbin_watson_max = max;
+        Ok(next)
+    }
+
+    /// Sets both hard Durbin-Watson bounds at once so they can be checked together.
+    #[wasm_bindgen(js_name = withHardDurbinWatson)]
+    pub fn with_hard_durbin_watson(&self, min: f64, max: f64) -> IndicatorResult<TrendBreakConfig> {
+        check_range("hardDurbinWatsonMin", min, "hardDurbinWatsonMax", max)?;
+        let mut next = *self;
+        next.inner.hard_durbin_watson_min = min;
+        next.inner.hard_durbin_watson_max = max;
+        Ok(next)
+    }
 }
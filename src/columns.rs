@@ -0,0 +1,29 @@
+use js_sys::{Float64Array, Object, Reflect};
+use wasm_bindgen::JsValue;
+
+// Struct-of-arrays output for multi-output bulk indicators.
+//
+// RustTI returns one tuple per window; the `*Columns` bindings transpose those rows into a plain
+// JS object with one `Float64Array` per named output, e.g. `{ lower, middle, upper }`.
+
+/// Transposes `rows` into an object with one `Float64Array` per entry in `names`.
+pub(crate) fn columns<const N: usize>(
+    names: [&str; N],
+    rows: impl ExactSizeIterator<Item = [f64; N]>,
+) -> Object {
+    let mut cols: [Vec<f64>; N] = std::array::from_fn(|_| Vec::with_capacity(rows.len()));
+    for row in rows {
+        for (col, value) in cols.iter_mut().zip(row) {
+            col.push(value);
+        }
+    }
+    let out = Object::new();
+    for (name, col) in names.iter().zip(cols.iter()) {
+        let _ = Reflect::set(
+            &out,
+            &JsValue::from_str(name),
+            &Float64Array::from(col.as_slice()),
+        );
+    }
+    out
+}
@@ -1,42 +1,71 @@
-use js_sys::Array;
+use crate::error::{
+    check_deviation_model, check_not_empty, check_period, check_same_length, IndicatorResult,
+};
+use crate::missing::bulk;
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsValue;
 
 /// Single-value correlation: returns a number
 #[wasm_bindgen(js_name = correlation_single_correlateAssetPrices)]
 pub fn correlation_single_correlate_asset_prices(
-    prices_asset_a: Vec<f64>,
-    prices_asset_b: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    prices_asset_a: &[f64],
+    prices_asset_b: &[f64],
+    constant_model_type: crate::ConstantModelLike,
     deviation_model: crate::DeviationModel,
-) -> f64 {
-    rust_ti::correlation_indicators::single::correlate_asset_prices(
-        &prices_asset_a,
-        &prices_asset_b,
-        constant_model_type.into(),
-        deviation_model.into(),
+) -> IndicatorResult<f64> {
+    check_not_empty("pricesAssetA", prices_asset_a)?;
+    check_same_length(
+        "pricesAssetA",
+        prices_asset_a.len(),
+        "pricesAssetB",
+        prices_asset_b.len(),
+    )?;
+    check_deviation_model(deviation_model, prices_asset_a.len(), prices_asset_a)?;
+    check_deviation_model(deviation_model, prices_asset_b.len(), prices_asset_b)?;
+    let constant_model_type =
+        crate::constant_model_type("constantModelType", &constant_model_type)?;
+    Ok(
+        rust_ti::correlation_indicators::single::correlate_asset_prices(
+            prices_asset_a,
+            prices_asset_b,
+            constant_model_type,
+            deviation_model.into(),
+        ),
     )
 }
 
-/// Rolling correlation over a period: returns Array<number>
+/// Rolling correlation over a period: returns Float64Array
 #[wasm_bindgen(js_name = correlation_bulk_correlateAssetPrices)]
 pub fn correlation_bulk_correlate_asset_prices(
-    prices_asset_a: Vec<f64>,
-    prices_asset_b: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    prices_asset_a: &[f64],
+    prices_asset_b: &[f64],
+    constant_model_type: crate::ConstantModelLike,
     deviation_model: crate::DeviationModel,
     period: usize,
-) -> Array {
-    let data = rust_ti::correlation_indicators::bulk::correlate_asset_prices(
-        &prices_asset_a,
-        &prices_asset_b,
-        constant_model_type.into(),
-        deviation_model.into(),
-        period,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(
+        ["pricesAssetA", "pricesAssetB"],
+        [prices_asset_a, prices_asset_b],
+        |[prices_asset_a, prices_asset_b]| {
+            check_same_length(
+                "pricesAssetA",
+                prices_asset_a.len(),
+                "pricesAssetB",
+                prices_asset_b.len(),
+            )?;
+            check_period("period", period, prices_asset_a.len())?;
+            check_deviation_model(deviation_model, period, prices_asset_a)?;
+            check_deviation_model(deviation_model, period, prices_asset_b)?;
+            let constant_model_type =
+                crate::constant_model_type("constantModelType", &constant_model_type)?;
+            Ok(
+                rust_ti::correlation_indicators::bulk::correlate_asset_prices(
+                    prices_asset_a,
+                    prices_asset_b,
+                    constant_model_type,
+                    deviation_model.into(),
+                    period,
+                ),
+            )
+        },
+    )
 }
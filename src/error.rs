@@ -0,0 +1,344 @@
+use js_sys::{Object, Reflect};
+use std::fmt;
+use wasm_bindgen::JsValue;
+
+// Structured errors for the JS boundary.
+//
+// RustTI panics on bad input, which in WASM becomes an opaque `RuntimeError: unreachable`.
+// Every binding validates its arguments with the helpers below before calling into RustTI
+// and returns `Result<_, IndicatorError>`; wasm-bindgen throws the converted `Error` in JS.
+// Bindings that only take scalars (e.g. `trueRange`) cannot panic and keep plain return types.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorCode {
+    EmptyInput,
+    LengthMismatch,
+    PeriodTooLong,
+    InvalidParameter,
+    MissingValue,
+    InvalidState,
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::EmptyInput => "EmptyInput",
+            ErrorCode::LengthMismatch => "LengthMismatch",
+            ErrorCode::PeriodTooLong => "PeriodTooLong",
+            ErrorCode::InvalidParameter => "InvalidParameter",
+            ErrorCode::MissingValue => "MissingValue",
+            ErrorCode::InvalidState => "InvalidState",
+        }
+    }
+}
+
+/// Error thrown to JS as an `Error` named `TiEngineError` with `code`, `argument` and `values`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndicatorError {
+    code: ErrorCode,
+    argument: &'static str,
+    message: String,
+    values: Vec<(&'static str, f64)>,
+}
+
+pub type IndicatorResult<T> = Result<T, IndicatorError>;
+
+impl IndicatorError {
+    pub fn new(code: ErrorCode, argument: &'static str, message: String) -> Self {
+        Self {
+            code,
+            argument,
+            message,
+            values: Vec::new(),
+        }
+    }
+
+    pub fn empty_input(argument: &'static str) -> Self {
+        Self::new(
+            ErrorCode::EmptyInput,
+            argument,
+            format!("{} cannot be empty", argument),
+        )
+        .with_value("length", 0.0)
+    }
+
+    pub fn length_mismatch(
+        reference: &'static str,
+        expected: usize,
+        argument: &'static str,
+        actual: usize,
+    ) -> Self {
+        Self::new(
+            ErrorCode::LengthMismatch,
+            argument,
+            format!(
+                "Length of {} ({}) must match length of {} ({})",
+                argument, actual, reference, expected
+            ),
+        )
+        .with_value("expected", expected as f64)
+        .with_value("actual", actual as f64)
+    }
+
+    pub fn period_too_long(argument: &'static str, period: usize, length: usize) -> Self {
+        Self::new(
+            ErrorCode::PeriodTooLong,
+            argument,
+            format!(
+                "{} ({}) cannot be longer than the length of the data ({})",
+                argument, period, length
+            ),
+        )
+        .with_value(argument, period as f64)
+        .with_value("length", length as f64)
+    }
+
+    pub fn invalid_parameter(argument: &'static str, value: f64, reason: &str) -> Self {
+        Self::new(
+            ErrorCode::InvalidParameter,
+            argument,
+            format!("{} ({}) {}", argument, value, reason),
+        )
+        .with_value(argument, value)
+    }
+
+    pub fn with_value(mut self, name: &'static str, value: f64) -> Self {
+        self.values.push((name, value));
+        self
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    pub fn argument(&self) -> &'static str {
+        self.argument
+    }
+
+    pub fn values(&self) -> &[(&'static str, f64)] {
+        &self.values
+    }
+}
+
+impl fmt::Display for IndicatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code.as_str(), self.message)
+    }
+}
+
+impl std::error::Error for IndicatorError {}
+
+impl From<IndicatorError> for JsValue {
+    fn from(e: IndicatorError) -> Self {
+        let err = js_sys::Error::new(&e.to_string());
+        err.set_name("TiEngineError");
+        let values = Object::new();
+        for (name, value) in &e.values {
+            let _ = Reflect::set(
+                &values,
+                &JsValue::from_str(name),
+                &JsValue::from_f64(*value),
+            );
+        }
+        let _ = Reflect::set(&err, &"code".into(), &e.code.as_str().into());
+        let _ = Reflect::set(&err, &"argument".into(), &e.argument.into());
+        let _ = Reflect::set(&err, &"values".into(), &values);
+        err.into()
+    }
+}
+
+// -------- VALIDATION --------
+
+pub fn check_not_empty(argument: &'static str, values: &[f64]) -> IndicatorResult<()> {
+    if values.is_empty() {
+        return Err(IndicatorError::empty_input(argument));
+    }
+    Ok(())
+}
+
+pub fn check_same_length(
+    reference: &'static str,
+    expected: usize,
+    argument: &'static str,
+    actual: usize,
+) -> IndicatorResult<()> {
+    if expected != actual {
+        return Err(IndicatorError::length_mismatch(
+            reference, expected, argument, actual,
+        ));
+    }
+    Ok(())
+}
+
+pub fn check_period_nonzero(argument: &'static str, period: usize) -> IndicatorResult<()> {
+    if period == 0 {
+        return Err(IndicatorError::invalid_parameter(
+            argument,
+            0.0,
+            "must be greater than 0",
+        ));
+    }
+    Ok(())
+}
+
+/// Period must be at least 1 and no longer than the data.
+pub fn check_period(argument: &'static str, period: usize, length: usize) -> IndicatorResult<()> {
+    check_period_nonzero(argument, period)?;
+    if period > length {
+        return Err(IndicatorError::period_too_long(argument, period, length));
+    }
+    Ok(())
+}
+
+/// Data must be at least `required` long (fixed-window indicators such as Bollinger Bands).
+pub fn check_min_length(
+    argument: &'static str,
+    length: usize,
+    required: usize,
+) -> IndicatorResult<()> {
+    if length < required {
+        return Err(IndicatorError::new(
+            ErrorCode::PeriodTooLong,
+            argument,
+            format!(
+                "Length of {} ({}) must be at least {}",
+                argument, length, required
+            ),
+        )
+        .with_value("period", required as f64)
+        .with_value("length", length as f64));
+    }
+    Ok(())
+}
+
+/// Data must be exactly `required` long (single-value fixed-window indicators).
+pub fn check_exact_length(
+    argument: &'static str,
+    length: usize,
+    required: usize,
+) -> IndicatorResult<()> {
+    if length != required {
+        return Err(IndicatorError::new(
+            ErrorCode::LengthMismatch,
+            argument,
+            format!(
+                "Length of {} ({}) must be exactly {}",
+                argument, length, required
+            ),
+        )
+        .with_value("expected", required as f64)
+        .with_value("actual", length as f64));
+    }
+    Ok(())
+}
+
+/// `argument` must be strictly smaller than `bound_argument` (short vs long periods).
+pub fn check_less_than(
+    argument: &'static str,
+    value: usize,
+    bound_argument: &'static str,
+    bound: usize,
+) -> IndicatorResult<()> {
+    if value >= bound {
+        return Err(IndicatorError::invalid_parameter(
+            argument,
+            value as f64,
+            &format!("must be less than {} ({})", bound_argument, bound),
+        )
+        .with_value(bound_argument, bound as f64));
+    }
+    Ok(())
+}
+
+/// Multipliers and other scale factors must be finite and not negative.
+pub fn check_non_negative(argument: &'static str, value: f64) -> IndicatorResult<()> {
+    if !(value.is_finite() && value >= 0.0) {
+        return Err(IndicatorError::invalid_parameter(
+            argument,
+            value,
+            "must be a finite number greater than or equal to 0",
+        ));
+    }
+    Ok(())
+}
+
+/// Scale factors that divide (e.g. the ALMA sigma) must be finite and greater than 0.
+pub fn check_positive(argument: &'static str, value: f64) -> IndicatorResult<()> {
+    if !(value.is_finite() && value > 0.0) {
+        return Err(IndicatorError::invalid_parameter(
+            argument,
+            value,
+            "must be a finite number greater than 0",
+        ));
+    }
+    Ok(())
+}
+
+/// Fractions such as the ALMA offset or the T3 volume factor must lie in [0, 1].
+pub fn check_fraction(argument: &'static str, value: f64) -> IndicatorResult<()> {
+    if !(0.0..=1.0).contains(&value) {
+        return Err(IndicatorError::invalid_parameter(
+            argument,
+            value,
+            "must be a number between 0 and 1",
+        ));
+    }
+    Ok(())
+}
+
+/// Periods split into two halves (the fractal adaptive moving average) must be even and not 0.
+pub fn check_even_period(argument: &'static str, period: usize) -> IndicatorResult<()> {
+    check_period_nonzero(argument, period)?;
+    if !period.is_multiple_of(2) {
+        return Err(IndicatorError::invalid_parameter(
+            argument,
+            period as f64,
+            "must be an even number",
+        ));
+    }
+    Ok(())
+}
+
+/// Lower bound of a range must not exceed its upper bound (e.g. Durbin-Watson min/max).
+pub fn check_range(
+    min_argument: &'static str,
+    min: f64,
+    max_argument: &'static str,
+    max: f64,
+) -> IndicatorResult<()> {
+    if min.is_nan() || max.is_nan() || min > max {
+        return Err(IndicatorError::invalid_parameter(
+            min_argument,
+            min,
+            &format!("must be less than or equal to {} ({})", max_argument, max),
+        )
+        .with_value(max_argument, max));
+    }
+    Ok(())
+}
+
+/// Rejects deviation models that RustTI cannot compute over the given window.
+pub fn check_deviation_model(
+    deviation_model: crate::DeviationModel,
+    window: usize,
+    prices: &[f64],
+) -> IndicatorResult<()> {
+    match deviation_model {
+        crate::DeviationModel::CauchyIQRScale if window < 4 => {
+            Err(IndicatorError::invalid_parameter(
+                "deviationModel",
+                window as f64,
+                "CauchyIQRScale requires a window of at least 4 values",
+            ))
+        }
+        crate::DeviationModel::LogStandardDeviation => match prices.iter().find(|p| **p <= 0.0) {
+            Some(p) => Err(IndicatorError::invalid_parameter(
+                "prices",
+                *p,
+                "must be positive for LogStandardDeviation",
+            )),
+            None => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
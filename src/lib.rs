@@ -1,6 +1,9 @@
+use crate::averages::{ConstantModel, Family, MovingAverage};
+use crate::error::{IndicatorError, IndicatorResult};
+use js_sys::Reflect;
 use wasm_bindgen::prelude::*;
 
-// Centralized JS-facing enums (no personalised variants)
+// Centralized JS-facing enums (personalised variants are passed as a PersonalisedModel, see below)
 #[wasm_bindgen]
 #[derive(Clone, Copy, Debug)]
 pub enum ConstantModelType {
@@ -29,6 +32,33 @@ impl From<ConstantModelType> for rust_ti::ConstantModelType {
     }
 }
 
+// Moving average families computed by ti_engine (see `averages`), only taken where
+// `constant_model` is used. The values follow on from `ConstantModelType` so that one argument can
+// take either enum.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub enum ConstantModelFamily {
+    WeightedMovingAverage = 5,
+    HullMovingAverage = 6,
+    DoubleExponentialMovingAverage = 7,
+    TripleExponentialMovingAverage = 8,
+    ZeroLagExponentialMovingAverage = 9,
+    TriangularMovingAverage = 10,
+}
+
+impl From<ConstantModelFamily> for Family {
+    fn from(v: ConstantModelFamily) -> Self {
+        match v {
+            ConstantModelFamily::WeightedMovingAverage => Family::Weighted,
+            ConstantModelFamily::HullMovingAverage => Family::Hull,
+            ConstantModelFamily::DoubleExponentialMovingAverage => Family::DoubleExponential,
+            ConstantModelFamily::TripleExponentialMovingAverage => Family::TripleExponential,
+            ConstantModelFamily::ZeroLagExponentialMovingAverage => Family::ZeroLagExponential,
+            ConstantModelFamily::TriangularMovingAverage => Family::Triangular,
+        }
+    }
+}
+
 #[wasm_bindgen]
 #[derive(Clone, Copy, Debug)]
 pub enum DeviationModel {
@@ -65,19 +95,249 @@ pub enum MovingAverageType {
     Simple,
     Smoothed,
     Exponential,
+    Weighted,
+    Hull,
+    DoubleExponential,
+    TripleExponential,
+    ZeroLagExponential,
+    Triangular,
+    VolumeWeighted,
 }
 
-// Conversion to the internal RustTI type
-impl From<MovingAverageType> for rust_ti::MovingAverageType {
+// Conversion to the internal RustTI type, or a family computed by ti_engine
+impl From<MovingAverageType> for MovingAverage {
     fn from(value: MovingAverageType) -> Self {
         match value {
-            MovingAverageType::Simple => rust_ti::MovingAverageType::Simple,
-            MovingAverageType::Smoothed => rust_ti::MovingAverageType::Smoothed,
-            MovingAverageType::Exponential => rust_ti::MovingAverageType::Exponential,
+            MovingAverageType::Simple => MovingAverage::RustTi(rust_ti::MovingAverageType::Simple),
+            MovingAverageType::Smoothed => {
+                MovingAverage::RustTi(rust_ti::MovingAverageType::Smoothed)
+            }
+            MovingAverageType::Exponential => {
+                MovingAverage::RustTi(rust_ti::MovingAverageType::Exponential)
+            }
+            MovingAverageType::Weighted => MovingAverage::Family(Family::Weighted),
+            MovingAverageType::Hull => MovingAverage::Family(Family::Hull),
+            MovingAverageType::DoubleExponential => {
+                MovingAverage::Family(Family::DoubleExponential)
+            }
+            MovingAverageType::TripleExponential => {
+                MovingAverage::Family(Family::TripleExponential)
+            }
+            MovingAverageType::ZeroLagExponential => {
+                MovingAverage::Family(Family::ZeroLagExponential)
+            }
+            MovingAverageType::Triangular => MovingAverage::Family(Family::Triangular),
+            MovingAverageType::VolumeWeighted => MovingAverage::VolumeWeighted,
         }
     }
 }
 
+// Personalised models
+//
+// wasm-bindgen enums cannot carry data, so RustTI's `Personalised` variants are exposed as a
+// `PersonalisedModel` class. Every binding that takes a model accepts either an enum value or a
+// personalised model (class instance or plain `{ alphaNum, alphaDen }` object) and resolves it
+// with `constant_model_type`, `constant_model` or `moving_average_type`.
+
+/// Custom smoothing factor: alpha = alphaNum / (window length + alphaDen).
+///
+/// `Smoothed` is (1, 0) and `Exponential` is (2, 1).
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PersonalisedModel {
+    alpha_num: f64,
+    alpha_den: f64,
+}
+
+#[wasm_bindgen]
+impl PersonalisedModel {
+    /// Throws unless alphaNum > 0 and alphaDen > -1 (so the denominator is positive for any window).
+    #[wasm_bindgen(constructor)]
+    pub fn new(alpha_num: f64, alpha_den: f64) -> IndicatorResult<PersonalisedModel> {
+        if !(alpha_num.is_finite() && alpha_num > 0.0) {
+            return Err(IndicatorError::invalid_parameter(
+                "alphaNum",
+                alpha_num,
+                "must be a finite number greater than 0",
+            ));
+        }
+        if !(alpha_den.is_finite() && alpha_den > -1.0) {
+            return Err(IndicatorError::invalid_parameter(
+                "alphaDen",
+                alpha_den,
+                "must be a finite number greater than -1",
+            ));
+        }
+        Ok(Self {
+            alpha_num,
+            alpha_den,
+        })
+    }
+
+    /// Model whose alpha is exactly `alpha` over windows of `period` values.
+    #[wasm_bindgen(js_name = fromAlpha)]
+    pub fn from_alpha(alpha: f64, period: usize) -> IndicatorResult<PersonalisedModel> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(IndicatorError::invalid_parameter(
+                "alpha",
+                alpha,
+                "must be greater than 0 and at most 1",
+            ));
+        }
+        crate::error::check_period_nonzero("period", period)?;
+        Self::new(alpha * period as f64, 0.0)
+    }
+
+    #[wasm_bindgen(getter, js_name = alphaNum)]
+    pub fn alpha_num(&self) -> f64 {
+        self.alpha_num
+    }
+
+    #[wasm_bindgen(getter, js_name = alphaDen)]
+    pub fn alpha_den(&self) -> f64 {
+        self.alpha_den
+    }
+
+    /// Alpha applied over a window of `length` values.
+    pub fn alpha(&self, length: usize) -> f64 {
+        self.alpha_num / (length as f64 + self.alpha_den)
+    }
+}
+
+impl PersonalisedModel {
+    // Reads `alphaNum` / `alphaDen` from a class instance or a plain object
+    fn from_js(argument: &'static str, value: &JsValue) -> IndicatorResult<Self> {
+        let field = |name: &str| {
+            if !value.is_object() {
+                return None;
+            }
+            Reflect::get(value, &JsValue::from_str(name))
+                .ok()
+                .and_then(|v| v.as_f64())
+        };
+        match (field("alphaNum"), field("alphaDen")) {
+            (Some(alpha_num), Some(alpha_den)) => Self::new(alpha_num, alpha_den),
+            _ => Err(IndicatorError::invalid_parameter(
+                argument,
+                f64::NAN,
+                "must be a model enum value or a PersonalisedModel",
+            )),
+        }
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// A `ConstantModelType` value or a personalised model.
+    #[wasm_bindgen(
+        typescript_type = "ConstantModelType | PersonalisedModel | { alphaNum: number; alphaDen: number }"
+    )]
+    pub type ConstantModelLike;
+
+    /// A `ConstantModelLike` or a `ConstantModelFamily` value.
+    #[wasm_bindgen(
+        typescript_type = "ConstantModelType | ConstantModelFamily | PersonalisedModel | { alphaNum: number; alphaDen: number }"
+    )]
+    pub type FamilyModelLike;
+
+    /// A `MovingAverageType` value or a personalised model.
+    #[wasm_bindgen(
+        typescript_type = "MovingAverageType | PersonalisedModel | { alphaNum: number; alphaDen: number }"
+    )]
+    pub type MovingAverageLike;
+}
+
+/// Resolves a `ConstantModelLike` for the indicators computed by RustTI.
+pub(crate) fn constant_model_type(
+    argument: &'static str,
+    value: &ConstantModelLike,
+) -> IndicatorResult<rust_ti::ConstantModelType> {
+    if let Some(n) = value.as_f64() {
+        return [
+            ConstantModelType::SimpleMovingAverage,
+            ConstantModelType::SmoothedMovingAverage,
+            ConstantModelType::ExponentialMovingAverage,
+            ConstantModelType::SimpleMovingMedian,
+            ConstantModelType::SimpleMovingMode,
+        ]
+        .into_iter()
+        .find(|v| *v as u32 as f64 == n)
+        .map(Into::into)
+        .ok_or_else(|| {
+            IndicatorError::invalid_parameter(argument, n, "is not a ConstantModelType")
+        });
+    }
+    let model = PersonalisedModel::from_js(argument, value)?;
+    Ok(rust_ti::ConstantModelType::PersonalisedMovingAverage {
+        alpha_num: model.alpha_num,
+        alpha_den: model.alpha_den,
+    })
+}
+
+/// Resolves a `FamilyModelLike` for the indicators that also take the moving average families.
+pub(crate) fn constant_model(
+    argument: &'static str,
+    value: &FamilyModelLike,
+) -> IndicatorResult<ConstantModel> {
+    if let Some(family) = value.as_f64().and_then(|n| {
+        [
+            ConstantModelFamily::WeightedMovingAverage,
+            ConstantModelFamily::HullMovingAverage,
+            ConstantModelFamily::DoubleExponentialMovingAverage,
+            ConstantModelFamily::TripleExponentialMovingAverage,
+            ConstantModelFamily::ZeroLagExponentialMovingAverage,
+            ConstantModelFamily::TriangularMovingAverage,
+        ]
+        .into_iter()
+        .find(|v| *v as u32 as f64 == n)
+    }) {
+        return Ok(ConstantModel::Family(family.into()));
+    }
+    constant_model_type(argument, value.unchecked_ref())
+        .map(ConstantModel::RustTi)
+        .map_err(|error| match value.as_f64() {
+            Some(n) => IndicatorError::invalid_parameter(
+                argument,
+                n,
+                "is not a ConstantModelType or ConstantModelFamily",
+            ),
+            None => error,
+        })
+}
+
+pub(crate) fn moving_average_type(
+    argument: &'static str,
+    value: &MovingAverageLike,
+) -> IndicatorResult<MovingAverage> {
+    if let Some(n) = value.as_f64() {
+        return [
+            MovingAverageType::Simple,
+            MovingAverageType::Smoothed,
+            MovingAverageType::Exponential,
+            MovingAverageType::Weighted,
+            MovingAverageType::Hull,
+            MovingAverageType::DoubleExponential,
+            MovingAverageType::TripleExponential,
+            MovingAverageType::ZeroLagExponential,
+            MovingAverageType::Triangular,
+            MovingAverageType::VolumeWeighted,
+        ]
+        .into_iter()
+        .find(|v| *v as u32 as f64 == n)
+        .map(Into::into)
+        .ok_or_else(|| {
+            IndicatorError::invalid_parameter(argument, n, "is not a MovingAverageType")
+        });
+    }
+    let model = PersonalisedModel::from_js(argument, value)?;
+    Ok(MovingAverage::RustTi(
+        rust_ti::MovingAverageType::Personalised {
+            alpha_num: model.alpha_num,
+            alpha_den: model.alpha_den,
+        },
+    ))
+}
+
 #[wasm_bindgen]
 #[derive(Clone, Copy, Debug)]
 pub enum Position {
@@ -94,6 +354,27 @@ impl From<Position> for rust_ti::Position {
     }
 }
 
+mod adaptive;
+mod align;
+mod averages;
+pub mod batch;
+pub mod candle_stream;
+mod columns;
+pub mod error;
+pub mod lookback;
+pub mod missing;
+pub mod momentum_stream;
+pub mod moving_average_stream;
+pub mod ohlcv;
+pub mod panel;
+pub mod points;
+pub mod registry;
+mod state;
+mod stream;
+pub mod sweep;
+pub mod trend_stream;
+pub mod volume_stream;
+
 // Mirror RustTI structure
 pub mod candle_indicators;
 pub mod chart_trends;
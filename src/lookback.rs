@@ -0,0 +1,118 @@
+use crate::error::{ErrorCode, IndicatorError, IndicatorResult};
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+
+// Warm-up length of every bulk indicator.
+//
+// `lookback` is the number of leading input bars a bulk function consumes before its first output,
+// i.e. `input length - output length`. It only depends on the period-like parameters, so the other
+// arguments of the bulk function can be left out of `params`.
+
+/// Number of input bars before the first value of a bulk indicator.
+///
+/// `indicator` is the namespaced JS name, e.g. `"momentumIndicators.macdLine"` (a `Columns`
+/// suffix is ignored); `params` holds the period arguments under their JS names.
+#[wasm_bindgen(js_name = lookback)]
+pub fn lookback(indicator: &str, params: &JsValue) -> IndicatorResult<usize> {
+    let name = indicator.strip_suffix("Columns").unwrap_or(indicator);
+    let period = |argument| read_period(params, argument);
+    Ok(match name {
+        "candleIndicators.movingConstantEnvelopes"
+        | "candleIndicators.mcginleyDynamicEnvelopes"
+        | "candleIndicators.movingConstantBands"
+        | "candleIndicators.mcginleyDynamicBands"
+        | "candleIndicators.donchianChannels"
+        | "candleIndicators.keltnerChannel"
+        | "candleIndicators.supertrend"
+        | "correlationIndicators.correlateAssetPrices"
+        | "momentumIndicators.relativeStrengthIndex"
+        | "momentumIndicators.stochasticOscillator"
+        | "momentumIndicators.slowStochastic"
+        | "momentumIndicators.slowestStochastic"
+        | "momentumIndicators.williamsPercentR"
+        | "momentumIndicators.moneyFlowIndex"
+        | "momentumIndicators.commodityChannelIndex"
+        | "momentumIndicators.mcginleyDynamicCommodityChannelIndex"
+        | "momentumIndicators.signalLine"
+        | "momentumIndicators.chandeMomentumOscillator"
+        | "movingAverage.movingAverage"
+        | "movingAverage.mcginleyDynamic"
+        | "movingAverage.volumeWeightedMovingAverage"
+        | "movingAverage.fractalAdaptiveMovingAverage"
+        | "movingAverage.arnaudLegouxMovingAverage"
+        | "movingAverage.tillsonT3"
+        | "otherIndicators.averageTrueRange"
+        | "standardIndicators.simpleMovingAverage"
+        | "standardIndicators.smoothedMovingAverage"
+        | "standardIndicators.exponentialMovingAverage"
+        | "strengthIndicators.relativeVigorIndex"
+        | "trendIndicators.aroonUp"
+        | "trendIndicators.aroonDown"
+        | "trendIndicators.aroonIndicator"
+        | "volatilityIndicators.ulcerIndex"
+        | "volatilityIndicators.volatilitySystem" => period("period")? - 1,
+        "otherIndicators.positivityIndicator" => period("signalPeriod")? - 1,
+        // Changes over the window, so one more price than the period
+        "movingAverage.kaufmanAdaptiveMovingAverage" => period("period")?,
+        "movingAverage.variableIndexDynamicAverage" => period("cmoPeriod")?,
+        "momentumIndicators.macdLine"
+        | "momentumIndicators.mcginleyDynamicMacdLine"
+        | "momentumIndicators.chaikinOscillator"
+        | "momentumIndicators.percentagePriceOscillator" => period("longPeriod")? - 1,
+        "candleIndicators.ichimokuCloud" => {
+            period("conversionPeriod")?
+                .max(period("basePeriod")?)
+                .max(period("spanBPeriod")?)
+                - 1
+        }
+        // +DI/-DI, then ADX over them, then ADXR over the ADX
+        "trendIndicators.directionalMovementSystem" => 3 * period("period")? - 2,
+        "trendIndicators.trueStrengthIndex" => period("firstPeriod")? + period("secondPeriod")? - 1,
+        "standardIndicators.bollingerBands" => 19,
+        "standardIndicators.macd" => 33,
+        "standardIndicators.rsi" => 13,
+        // One output per price change
+        "momentumIndicators.rateOfChange"
+        | "momentumIndicators.onBalanceVolume"
+        | "otherIndicators.returnOnInvestment"
+        | "strengthIndicators.positiveVolumeIndex"
+        | "strengthIndicators.negativeVolumeIndex"
+        | "trendIndicators.volumePriceTrend" => 1,
+        "otherIndicators.trueRange"
+        | "otherIndicators.internalBarStrength"
+        | "strengthIndicators.accumulationDistribution"
+        | "trendIndicators.aroonOscillator"
+        | "trendIndicators.parabolicTimePriceSystem" => 0,
+        _ => {
+            return Err(IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                "indicator",
+                format!("indicator ({}) is not a bulk indicator", indicator),
+            ))
+        }
+    })
+}
+
+// Reads a required positive integer from `params`
+fn read_period(params: &JsValue, argument: &'static str) -> IndicatorResult<usize> {
+    let value = if params.is_object() {
+        Reflect::get(params, &JsValue::from_str(argument))
+            .ok()
+            .and_then(|v| v.as_f64())
+    } else {
+        None
+    };
+    match value {
+        Some(v) if v >= 1.0 && v.fract() == 0.0 && v <= u32::MAX as f64 => Ok(v as usize),
+        Some(v) => Err(IndicatorError::invalid_parameter(
+            argument,
+            v,
+            "must be a whole number greater than 0",
+        )),
+        None => Err(IndicatorError::new(
+            ErrorCode::InvalidParameter,
+            argument,
+            format!("{} is required", argument),
+        )),
+    }
+}
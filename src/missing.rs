@@ -0,0 +1,281 @@
+use crate::align::{aligned, Padding};
+use crate::error::{ErrorCode, IndicatorError, IndicatorResult};
+use std::cell::Cell;
+use wasm_bindgen::prelude::*;
+
+// Missing-data handling for bulk functions.
+//
+// RustTI has no notion of a missing value: one NaN in the input turns every window that contains
+// it, and for recursive models every later value, into NaN. Every bulk binding runs its RustTI
+// call through `bulk`, which applies the current `MissingData` policy to the input arrays first.
+// Inputs of different lengths (e.g. prices and one-shorter volumes) are aligned on their last bar,
+// and every run of bars handed to RustTI keeps those length differences.
+// Recursive indicators go through `bulk_seeded`, so a run after a gap continues from the value
+// before it rather than from the caller's seed.
+// Like `setAlignedOutput`, the policy is a thread-local; `withMissingData` scopes it to one call.
+
+/// How bulk functions treat missing values (NaN) in their inputs.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MissingData {
+    /// Pass NaN to RustTI unchanged (default, previous behaviour).
+    Passthrough,
+    /// Throw a `MissingValue` error naming the input and index of the first NaN.
+    Reject,
+    /// NaN for every output whose window includes a missing bar; other outputs are unaffected.
+    /// Indicators seeded with a `previous*` value (McGinley dynamic, KAMA, FRAMA, VIDYA, OBV,
+    /// accumulation/distribution, volume indices, volume price trend) carry their last value
+    /// across the gap; the parabolic SAR restarts after it.
+    Propagate,
+    /// Drop missing bars, compute over the remaining bars and return NaN at the dropped ones.
+    Skip,
+    /// Replace each NaN with the previous value, then `Propagate` leading gaps.
+    ForwardFill,
+    /// Replace each NaN by linear interpolation between its neighbours, then `Propagate` gaps at
+    /// either end.
+    Interpolate,
+}
+
+thread_local! {
+    static POLICY: Cell<MissingData> = const { Cell::new(MissingData::Passthrough) };
+}
+
+/// Sets the missing-data policy used by every bulk function.
+#[wasm_bindgen(js_name = setMissingData)]
+pub fn set_missing_data(policy: MissingData) {
+    POLICY.with(|p| p.set(policy));
+}
+
+/// Current missing-data policy.
+#[wasm_bindgen(js_name = getMissingData)]
+pub fn get_missing_data() -> MissingData {
+    POLICY.with(|p| p.get())
+}
+
+/// Calls `callback` with `policy` in effect and restores the previous policy afterwards.
+#[wasm_bindgen(js_name = withMissingData)]
+pub fn with_missing_data(
+    policy: MissingData,
+    callback: &js_sys::Function,
+) -> Result<JsValue, JsValue> {
+    with_policy(policy, || callback.call0(&JsValue::NULL))
+}
+
+pub(crate) fn with_policy<R>(policy: MissingData, f: impl FnOnce() -> R) -> R {
+    let previous = get_missing_data();
+    set_missing_data(policy);
+    let result = f();
+    set_missing_data(previous);
+    result
+}
+
+// Policy given to `computeBatch`-style specs as the enum value or its variant name
+pub(crate) fn from_js(value: &JsValue) -> IndicatorResult<MissingData> {
+    use MissingData::*;
+    let policies = [
+        Passthrough,
+        Reject,
+        Propagate,
+        Skip,
+        ForwardFill,
+        Interpolate,
+    ];
+    let policy = match (value.as_f64(), value.as_string()) {
+        (Some(n), _) => policies.into_iter().find(|p| *p as u32 as f64 == n),
+        (_, Some(s)) => policies.into_iter().find(|p| format!("{:?}", p) == s),
+        _ => None,
+    };
+    policy.ok_or_else(|| {
+        IndicatorError::new(
+            ErrorCode::InvalidParameter,
+            "missingData",
+            "missingData must be a MissingData value or name".to_string(),
+        )
+    })
+}
+
+/// Runs `indicator` over `inputs` under the current policy, then aligns the output to `inputs[0]`.
+///
+/// `indicator` does its own validation, so with `Propagate` each gap-free run of bars is checked
+/// and computed as its own series; runs too short for the period only yield NaN.
+pub(crate) fn bulk<const N: usize, T: Padding>(
+    names: [&'static str; N],
+    inputs: [&[f64]; N],
+    indicator: impl Fn([&[f64]; N]) -> IndicatorResult<Vec<T>>,
+) -> IndicatorResult<Vec<T>> {
+    bulk_seeded(names, inputs, (), |_| (), |inputs, ()| indicator(inputs))
+}
+
+/// `bulk` for recursive indicators seeded with their previous value(s), like the McGinley
+/// dynamic or OBV: the first run starts from `seed`, and with `Propagate` each run after a gap
+/// starts from `carry` of the last output before the gap instead of restarting.
+pub(crate) fn bulk_seeded<const N: usize, T: Padding, S: Copy>(
+    names: [&'static str; N],
+    inputs: [&[f64]; N],
+    seed: S,
+    carry: impl Fn(&T) -> S,
+    indicator: impl Fn([&[f64]; N], S) -> IndicatorResult<Vec<T>>,
+) -> IndicatorResult<Vec<T>> {
+    let length = inputs[0].len();
+    let policy = get_missing_data();
+    if policy == MissingData::Passthrough || !inputs.iter().any(|i| i.iter().any(|v| v.is_nan())) {
+        return Ok(aligned(indicator(inputs, seed)?, length));
+    }
+    let output = match policy {
+        MissingData::Passthrough => indicator(inputs, seed)?,
+        MissingData::Reject => return Err(reject(names, inputs)),
+        MissingData::Propagate => propagate(names, inputs, seed, &carry, &indicator)?,
+        MissingData::Skip => skip(names, inputs, |inputs| indicator(inputs, seed))?,
+        MissingData::ForwardFill | MissingData::Interpolate => {
+            let filled: [Vec<f64>; N] = inputs.map(|input| fill(policy, input));
+            propagate(
+                names,
+                std::array::from_fn(|i| filled[i].as_slice()),
+                seed,
+                &carry,
+                &indicator,
+            )?
+        }
+    };
+    Ok(aligned(output, length))
+}
+
+fn reject<const N: usize>(names: [&'static str; N], inputs: [&[f64]; N]) -> IndicatorError {
+    let (name, index) = names
+        .into_iter()
+        .zip(inputs)
+        .find_map(|(name, input)| Some((name, input.iter().position(|v| v.is_nan())?)))
+        .unwrap_or((names[0], 0));
+    IndicatorError::new(
+        ErrorCode::MissingValue,
+        name,
+        format!("{} has a missing value (NaN) at index {}", name, index),
+    )
+    .with_value("index", index as f64)
+}
+
+// Bars (aligned on the last bar of the longest input) where any input is NaN
+fn missing_bars<const N: usize>(inputs: &[&[f64]; N], bars: usize) -> Vec<bool> {
+    let mut missing = vec![false; bars];
+    for input in inputs {
+        let offset = bars - input.len();
+        for (bar, value) in input.iter().enumerate() {
+            missing[offset + bar] |= value.is_nan();
+        }
+    }
+    missing
+}
+
+fn propagate<const N: usize, T: Padding, S: Copy>(
+    names: [&'static str; N],
+    inputs: [&[f64]; N],
+    seed: S,
+    carry: &impl Fn(&T) -> S,
+    indicator: &impl Fn([&[f64]; N], S) -> IndicatorResult<Vec<T>>,
+) -> IndicatorResult<Vec<T>> {
+    let bars = inputs.iter().map(|i| i.len()).max().unwrap_or(0);
+    let missing = missing_bars(&inputs, bars);
+    let mut out = vec![T::PAD; bars];
+    let mut lookback = None;
+    let mut too_short = None;
+    let mut seed = seed;
+    let mut start = 0;
+    while start < bars {
+        if missing[start] {
+            start += 1;
+            continue;
+        }
+        let end = (start..bars).find(|b| missing[*b]).unwrap_or(bars);
+        let run: [&[f64]; N] = std::array::from_fn(|i| {
+            let offset = bars - inputs[i].len();
+            let to = end.saturating_sub(offset);
+            &inputs[i][start.min(to)..to]
+        });
+        match indicator(run, seed) {
+            Ok(values) => {
+                lookback.get_or_insert((end - start).saturating_sub(values.len()));
+                if let Some(last) = values.last() {
+                    seed = carry(last);
+                }
+                out[end - values.len()..end].copy_from_slice(&values);
+            }
+            Err(e) if matches!(e.code(), ErrorCode::PeriodTooLong | ErrorCode::EmptyInput) => {
+                too_short.get_or_insert(e);
+            }
+            Err(e) => return Err(e),
+        }
+        start = end;
+    }
+    match lookback {
+        Some(lookback) => Ok(out.split_off(lookback)),
+        None => Err(too_short.unwrap_or_else(|| IndicatorError::empty_input(names[0]))),
+    }
+}
+
+fn skip<const N: usize, T: Padding>(
+    names: [&'static str; N],
+    inputs: [&[f64]; N],
+    indicator: impl Fn([&[f64]; N]) -> IndicatorResult<Vec<T>>,
+) -> IndicatorResult<Vec<T>> {
+    let bars = inputs.iter().map(|i| i.len()).max().unwrap_or(0);
+    let missing = missing_bars(&inputs, bars);
+    let kept: Vec<usize> = (0..bars).filter(|b| !missing[*b]).collect();
+    if kept.is_empty() {
+        return Err(IndicatorError::empty_input(names[0]));
+    }
+    let compacted: [Vec<f64>; N] = std::array::from_fn(|i| {
+        let offset = bars - inputs[i].len();
+        kept.iter()
+            .skip(offset)
+            .map(|b| inputs[i][b - offset])
+            .collect()
+    });
+    let values = indicator(std::array::from_fn(|i| compacted[i].as_slice()))?;
+    let kept = &kept[kept.len() - values.len()..];
+    let mut out = vec![T::PAD; bars];
+    for (bar, value) in kept.iter().zip(values) {
+        out[*bar] = value;
+    }
+    Ok(out.split_off(kept.first().copied().unwrap_or(bars)))
+}
+
+/// `input` with its gaps filled the way `policy` (`ForwardFill` or `Interpolate`) fills them
+/// before propagating the rest.
+pub(crate) fn fill(policy: MissingData, input: &[f64]) -> Vec<f64> {
+    match policy {
+        MissingData::ForwardFill => forward_fill(input),
+        _ => interpolate(input),
+    }
+}
+
+fn forward_fill(input: &[f64]) -> Vec<f64> {
+    let mut last = f64::NAN;
+    input
+        .iter()
+        .map(|v| {
+            if !v.is_nan() {
+                last = *v;
+            }
+            last
+        })
+        .collect()
+}
+
+fn interpolate(input: &[f64]) -> Vec<f64> {
+    let mut out = input.to_vec();
+    let mut previous: Option<usize> = None;
+    for index in 0..out.len() {
+        if out[index].is_nan() {
+            continue;
+        }
+        if let Some(from) = previous.filter(|from| index - from > 1) {
+            let (a, b) = (out[from], out[index]);
+            let span = (index - from) as f64;
+            for (step, value) in out[from + 1..index].iter_mut().enumerate() {
+                *value = a + (b - a) * (step + 1) as f64 / span;
+            }
+        }
+        previous = Some(index);
+    }
+    out
+}
@@ -1,51 +1,86 @@
-use js_sys::Array;
+use crate::columns::columns;
+use crate::error::{
+    check_deviation_model, check_less_than, check_min_length, check_not_empty, check_period,
+    check_period_nonzero, check_same_length, IndicatorResult,
+};
+use crate::missing::{bulk, bulk_seeded};
+use crate::points::{
+    ChaikinOscillatorPoint, McGinleyCommodityChannelIndexPoint, McGinleyMacdLinePoint,
+};
+use js_sys::{Array, Object};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsValue;
 
 // -------- SINGLE --------
 #[wasm_bindgen(js_name = momentum_single_relativeStrengthIndex)]
 pub fn momentum_single_relative_strength_index(
-    prices: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
-) -> f64 {
-    rust_ti::momentum_indicators::single::relative_strength_index(
-        &prices,
-        constant_model_type.into(),
-    )
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
+) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    let constant_model = crate::constant_model("constantModelType", &constant_model_type)?;
+    Ok(crate::averages::relative_strength_index(
+        prices,
+        constant_model,
+    ))
 }
 
 #[wasm_bindgen(js_name = momentum_single_stochasticOscillator)]
-pub fn momentum_single_stochastic_oscillator(prices: Vec<f64>) -> f64 {
-    rust_ti::momentum_indicators::single::stochastic_oscillator(&prices)
+pub fn momentum_single_stochastic_oscillator(prices: &[f64]) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    Ok(rust_ti::momentum_indicators::single::stochastic_oscillator(
+        prices,
+    ))
 }
 
 #[wasm_bindgen(js_name = momentum_single_slowStochastic)]
 pub fn momentum_single_slow_stochastic(
-    stochastics: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
-) -> f64 {
-    rust_ti::momentum_indicators::single::slow_stochastic(&stochastics, constant_model_type.into())
+    stochastics: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<f64> {
+    check_not_empty("stochastics", stochastics)?;
+    let constant_model_type =
+        crate::constant_model_type("constantModelType", &constant_model_type)?;
+    Ok(rust_ti::momentum_indicators::single::slow_stochastic(
+        stochastics,
+        constant_model_type,
+    ))
 }
 
 #[wasm_bindgen(js_name = momentum_single_slowestStochastic)]
 pub fn momentum_single_slowest_stochastic(
-    slow_stochastics: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
-) -> f64 {
-    rust_ti::momentum_indicators::single::slowest_stochastic(
-        &slow_stochastics,
-        constant_model_type.into(),
-    )
+    slow_stochastics: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<f64> {
+    check_not_empty("slowStochastics", slow_stochastics)?;
+    let constant_model_type =
+        crate::constant_model_type("constantModelType", &constant_model_type)?;
+    Ok(rust_ti::momentum_indicators::single::slowest_stochastic(
+        slow_stochastics,
+        constant_model_type,
+    ))
 }
 
 #[wasm_bindgen(js_name = momentum_single_williamsPercentR)]
-pub fn momentum_single_williams_percent_r(high: Vec<f64>, low: Vec<f64>, close: f64) -> f64 {
-    rust_ti::momentum_indicators::single::williams_percent_r(&high, &low, close)
+pub fn momentum_single_williams_percent_r(
+    high: &[f64],
+    low: &[f64],
+    close: f64,
+) -> IndicatorResult<f64> {
+    check_not_empty("high", high)?;
+    check_same_length("high", high.len(), "low", low.len())?;
+    Ok(rust_ti::momentum_indicators::single::williams_percent_r(
+        high, low, close,
+    ))
 }
 
 #[wasm_bindgen(js_name = momentum_single_moneyFlowIndex)]
-pub fn momentum_single_money_flow_index(prices: Vec<f64>, volume: Vec<f64>) -> f64 {
-    rust_ti::momentum_indicators::single::money_flow_index(&prices, &volume)
+pub fn momentum_single_money_flow_index(prices: &[f64], volume: &[f64]) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    check_same_length("prices", prices.len(), "volume", volume.len())?;
+    Ok(rust_ti::momentum_indicators::single::money_flow_index(
+        prices, volume,
+    ))
 }
 
 #[wasm_bindgen(js_name = momentum_single_rateOfChange)]
@@ -70,281 +105,474 @@ pub fn momentum_single_on_balance_volume(
 
 #[wasm_bindgen(js_name = momentum_single_commodityChannelIndex)]
 pub fn momentum_single_commodity_channel_index(
-    prices: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    prices: &[f64],
+    constant_model_type: crate::ConstantModelLike,
     deviation_model: crate::DeviationModel,
     constant_multiplier: f64,
-) -> f64 {
-    rust_ti::momentum_indicators::single::commodity_channel_index(
-        &prices,
-        constant_model_type.into(),
-        deviation_model.into(),
-        constant_multiplier,
+) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    check_deviation_model(deviation_model, prices.len(), prices)?;
+    let constant_model_type =
+        crate::constant_model_type("constantModelType", &constant_model_type)?;
+    Ok(
+        rust_ti::momentum_indicators::single::commodity_channel_index(
+            prices,
+            constant_model_type,
+            deviation_model.into(),
+            constant_multiplier,
+        ),
+    )
+}
+
+fn mcginley_dynamic_commodity_channel_index_value(
+    prices: &[f64],
+    previous_mcginley_dynamic: f64,
+    deviation_model: crate::DeviationModel,
+    constant_multiplier: f64,
+) -> IndicatorResult<(f64, f64)> {
+    check_not_empty("prices", prices)?;
+    check_deviation_model(deviation_model, prices.len(), prices)?;
+    Ok(
+        rust_ti::momentum_indicators::single::mcginley_dynamic_commodity_channel_index(
+            prices,
+            previous_mcginley_dynamic,
+            deviation_model.into(),
+            constant_multiplier,
+        ),
     )
 }
 
 #[wasm_bindgen(js_name = momentum_single_mcginleyDynamicCommodityChannelIndex)]
 pub fn momentum_single_mcginley_dynamic_commodity_channel_index(
-    prices: Vec<f64>,
+    prices: &[f64],
     previous_mcginley_dynamic: f64,
     deviation_model: crate::DeviationModel,
     constant_multiplier: f64,
-) -> Array {
-    let (v, m) = rust_ti::momentum_indicators::single::mcginley_dynamic_commodity_channel_index(
-        &prices,
+) -> IndicatorResult<Array> {
+    let (v, m) = mcginley_dynamic_commodity_channel_index_value(
+        prices,
         previous_mcginley_dynamic,
-        deviation_model.into(),
+        deviation_model,
         constant_multiplier,
-    );
+    )?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(v));
     arr.push(&JsValue::from_f64(m));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = momentum_single_mcginleyDynamicCommodityChannelIndexPoint)]
+pub fn momentum_single_mcginley_dynamic_commodity_channel_index_point(
+    prices: &[f64],
+    previous_mcginley_dynamic: f64,
+    deviation_model: crate::DeviationModel,
+    constant_multiplier: f64,
+) -> IndicatorResult<McGinleyCommodityChannelIndexPoint> {
+    Ok(mcginley_dynamic_commodity_channel_index_value(
+        prices,
+        previous_mcginley_dynamic,
+        deviation_model,
+        constant_multiplier,
+    )?
+    .into())
 }
 
 #[wasm_bindgen(js_name = momentum_single_macdLine)]
 pub fn momentum_single_macd_line(
-    prices: Vec<f64>,
+    prices: &[f64],
     short_period: usize,
-    short_period_model: crate::ConstantModelType,
-    long_period_model: crate::ConstantModelType,
-) -> f64 {
-    rust_ti::momentum_indicators::single::macd_line(
-        &prices,
+    short_period_model: crate::FamilyModelLike,
+    long_period_model: crate::FamilyModelLike,
+) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    check_period_nonzero("shortPeriod", short_period)?;
+    check_less_than("shortPeriod", short_period, "length", prices.len())?;
+    let short_period_model = crate::constant_model("shortPeriodModel", &short_period_model)?;
+    let long_period_model = crate::constant_model("longPeriodModel", &long_period_model)?;
+    Ok(crate::averages::macd_line(
+        prices,
         short_period,
-        short_period_model.into(),
-        long_period_model.into(),
-    )
+        short_period_model,
+        long_period_model,
+    ))
 }
 
 #[wasm_bindgen(js_name = momentum_single_signalLine)]
 pub fn momentum_single_signal_line(
-    macds: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
-) -> f64 {
-    rust_ti::momentum_indicators::single::signal_line(&macds, constant_model_type.into())
+    macds: &[f64],
+    constant_model_type: crate::FamilyModelLike,
+) -> IndicatorResult<f64> {
+    check_not_empty("macds", macds)?;
+    let constant_model = crate::constant_model("constantModelType", &constant_model_type)?;
+    Ok(crate::averages::signal_line(macds, constant_model))
+}
+
+fn mcginley_dynamic_macd_line_value(
+    prices: &[f64],
+    short_period: usize,
+    previous_short_mcginley: f64,
+    previous_long_mcginley: f64,
+) -> IndicatorResult<(f64, f64, f64)> {
+    check_not_empty("prices", prices)?;
+    check_period_nonzero("shortPeriod", short_period)?;
+    check_less_than("shortPeriod", short_period, "length", prices.len())?;
+    Ok(
+        rust_ti::momentum_indicators::single::mcginley_dynamic_macd_line(
+            prices,
+            short_period,
+            previous_short_mcginley,
+            previous_long_mcginley,
+        ),
+    )
 }
 
 #[wasm_bindgen(js_name = momentum_single_mcginleyDynamicMacdLine)]
 pub fn momentum_single_mcginley_dynamic_macd_line(
-    prices: Vec<f64>,
+    prices: &[f64],
     short_period: usize,
     previous_short_mcginley: f64,
     previous_long_mcginley: f64,
-) -> Array {
-    let (macd, short_m, long_m) = rust_ti::momentum_indicators::single::mcginley_dynamic_macd_line(
-        &prices,
+) -> IndicatorResult<Array> {
+    let (macd, short_m, long_m) = mcginley_dynamic_macd_line_value(
+        prices,
         short_period,
         previous_short_mcginley,
         previous_long_mcginley,
-    );
+    )?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(macd));
     arr.push(&JsValue::from_f64(short_m));
     arr.push(&JsValue::from_f64(long_m));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = momentum_single_mcginleyDynamicMacdLinePoint)]
+pub fn momentum_single_mcginley_dynamic_macd_line_point(
+    prices: &[f64],
+    short_period: usize,
+    previous_short_mcginley: f64,
+    previous_long_mcginley: f64,
+) -> IndicatorResult<McGinleyMacdLinePoint> {
+    Ok(mcginley_dynamic_macd_line_value(
+        prices,
+        short_period,
+        previous_short_mcginley,
+        previous_long_mcginley,
+    )?
+    .into())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn chaikin_oscillator_value(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    volume: &[f64],
+    short_period: usize,
+    previous_accumulation_distribution: f64,
+    short_period_model: crate::ConstantModelLike,
+    long_period_model: crate::ConstantModelLike,
+) -> IndicatorResult<(f64, f64)> {
+    check_not_empty("highs", highs)?;
+    check_same_length("highs", highs.len(), "lows", lows.len())?;
+    check_same_length("highs", highs.len(), "close", close.len())?;
+    check_same_length("highs", highs.len(), "volume", volume.len())?;
+    check_period_nonzero("shortPeriod", short_period)?;
+    check_less_than("shortPeriod", short_period, "length", highs.len())?;
+    let short_period_model = crate::constant_model_type("shortPeriodModel", &short_period_model)?;
+    let long_period_model = crate::constant_model_type("longPeriodModel", &long_period_model)?;
+    Ok(rust_ti::momentum_indicators::single::chaikin_oscillator(
+        highs,
+        lows,
+        close,
+        volume,
+        short_period,
+        previous_accumulation_distribution,
+        short_period_model,
+        long_period_model,
+    ))
 }
 
+#[allow(clippy::too_many_arguments)]
 #[wasm_bindgen(js_name = momentum_single_chaikinOscillator)]
 pub fn momentum_single_chaikin_oscillator(
-    highs: Vec<f64>,
-    lows: Vec<f64>,
-    close: Vec<f64>,
-    volume: Vec<f64>,
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    volume: &[f64],
     short_period: usize,
     previous_accumulation_distribution: f64,
-    short_period_model: crate::ConstantModelType,
-    long_period_model: crate::ConstantModelType,
-) -> Array {
-    let (v, ad) = rust_ti::momentum_indicators::single::chaikin_oscillator(
-        &highs,
-        &lows,
-        &close,
-        &volume,
+    short_period_model: crate::ConstantModelLike,
+    long_period_model: crate::ConstantModelLike,
+) -> IndicatorResult<Array> {
+    let (v, ad) = chaikin_oscillator_value(
+        highs,
+        lows,
+        close,
+        volume,
         short_period,
         previous_accumulation_distribution,
-        short_period_model.into(),
-        long_period_model.into(),
-    );
+        short_period_model,
+        long_period_model,
+    )?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(v));
     arr.push(&JsValue::from_f64(ad));
-    arr
+    Ok(arr)
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = momentum_single_chaikinOscillatorPoint)]
+pub fn momentum_single_chaikin_oscillator_point(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    volume: &[f64],
+    short_period: usize,
+    previous_accumulation_distribution: f64,
+    short_period_model: crate::ConstantModelLike,
+    long_period_model: crate::ConstantModelLike,
+) -> IndicatorResult<ChaikinOscillatorPoint> {
+    Ok(chaikin_oscillator_value(
+        highs,
+        lows,
+        close,
+        volume,
+        short_period,
+        previous_accumulation_distribution,
+        short_period_model,
+        long_period_model,
+    )?
+    .into())
 }
 
 #[wasm_bindgen(js_name = momentum_single_percentagePriceOscillator)]
 pub fn momentum_single_percentage_price_oscillator(
-    prices: Vec<f64>,
+    prices: &[f64],
     short_period: usize,
-    constant_model_type: crate::ConstantModelType,
-) -> f64 {
-    rust_ti::momentum_indicators::single::percentage_price_oscillator(
-        &prices,
-        short_period,
-        constant_model_type.into(),
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<f64> {
+    check_period("shortPeriod", short_period, prices.len())?;
+    let constant_model_type =
+        crate::constant_model_type("constantModelType", &constant_model_type)?;
+    Ok(
+        rust_ti::momentum_indicators::single::percentage_price_oscillator(
+            prices,
+            short_period,
+            constant_model_type,
+        ),
     )
 }
 
 #[wasm_bindgen(js_name = momentum_single_chandeMomentumOscillator)]
-pub fn momentum_single_chande_momentum_oscillator(prices: Vec<f64>) -> f64 {
-    rust_ti::momentum_indicators::single::chande_momentum_oscillator(&prices)
+pub fn momentum_single_chande_momentum_oscillator(prices: &[f64]) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    Ok(rust_ti::momentum_indicators::single::chande_momentum_oscillator(prices))
 }
 
 // -------- BULK --------
 #[wasm_bindgen(js_name = momentum_bulk_relativeStrengthIndex)]
 pub fn momentum_bulk_relative_strength_index(
-    prices: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    prices: &[f64],
+    constant_model_type: crate::FamilyModelLike,
     period: usize,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::relative_strength_index(
-        &prices,
-        constant_model_type.into(),
-        period,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        let constant_model = crate::constant_model("constantModelType", &constant_model_type)?;
+        Ok(prices
+            .windows(period)
+            .map(|window| crate::averages::relative_strength_index(window, constant_model))
+            .collect())
+    })
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_stochasticOscillator)]
-pub fn momentum_bulk_stochastic_oscillator(prices: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::stochastic_oscillator(&prices, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn momentum_bulk_stochastic_oscillator(
+    prices: &[f64],
+    period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        Ok(rust_ti::momentum_indicators::bulk::stochastic_oscillator(
+            prices, period,
+        ))
+    })
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_slowStochastic)]
 pub fn momentum_bulk_slow_stochastic(
-    stochastics: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    stochastics: &[f64],
+    constant_model_type: crate::ConstantModelLike,
     period: usize,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::slow_stochastic(
-        &stochastics,
-        constant_model_type.into(),
-        period,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["stochastics"], [stochastics], |[stochastics]| {
+        check_period("period", period, stochastics.len())?;
+        let constant_model_type =
+            crate::constant_model_type("constantModelType", &constant_model_type)?;
+        Ok(rust_ti::momentum_indicators::bulk::slow_stochastic(
+            stochastics,
+            constant_model_type,
+            period,
+        ))
+    })
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_slowestStochastic)]
 pub fn momentum_bulk_slowest_stochastic(
-    slow_stochastics: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    slow_stochastics: &[f64],
+    constant_model_type: crate::ConstantModelLike,
     period: usize,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::slowest_stochastic(
-        &slow_stochastics,
-        constant_model_type.into(),
-        period,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(
+        ["slowStochastics"],
+        [slow_stochastics],
+        |[slow_stochastics]| {
+            check_period("period", period, slow_stochastics.len())?;
+            let constant_model_type =
+                crate::constant_model_type("constantModelType", &constant_model_type)?;
+            Ok(rust_ti::momentum_indicators::bulk::slowest_stochastic(
+                slow_stochastics,
+                constant_model_type,
+                period,
+            ))
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_williamsPercentR)]
 pub fn momentum_bulk_williams_percent_r(
-    high: Vec<f64>,
-    low: Vec<f64>,
-    close: Vec<f64>,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
     period: usize,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::williams_percent_r(&high, &low, &close, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(
+        ["high", "low", "close"],
+        [high, low, close],
+        |[high, low, close]| {
+            check_same_length("close", close.len(), "high", high.len())?;
+            check_same_length("close", close.len(), "low", low.len())?;
+            check_period("period", period, close.len())?;
+            Ok(rust_ti::momentum_indicators::bulk::williams_percent_r(
+                high, low, close, period,
+            ))
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_moneyFlowIndex)]
-pub fn momentum_bulk_money_flow_index(prices: Vec<f64>, volume: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::money_flow_index(&prices, &volume, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn momentum_bulk_money_flow_index(
+    prices: &[f64],
+    volume: &[f64],
+    period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices", "volume"], [prices, volume], |[prices, volume]| {
+        check_same_length("prices", prices.len(), "volume", volume.len())?;
+        check_period("period", period, prices.len())?;
+        Ok(rust_ti::momentum_indicators::bulk::money_flow_index(
+            prices, volume, period,
+        ))
+    })
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_rateOfChange)]
-pub fn momentum_bulk_rate_of_change(prices: Vec<f64>) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::rate_of_change(&prices);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn momentum_bulk_rate_of_change(prices: &[f64]) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_not_empty("prices", prices)?;
+        Ok(rust_ti::momentum_indicators::bulk::rate_of_change(prices))
+    })
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_onBalanceVolume)]
 pub fn momentum_bulk_on_balance_volume(
-    prices: Vec<f64>,
-    volume: Vec<f64>,
+    prices: &[f64],
+    volume: &[f64],
     previous_on_balance_volume: f64,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::on_balance_volume(
-        &prices,
-        &volume,
+) -> IndicatorResult<Vec<f64>> {
+    bulk_seeded(
+        ["prices", "volume"],
+        [prices, volume],
         previous_on_balance_volume,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+        |value| *value,
+        |[prices, volume], previous_on_balance_volume| {
+            check_same_length("prices", prices.len(), "volume", volume.len())?;
+            check_min_length("prices", prices.len(), 2)?;
+            Ok(rust_ti::momentum_indicators::bulk::on_balance_volume(
+                prices,
+                volume,
+                previous_on_balance_volume,
+            ))
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_commodityChannelIndex)]
 pub fn momentum_bulk_commodity_channel_index(
-    prices: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    prices: &[f64],
+    constant_model_type: crate::ConstantModelLike,
     deviation_model: crate::DeviationModel,
     constant_multiplier: f64,
     period: usize,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::commodity_channel_index(
-        &prices,
-        constant_model_type.into(),
-        deviation_model.into(),
-        constant_multiplier,
-        period,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        check_deviation_model(deviation_model, period, prices)?;
+        let constant_model_type =
+            crate::constant_model_type("constantModelType", &constant_model_type)?;
+        Ok(rust_ti::momentum_indicators::bulk::commodity_channel_index(
+            prices,
+            constant_model_type,
+            deviation_model.into(),
+            constant_multiplier,
+            period,
+        ))
+    })
+}
+
+fn mcginley_dynamic_commodity_channel_index_rows(
+    prices: &[f64],
+    previous_mcginley_dynamic: f64,
+    deviation_model: crate::DeviationModel,
+    constant_multiplier: f64,
+    period: usize,
+) -> IndicatorResult<Vec<(f64, f64)>> {
+    bulk_seeded(
+        ["prices"],
+        [prices],
+        previous_mcginley_dynamic,
+        |(_, mcginley)| *mcginley,
+        |[prices], previous_mcginley_dynamic| {
+            check_period("period", period, prices.len())?;
+            check_deviation_model(deviation_model, period, prices)?;
+            Ok(
+                rust_ti::momentum_indicators::bulk::mcginley_dynamic_commodity_channel_index(
+                    prices,
+                    previous_mcginley_dynamic,
+                    deviation_model.into(),
+                    constant_multiplier,
+                    period,
+                ),
+            )
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_mcginleyDynamicCommodityChannelIndex)]
 pub fn momentum_bulk_mcginley_dynamic_commodity_channel_index(
-    prices: Vec<f64>,
+    prices: &[f64],
     previous_mcginley_dynamic: f64,
     deviation_model: crate::DeviationModel,
     constant_multiplier: f64,
     period: usize,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::mcginley_dynamic_commodity_channel_index(
-        &prices,
+) -> IndicatorResult<Array> {
+    let data = mcginley_dynamic_commodity_channel_index_rows(
+        prices,
         previous_mcginley_dynamic,
-        deviation_model.into(),
+        deviation_model,
         constant_multiplier,
         period,
-    );
+    )?;
     let out = Array::new();
     for (v, m) in data {
         let inner = Array::new();
@@ -352,61 +580,118 @@ pub fn momentum_bulk_mcginley_dynamic_commodity_channel_index(
         inner.push(&JsValue::from_f64(m));
         out.push(&inner);
     }
-    out
+    Ok(out)
+}
+
+#[wasm_bindgen(js_name = momentum_bulk_mcginleyDynamicCommodityChannelIndexColumns)]
+pub fn momentum_bulk_mcginley_dynamic_commodity_channel_index_columns(
+    prices: &[f64],
+    previous_mcginley_dynamic: f64,
+    deviation_model: crate::DeviationModel,
+    constant_multiplier: f64,
+    period: usize,
+) -> IndicatorResult<Object> {
+    let data = mcginley_dynamic_commodity_channel_index_rows(
+        prices,
+        previous_mcginley_dynamic,
+        deviation_model,
+        constant_multiplier,
+        period,
+    )?;
+    Ok(columns(
+        ["cci", "mcginley"],
+        data.into_iter().map(|(v, m)| [v, m]),
+    ))
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_macdLine)]
 pub fn momentum_bulk_macd_line(
-    prices: Vec<f64>,
+    prices: &[f64],
     short_period: usize,
-    short_period_model: crate::ConstantModelType,
+    short_period_model: crate::FamilyModelLike,
     long_period: usize,
-    long_period_model: crate::ConstantModelType,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::macd_line(
-        &prices,
-        short_period,
-        short_period_model.into(),
-        long_period,
-        long_period_model.into(),
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+    long_period_model: crate::FamilyModelLike,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period_nonzero("shortPeriod", short_period)?;
+        check_less_than("shortPeriod", short_period, "longPeriod", long_period)?;
+        check_period("longPeriod", long_period, prices.len())?;
+        let short_period_model = crate::constant_model("shortPeriodModel", &short_period_model)?;
+        let long_period_model = crate::constant_model("longPeriodModel", &long_period_model)?;
+        Ok(prices
+            .windows(long_period)
+            .map(|window| {
+                crate::averages::macd_line(
+                    window,
+                    short_period,
+                    short_period_model,
+                    long_period_model,
+                )
+            })
+            .collect())
+    })
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_signalLine)]
 pub fn momentum_bulk_signal_line(
-    macds: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    macds: &[f64],
+    constant_model_type: crate::FamilyModelLike,
     period: usize,
-) -> Array {
-    let data =
-        rust_ti::momentum_indicators::bulk::signal_line(&macds, constant_model_type.into(), period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["macds"], [macds], |[macds]| {
+        check_period("period", period, macds.len())?;
+        let constant_model = crate::constant_model("constantModelType", &constant_model_type)?;
+        Ok(macds
+            .windows(period)
+            .map(|window| crate::averages::signal_line(window, constant_model))
+            .collect())
+    })
+}
+
+fn mcginley_dynamic_macd_line_rows(
+    prices: &[f64],
+    short_period: usize,
+    previous_short_mcginley: f64,
+    long_period: usize,
+    previous_long_mcginley: f64,
+) -> IndicatorResult<Vec<(f64, f64, f64)>> {
+    bulk_seeded(
+        ["prices"],
+        [prices],
+        (previous_short_mcginley, previous_long_mcginley),
+        |(_, short, long)| (*short, *long),
+        |[prices], (previous_short_mcginley, previous_long_mcginley)| {
+            check_period_nonzero("shortPeriod", short_period)?;
+            check_less_than("shortPeriod", short_period, "longPeriod", long_period)?;
+            check_period("longPeriod", long_period, prices.len())?;
+            Ok(
+                rust_ti::momentum_indicators::bulk::mcginley_dynamic_macd_line(
+                    prices,
+                    short_period,
+                    previous_short_mcginley,
+                    long_period,
+                    previous_long_mcginley,
+                ),
+            )
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_mcginleyDynamicMacdLine)]
 pub fn momentum_bulk_mcginley_dynamic_macd_line(
-    prices: Vec<f64>,
+    prices: &[f64],
     short_period: usize,
     previous_short_mcginley: f64,
     long_period: usize,
     previous_long_mcginley: f64,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::mcginley_dynamic_macd_line(
-        &prices,
+) -> IndicatorResult<Array> {
+    let data = mcginley_dynamic_macd_line_rows(
+        prices,
         short_period,
         previous_short_mcginley,
         long_period,
         previous_long_mcginley,
-    );
+    )?;
     let out = Array::new();
     for (macd, short_m, long_m) in data {
         let inner = Array::new();
@@ -415,32 +700,98 @@ pub fn momentum_bulk_mcginley_dynamic_macd_line(
         inner.push(&JsValue::from_f64(long_m));
         out.push(&inner);
     }
-    out
+    Ok(out)
 }
 
+#[wasm_bindgen(js_name = momentum_bulk_mcginleyDynamicMacdLineColumns)]
+pub fn momentum_bulk_mcginley_dynamic_macd_line_columns(
+    prices: &[f64],
+    short_period: usize,
+    previous_short_mcginley: f64,
+    long_period: usize,
+    previous_long_mcginley: f64,
+) -> IndicatorResult<Object> {
+    let data = mcginley_dynamic_macd_line_rows(
+        prices,
+        short_period,
+        previous_short_mcginley,
+        long_period,
+        previous_long_mcginley,
+    )?;
+    Ok(columns(
+        ["macd", "shortMcginley", "longMcginley"],
+        data.into_iter()
+            .map(|(macd, short_m, long_m)| [macd, short_m, long_m]),
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn chaikin_oscillator_rows(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    volume: &[f64],
+    short_period: usize,
+    long_period: usize,
+    previous_accumulation_distribution: f64,
+    short_period_model: crate::ConstantModelLike,
+    long_period_model: crate::ConstantModelLike,
+) -> IndicatorResult<Vec<(f64, f64)>> {
+    bulk_seeded(
+        ["highs", "lows", "close", "volume"],
+        [highs, lows, close, volume],
+        previous_accumulation_distribution,
+        |(_, ad)| *ad,
+        |[highs, lows, close, volume], previous_accumulation_distribution| {
+            check_same_length("highs", highs.len(), "lows", lows.len())?;
+            check_same_length("highs", highs.len(), "close", close.len())?;
+            check_same_length("highs", highs.len(), "volume", volume.len())?;
+            check_period_nonzero("shortPeriod", short_period)?;
+            check_less_than("shortPeriod", short_period, "longPeriod", long_period)?;
+            check_period("longPeriod", long_period, highs.len())?;
+            let short_period_model =
+                crate::constant_model_type("shortPeriodModel", &short_period_model)?;
+            let long_period_model =
+                crate::constant_model_type("longPeriodModel", &long_period_model)?;
+            Ok(rust_ti::momentum_indicators::bulk::chaikin_oscillator(
+                highs,
+                lows,
+                close,
+                volume,
+                short_period,
+                long_period,
+                previous_accumulation_distribution,
+                short_period_model,
+                long_period_model,
+            ))
+        },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 #[wasm_bindgen(js_name = momentum_bulk_chaikinOscillator)]
 pub fn momentum_bulk_chaikin_oscillator(
-    highs: Vec<f64>,
-    lows: Vec<f64>,
-    close: Vec<f64>,
-    volume: Vec<f64>,
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    volume: &[f64],
     short_period: usize,
     long_period: usize,
     previous_accumulation_distribution: f64,
-    short_period_model: crate::ConstantModelType,
-    long_period_model: crate::ConstantModelType,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::chaikin_oscillator(
-        &highs,
-        &lows,
-        &close,
-        &volume,
+    short_period_model: crate::ConstantModelLike,
+    long_period_model: crate::ConstantModelLike,
+) -> IndicatorResult<Array> {
+    let data = chaikin_oscillator_rows(
+        highs,
+        lows,
+        close,
+        volume,
         short_period,
         long_period,
         previous_accumulation_distribution,
-        short_period_model.into(),
-        long_period_model.into(),
-    );
+        short_period_model,
+        long_period_model,
+    )?;
     let out = Array::new();
     for (v, ad) in data {
         let inner = Array::new();
@@ -448,35 +799,70 @@ pub fn momentum_bulk_chaikin_oscillator(
         inner.push(&JsValue::from_f64(ad));
         out.push(&inner);
     }
-    out
+    Ok(out)
 }
 
-#[wasm_bindgen(js_name = momentum_bulk_percentagePriceOscillator)]
-pub fn momentum_bulk_percentage_price_oscillator(
-    prices: Vec<f64>,
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = momentum_bulk_chaikinOscillatorColumns)]
+pub fn momentum_bulk_chaikin_oscillator_columns(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    volume: &[f64],
     short_period: usize,
     long_period: usize,
-    constant_model_type: crate::ConstantModelType,
-) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::percentage_price_oscillator(
-        &prices,
+    previous_accumulation_distribution: f64,
+    short_period_model: crate::ConstantModelLike,
+    long_period_model: crate::ConstantModelLike,
+) -> IndicatorResult<Object> {
+    let data = chaikin_oscillator_rows(
+        highs,
+        lows,
+        close,
+        volume,
         short_period,
         long_period,
-        constant_model_type.into(),
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+        previous_accumulation_distribution,
+        short_period_model,
+        long_period_model,
+    )?;
+    Ok(columns(
+        ["oscillator", "accumulationDistribution"],
+        data.into_iter().map(|(v, ad)| [v, ad]),
+    ))
+}
+
+#[wasm_bindgen(js_name = momentum_bulk_percentagePriceOscillator)]
+pub fn momentum_bulk_percentage_price_oscillator(
+    prices: &[f64],
+    short_period: usize,
+    long_period: usize,
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period_nonzero("shortPeriod", short_period)?;
+        check_less_than("shortPeriod", short_period, "longPeriod", long_period)?;
+        check_period("longPeriod", long_period, prices.len())?;
+        let constant_model_type =
+            crate::constant_model_type("constantModelType", &constant_model_type)?;
+        Ok(
+            rust_ti::momentum_indicators::bulk::percentage_price_oscillator(
+                prices,
+                short_period,
+                long_period,
+                constant_model_type,
+            ),
+        )
+    })
 }
 
 #[wasm_bindgen(js_name = momentum_bulk_chandeMomentumOscillator)]
-pub fn momentum_bulk_chande_momentum_oscillator(prices: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::momentum_indicators::bulk::chande_momentum_oscillator(&prices, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn momentum_bulk_chande_momentum_oscillator(
+    prices: &[f64],
+    period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        Ok(rust_ti::momentum_indicators::bulk::chande_momentum_oscillator(prices, period))
+    })
 }
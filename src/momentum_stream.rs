@@ -0,0 +1,1397 @@
+use crate::averages::ConstantModel;
+use crate::error::{check_deviation_model, check_less_than, check_period_nonzero, IndicatorResult};
+use crate::state::{state_fields, stream_state};
+use crate::stream::{extend, open_bar, MovingConstant, RollingExtreme, Window};
+use rust_ti::momentum_indicators::single;
+use rust_ti::moving_average::single::mcginley_dynamic;
+use wasm_bindgen::prelude::*;
+
+// Streaming momentum oscillators.
+//
+// Each class takes one bar per `push` and returns the value the matching `momentumIndicators.bulk`
+// function returns for that bar (NaN during warm-up). The stochastic and Williams %R keep rolling
+// maxima and minima, MACD and PPO keep one moving constant per period, the slow and slowest
+// stochastics and the MACD signal line feed a nested moving constant, and the McGinley variants
+// update in O(1). RSI, MFI, CCI, Chaikin and Chande keep their window in WASM memory and run
+// RustTI's single function over it, which is what their bulk functions do for every bar.
+
+// Rolling stochastic oscillator shared by the stochastic streams
+#[derive(Clone, Debug)]
+struct Stochastic {
+    max: RollingExtreme,
+    min: RollingExtreme,
+}
+
+state_fields!(Stochastic { max, min });
+
+impl Stochastic {
+    fn new(period: usize) -> Self {
+        Self {
+            max: RollingExtreme::maximum(period),
+            min: RollingExtreme::minimum(period),
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.max.is_full()
+    }
+
+    fn push(&mut self, price: f64) -> f64 {
+        let max = self.max.push(price);
+        let min = self.min.push(price);
+        if !self.is_full() {
+            return f64::NAN;
+        }
+        100.0 * ((price - min) / (max - min))
+    }
+
+    fn clear(&mut self) {
+        self.max.clear();
+        self.min.clear();
+    }
+}
+
+/// Relative strength index of the latest `period` prices, matching
+/// `momentumIndicators.bulk.relativeStrengthIndex`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct RelativeStrengthIndexStream {
+    prices: Window,
+    constant_model_type: ConstantModel,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl RelativeStrengthIndexStream {
+    /// Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        constant_model_type: crate::FamilyModelLike,
+        period: usize,
+    ) -> IndicatorResult<RelativeStrengthIndexStream> {
+        check_period_nonzero("period", period)?;
+        Ok(Self {
+            prices: Window::new(period),
+            constant_model_type: crate::constant_model("constantModelType", &constant_model_type)?,
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest RSI (NaN until `period` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        self.prices.push(price);
+        if self.prices.is_full() {
+            self.value = crate::averages::relative_strength_index(
+                self.prices.as_slice(),
+                self.constant_model_type,
+            );
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period()
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(RelativeStrengthIndexStream, (price: f64) -> f64);
+extend!(RelativeStrengthIndexStream, (prices) -> Vec<f64>);
+stream_state!(RelativeStrengthIndexStream {
+    prices,
+    constant_model_type,
+    value,
+    committed
+});
+
+/// Stochastic oscillator of the latest `period` prices, matching
+/// `momentumIndicators.bulk.stochasticOscillator`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct StochasticOscillatorStream {
+    stochastic: Stochastic,
+    period: usize,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl StochasticOscillatorStream {
+    /// Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(period: usize) -> IndicatorResult<StochasticOscillatorStream> {
+        check_period_nonzero("period", period)?;
+        Ok(Self {
+            stochastic: Stochastic::new(period),
+            period,
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest stochastic (NaN until `period` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        self.value = self.stochastic.push(price);
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.stochastic.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.stochastic.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(StochasticOscillatorStream, (price: f64) -> f64);
+extend!(StochasticOscillatorStream, (prices) -> Vec<f64>);
+stream_state!(StochasticOscillatorStream {
+    stochastic,
+    period,
+    value,
+    committed
+});
+
+/// Slow stochastic computed from prices: `momentumIndicators.bulk.slowStochastic` over the
+/// `stochasticPeriod` stochastic oscillator.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct SlowStochasticStream {
+    stochastic: Stochastic,
+    stochastic_period: usize,
+    slow: MovingConstant,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl SlowStochasticStream {
+    /// Throws unless both periods are greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        stochastic_period: usize,
+        constant_model_type: crate::ConstantModelLike,
+        period: usize,
+    ) -> IndicatorResult<SlowStochasticStream> {
+        check_period_nonzero("stochasticPeriod", stochastic_period)?;
+        check_period_nonzero("period", period)?;
+        let constant_model_type =
+            crate::constant_model_type("constantModelType", &constant_model_type)?;
+        Ok(Self {
+            stochastic: Stochastic::new(stochastic_period),
+            stochastic_period,
+            slow: MovingConstant::new(constant_model_type, period),
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest slow stochastic (NaN until
+    /// `stochasticPeriod + period - 1` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        let stochastic = self.stochastic.push(price);
+        if self.stochastic.is_full() {
+            self.value = self.slow.push(stochastic);
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.slow.period()
+    }
+
+    #[wasm_bindgen(getter, js_name = stochasticPeriod)]
+    pub fn stochastic_period(&self) -> usize {
+        self.stochastic_period
+    }
+
+    /// Whether `stochasticPeriod + period - 1` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.slow.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.stochastic.clear();
+        self.slow.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(SlowStochasticStream, (price: f64) -> f64);
+extend!(SlowStochasticStream, (prices) -> Vec<f64>);
+stream_state!(SlowStochasticStream {
+    stochastic,
+    stochastic_period,
+    slow,
+    value,
+    committed
+});
+
+/// Slowest stochastic computed from prices: `momentumIndicators.bulk.slowestStochastic` over the
+/// slow stochastic over the `stochasticPeriod` stochastic oscillator.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct SlowestStochasticStream {
+    stochastic: Stochastic,
+    stochastic_period: usize,
+    slow: MovingConstant,
+    slowest: MovingConstant,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl SlowestStochasticStream {
+    /// Throws unless every period is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        stochastic_period: usize,
+        slow_model: crate::ConstantModelLike,
+        slow_period: usize,
+        constant_model_type: crate::ConstantModelLike,
+        period: usize,
+    ) -> IndicatorResult<SlowestStochasticStream> {
+        check_period_nonzero("stochasticPeriod", stochastic_period)?;
+        check_period_nonzero("slowPeriod", slow_period)?;
+        check_period_nonzero("period", period)?;
+        let slow_model = crate::constant_model_type("slowModel", &slow_model)?;
+        let constant_model_type =
+            crate::constant_model_type("constantModelType", &constant_model_type)?;
+        Ok(Self {
+            stochastic: Stochastic::new(stochastic_period),
+            stochastic_period,
+            slow: MovingConstant::new(slow_model, slow_period),
+            slowest: MovingConstant::new(constant_model_type, period),
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest slowest stochastic (NaN until
+    /// `stochasticPeriod + slowPeriod + period - 2` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        let stochastic = self.stochastic.push(price);
+        if !self.stochastic.is_full() {
+            return self.value;
+        }
+        let slow = self.slow.push(stochastic);
+        if self.slow.is_full() {
+            self.value = self.slowest.push(slow);
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.slowest.period()
+    }
+
+    #[wasm_bindgen(getter, js_name = slowPeriod)]
+    pub fn slow_period(&self) -> usize {
+        self.slow.period()
+    }
+
+    #[wasm_bindgen(getter, js_name = stochasticPeriod)]
+    pub fn stochastic_period(&self) -> usize {
+        self.stochastic_period
+    }
+
+    /// Whether `stochasticPeriod + slowPeriod + period - 2` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.slowest.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.stochastic.clear();
+        self.slow.clear();
+        self.slowest.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(SlowestStochasticStream, (price: f64) -> f64);
+extend!(SlowestStochasticStream, (prices) -> Vec<f64>);
+stream_state!(SlowestStochasticStream {
+    stochastic,
+    stochastic_period,
+    slow,
+    slowest,
+    value,
+    committed
+});
+
+/// Williams %R over the latest `period` bars, matching `momentumIndicators.bulk.williamsPercentR`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct WilliamsPercentRStream {
+    max_high: RollingExtreme,
+    min_low: RollingExtreme,
+    period: usize,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl WilliamsPercentRStream {
+    /// Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(period: usize) -> IndicatorResult<WilliamsPercentRStream> {
+        check_period_nonzero("period", period)?;
+        Ok(Self {
+            max_high: RollingExtreme::maximum(period),
+            min_low: RollingExtreme::minimum(period),
+            period,
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next bar and returns the latest Williams %R (NaN until `period` bars).
+    pub fn push(&mut self, high: f64, low: f64, close: f64) -> f64 {
+        self.revert_open_bar();
+        let max_high = self.max_high.push(high);
+        let min_low = self.min_low.push(low);
+        if self.max_high.is_full() {
+            self.value = -100.0_f64 * ((max_high - close) / (max_high - min_low));
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Whether `period` bars have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.max_high.is_full()
+    }
+
+    /// Forgets every pushed bar.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.max_high.clear();
+        self.min_low.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(WilliamsPercentRStream, (high: f64, low: f64, close: f64) -> f64);
+extend!(WilliamsPercentRStream, (highs, lows, close) -> Vec<f64>);
+stream_state!(WilliamsPercentRStream {
+    max_high,
+    min_low,
+    period,
+    value,
+    committed
+});
+
+/// Money flow index of the latest `period` bars, matching `momentumIndicators.bulk.moneyFlowIndex`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct MoneyFlowIndexStream {
+    prices: Window,
+    volume: Window,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl MoneyFlowIndexStream {
+    /// Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(period: usize) -> IndicatorResult<MoneyFlowIndexStream> {
+        check_period_nonzero("period", period)?;
+        Ok(Self {
+            prices: Window::new(period),
+            volume: Window::new(period),
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and volume and returns the latest MFI (NaN until `period` bars).
+    pub fn push(&mut self, price: f64, volume: f64) -> f64 {
+        self.revert_open_bar();
+        self.prices.push(price);
+        self.volume.push(volume);
+        if self.prices.is_full() {
+            self.value = single::money_flow_index(self.prices.as_slice(), self.volume.as_slice());
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period()
+    }
+
+    /// Whether `period` bars have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed bar.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.volume.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(MoneyFlowIndexStream, (price: f64, volume: f64) -> f64);
+extend!(MoneyFlowIndexStream, (prices, volumes) -> Vec<f64>);
+stream_state!(MoneyFlowIndexStream {
+    prices,
+    volume,
+    value,
+    committed
+});
+
+/// Rate of change against the previous price, matching `momentumIndicators.bulk.rateOfChange`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct RateOfChangeStream {
+    previous: f64,
+    pushed: usize,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl RateOfChangeStream {
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> RateOfChangeStream {
+        Self {
+            previous: f64::NAN,
+            pushed: 0,
+            value: f64::NAN,
+            committed: None,
+        }
+    }
+
+    /// Adds the next price and returns the latest rate of change (NaN for the first price).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        self.pushed += 1;
+        if self.pushed > 1 {
+            self.value = single::rate_of_change(price, self.previous);
+        }
+        self.previous = price;
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Whether two prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.pushed > 1
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.pushed = 0;
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(RateOfChangeStream, (price: f64) -> f64);
+extend!(RateOfChangeStream, (prices) -> Vec<f64>);
+stream_state!(RateOfChangeStream {
+    previous,
+    pushed,
+    value,
+    committed
+});
+
+/// On-balance volume, matching `momentumIndicators.bulk.onBalanceVolume`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct OnBalanceVolumeStream {
+    seed: f64,
+    previous: f64,
+    pushed: usize,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl OnBalanceVolumeStream {
+    /// `previousOnBalanceVolume` seeds the first value like the bulk argument (default 0).
+    #[wasm_bindgen(constructor)]
+    pub fn new(previous_on_balance_volume: Option<f64>) -> OnBalanceVolumeStream {
+        Self {
+            seed: previous_on_balance_volume.unwrap_or(0.0),
+            previous: f64::NAN,
+            pushed: 0,
+            value: f64::NAN,
+            committed: None,
+        }
+    }
+
+    /// Adds the next price and volume and returns the latest OBV (NaN for the first bar).
+    pub fn push(&mut self, price: f64, volume: f64) -> f64 {
+        self.revert_open_bar();
+        self.pushed += 1;
+        if self.pushed > 1 {
+            let previous_obv = if self.pushed == 2 {
+                self.seed
+            } else {
+                self.value
+            };
+            self.value = single::on_balance_volume(price, self.previous, volume, previous_obv);
+        }
+        self.previous = price;
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Whether two bars have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.pushed > 1
+    }
+
+    /// Forgets every pushed bar; the next value starts from the constructor seed again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.pushed = 0;
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(OnBalanceVolumeStream, (price: f64, volume: f64) -> f64);
+extend!(OnBalanceVolumeStream, (prices, volumes) -> Vec<f64>);
+stream_state!(OnBalanceVolumeStream {
+    seed,
+    previous,
+    pushed,
+    value,
+    committed
+});
+
+/// Commodity channel index of the latest `period` prices, matching
+/// `momentumIndicators.bulk.commodityChannelIndex`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CommodityChannelIndexStream {
+    prices: Window,
+    constant_model_type: rust_ti::ConstantModelType,
+    deviation_model: crate::DeviationModel,
+    constant_multiplier: f64,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl CommodityChannelIndexStream {
+    /// Throws unless `period` is greater than 0 (at least 4 for `CauchyIQRScale`).
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        constant_model_type: crate::ConstantModelLike,
+        deviation_model: crate::DeviationModel,
+        constant_multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<CommodityChannelIndexStream> {
+        check_period_nonzero("period", period)?;
+        check_deviation_model(deviation_model, period, &[])?;
+        Ok(Self {
+            prices: Window::new(period),
+            constant_model_type: crate::constant_model_type(
+                "constantModelType",
+                &constant_model_type,
+            )?,
+            deviation_model,
+            constant_multiplier,
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest CCI (NaN until `period` prices).
+    ///
+    /// Throws, without adding the price, if it is not positive under `LogStandardDeviation`.
+    pub fn push(&mut self, price: f64) -> IndicatorResult<f64> {
+        self.revert_open_bar();
+        check_deviation_model(self.deviation_model, self.prices.period(), &[price])?;
+        self.prices.push(price);
+        if self.prices.is_full() {
+            self.value = single::commodity_channel_index(
+                self.prices.as_slice(),
+                self.constant_model_type,
+                self.deviation_model.into(),
+                self.constant_multiplier,
+            );
+        }
+        Ok(self.value)
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period()
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(CommodityChannelIndexStream, (price: f64) -> IndicatorResult<f64>);
+extend!(CommodityChannelIndexStream, (prices) -> Vec<f64>);
+stream_state!(CommodityChannelIndexStream {
+    prices,
+    constant_model_type,
+    deviation_model,
+    constant_multiplier,
+    value,
+    committed
+});
+
+/// McGinley dynamic commodity channel index of the latest `period` prices, matching
+/// `momentumIndicators.bulk.mcginleyDynamicCommodityChannelIndex`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct McGinleyDynamicCommodityChannelIndexStream {
+    prices: Window,
+    seed: f64,
+    deviation_model: crate::DeviationModel,
+    constant_multiplier: f64,
+    // Whether `mcginley` belongs to a previous window rather than the seed
+    started: bool,
+    value: f64,
+    mcginley: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl McGinleyDynamicCommodityChannelIndexStream {
+    /// `previousMcginleyDynamic` seeds the first value like the bulk argument (0 to start from
+    /// the price). Throws unless `period` is greater than 0 (at least 4 for `CauchyIQRScale`).
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        previous_mcginley_dynamic: f64,
+        deviation_model: crate::DeviationModel,
+        constant_multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<McGinleyDynamicCommodityChannelIndexStream> {
+        check_period_nonzero("period", period)?;
+        check_deviation_model(deviation_model, period, &[])?;
+        Ok(Self {
+            prices: Window::new(period),
+            seed: previous_mcginley_dynamic,
+            deviation_model,
+            constant_multiplier,
+            started: false,
+            value: f64::NAN,
+            mcginley: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest CCI (NaN until `period` prices).
+    ///
+    /// Throws, without adding the price, if it is not positive under `LogStandardDeviation`.
+    pub fn push(&mut self, price: f64) -> IndicatorResult<f64> {
+        self.revert_open_bar();
+        check_deviation_model(self.deviation_model, self.prices.period(), &[price])?;
+        self.prices.push(price);
+        if self.prices.is_full() {
+            let previous = if self.started {
+                self.mcginley
+            } else {
+                self.seed
+            };
+            self.started = true;
+            (self.value, self.mcginley) = single::mcginley_dynamic_commodity_channel_index(
+                self.prices.as_slice(),
+                previous,
+                self.deviation_model.into(),
+                self.constant_multiplier,
+            );
+        }
+        Ok(self.value)
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// McGinley dynamic behind the latest value.
+    #[wasm_bindgen(getter)]
+    pub fn mcginley(&self) -> f64 {
+        self.mcginley
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period()
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price; the next value starts from the constructor seed again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.started = false;
+        self.value = f64::NAN;
+        self.mcginley = f64::NAN;
+    }
+}
+
+open_bar!(McGinleyDynamicCommodityChannelIndexStream, (price: f64) -> IndicatorResult<f64>);
+extend!(McGinleyDynamicCommodityChannelIndexStream, (prices) -> Vec<f64>);
+stream_state!(McGinleyDynamicCommodityChannelIndexStream {
+    prices,
+    seed,
+    deviation_model,
+    constant_multiplier,
+    started,
+    value,
+    mcginley,
+    committed
+});
+
+/// MACD line with an optional signal line, matching `momentumIndicators.bulk.macdLine` and
+/// `momentumIndicators.bulk.signalLine` over its output.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct MacdLineStream {
+    short: MovingConstant,
+    long: MovingConstant,
+    signal: Option<MovingConstant>,
+    value: f64,
+    signal_value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl MacdLineStream {
+    /// `signalPeriod` adds a signal line (`signalModel` defaults to `ExponentialMovingAverage`).
+    /// Throws unless 0 < `shortPeriod` < `longPeriod` and `signalPeriod`, if given, is above 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        short_period: usize,
+        short_period_model: crate::FamilyModelLike,
+        long_period: usize,
+        long_period_model: crate::FamilyModelLike,
+        signal_period: Option<usize>,
+        signal_model: Option<crate::FamilyModelLike>,
+    ) -> IndicatorResult<MacdLineStream> {
+        check_period_nonzero("shortPeriod", short_period)?;
+        check_less_than("shortPeriod", short_period, "longPeriod", long_period)?;
+        let short_period_model = crate::constant_model("shortPeriodModel", &short_period_model)?;
+        let long_period_model = crate::constant_model("longPeriodModel", &long_period_model)?;
+        let signal = match signal_period {
+            Some(period) => {
+                check_period_nonzero("signalPeriod", period)?;
+                let model = match signal_model {
+                    Some(model) => crate::constant_model("signalModel", &model)?,
+                    None => rust_ti::ConstantModelType::ExponentialMovingAverage.into(),
+                };
+                Some(MovingConstant::new(model, period))
+            }
+            None => None,
+        };
+        Ok(Self {
+            short: MovingConstant::new(short_period_model, short_period),
+            long: MovingConstant::new(long_period_model, long_period),
+            signal,
+            value: f64::NAN,
+            signal_value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest MACD (NaN until `longPeriod` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        let short = self.short.push(price);
+        let long = self.long.push(price);
+        if !self.long.is_full() {
+            return self.value;
+        }
+        self.value = short - long;
+        if let Some(signal) = &mut self.signal {
+            self.signal_value = signal.push(self.value);
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Latest signal line (NaN until `longPeriod + signalPeriod - 1` prices, or without a
+    /// `signalPeriod`).
+    #[wasm_bindgen(getter)]
+    pub fn signal(&self) -> f64 {
+        self.signal_value
+    }
+
+    #[wasm_bindgen(getter, js_name = shortPeriod)]
+    pub fn short_period(&self) -> usize {
+        self.short.period()
+    }
+
+    #[wasm_bindgen(getter, js_name = longPeriod)]
+    pub fn long_period(&self) -> usize {
+        self.long.period()
+    }
+
+    /// Whether `longPeriod` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.long.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.short.clear();
+        self.long.clear();
+        if let Some(signal) = &mut self.signal {
+            signal.clear();
+        }
+        self.value = f64::NAN;
+        self.signal_value = f64::NAN;
+    }
+}
+
+open_bar!(MacdLineStream, (price: f64) -> f64);
+extend!(MacdLineStream, (prices) -> Vec<f64>);
+stream_state!(MacdLineStream {
+    short,
+    long,
+    signal,
+    value,
+    signal_value,
+    committed
+});
+
+/// McGinley dynamic MACD line, matching `momentumIndicators.bulk.mcginleyDynamicMacdLine`: NaN
+/// for the first `longPeriod - 1` prices, then one O(1) update per price.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct McGinleyDynamicMacdLineStream {
+    short_period: usize,
+    long_period: usize,
+    short_seed: f64,
+    long_seed: f64,
+    pushed: usize,
+    value: f64,
+    short_mcginley: f64,
+    long_mcginley: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl McGinleyDynamicMacdLineStream {
+    /// The previous McGinley dynamics seed the first value like the bulk arguments (both 0 to
+    /// start from the price). Throws unless 0 < `shortPeriod` < `longPeriod`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        short_period: usize,
+        previous_short_mcginley: f64,
+        long_period: usize,
+        previous_long_mcginley: f64,
+    ) -> IndicatorResult<McGinleyDynamicMacdLineStream> {
+        check_period_nonzero("shortPeriod", short_period)?;
+        check_less_than("shortPeriod", short_period, "longPeriod", long_period)?;
+        Ok(Self {
+            short_period,
+            long_period,
+            short_seed: previous_short_mcginley,
+            long_seed: previous_long_mcginley,
+            pushed: 0,
+            value: f64::NAN,
+            short_mcginley: f64::NAN,
+            long_mcginley: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest MACD (NaN until `longPeriod` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        self.pushed += 1;
+        if self.pushed < self.long_period {
+            return self.value;
+        }
+        let (previous_short, previous_long) = if self.pushed == self.long_period {
+            (self.short_seed, self.long_seed)
+        } else {
+            (self.short_mcginley, self.long_mcginley)
+        };
+        // RustTI's single function, which only reads the window's last price and length
+        if previous_short == 0.0 && previous_long == 0.0 {
+            (self.value, self.short_mcginley, self.long_mcginley) = (0.0, price, price);
+        } else {
+            self.long_mcginley = mcginley_dynamic(price, previous_long, self.long_period);
+            self.short_mcginley = mcginley_dynamic(price, previous_short, self.short_period);
+            self.value = self.short_mcginley - self.long_mcginley;
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Short-period McGinley dynamic behind the latest value.
+    #[wasm_bindgen(getter, js_name = shortMcginley)]
+    pub fn short_mcginley(&self) -> f64 {
+        self.short_mcginley
+    }
+
+    /// Long-period McGinley dynamic behind the latest value.
+    #[wasm_bindgen(getter, js_name = longMcginley)]
+    pub fn long_mcginley(&self) -> f64 {
+        self.long_mcginley
+    }
+
+    #[wasm_bindgen(getter, js_name = shortPeriod)]
+    pub fn short_period(&self) -> usize {
+        self.short_period
+    }
+
+    #[wasm_bindgen(getter, js_name = longPeriod)]
+    pub fn long_period(&self) -> usize {
+        self.long_period
+    }
+
+    /// Whether `longPeriod` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.pushed >= self.long_period
+    }
+
+    /// Forgets every pushed price; the next value starts from the constructor seeds again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.pushed = 0;
+        self.value = f64::NAN;
+        self.short_mcginley = f64::NAN;
+        self.long_mcginley = f64::NAN;
+    }
+}
+
+open_bar!(McGinleyDynamicMacdLineStream, (price: f64) -> f64);
+extend!(McGinleyDynamicMacdLineStream, (prices) -> Vec<f64>);
+stream_state!(McGinleyDynamicMacdLineStream {
+    short_period,
+    long_period,
+    short_seed,
+    long_seed,
+    pushed,
+    value,
+    short_mcginley,
+    long_mcginley,
+    committed
+});
+
+/// Chaikin oscillator over the latest `longPeriod` bars, matching
+/// `momentumIndicators.bulk.chaikinOscillator`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct ChaikinOscillatorStream {
+    highs: Window,
+    lows: Window,
+    close: Window,
+    volume: Window,
+    short_period: usize,
+    short_period_model: rust_ti::ConstantModelType,
+    long_period_model: rust_ti::ConstantModelType,
+    seed: f64,
+    // Whether `accumulation_distribution` belongs to a previous window rather than the seed
+    started: bool,
+    value: f64,
+    accumulation_distribution: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl ChaikinOscillatorStream {
+    /// `previousAccumulationDistribution` seeds the first window like the bulk argument.
+    /// Throws unless 0 < `shortPeriod` < `longPeriod`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        short_period: usize,
+        long_period: usize,
+        previous_accumulation_distribution: f64,
+        short_period_model: crate::ConstantModelLike,
+        long_period_model: crate::ConstantModelLike,
+    ) -> IndicatorResult<ChaikinOscillatorStream> {
+        check_period_nonzero("shortPeriod", short_period)?;
+        check_less_than("shortPeriod", short_period, "longPeriod", long_period)?;
+        Ok(Self {
+            highs: Window::new(long_period),
+            lows: Window::new(long_period),
+            close: Window::new(long_period),
+            volume: Window::new(long_period),
+            short_period,
+            short_period_model: crate::constant_model_type(
+                "shortPeriodModel",
+                &short_period_model,
+            )?,
+            long_period_model: crate::constant_model_type("longPeriodModel", &long_period_model)?,
+            seed: previous_accumulation_distribution,
+            started: false,
+            value: f64::NAN,
+            accumulation_distribution: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next bar and returns the latest oscillator value (NaN until `longPeriod` bars).
+    pub fn push(&mut self, high: f64, low: f64, close: f64, volume: f64) -> f64 {
+        self.revert_open_bar();
+        self.highs.push(high);
+        self.lows.push(low);
+        self.close.push(close);
+        self.volume.push(volume);
+        if !self.highs.is_full() {
+            return self.value;
+        }
+        // Like the bulk function, each window restarts from the previous window's last value
+        let previous = if self.started {
+            self.accumulation_distribution
+        } else {
+            self.seed
+        };
+        self.started = true;
+        (self.value, self.accumulation_distribution) = single::chaikin_oscillator(
+            self.highs.as_slice(),
+            self.lows.as_slice(),
+            self.close.as_slice(),
+            self.volume.as_slice(),
+            self.short_period,
+            previous,
+            self.short_period_model,
+            self.long_period_model,
+        );
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Accumulation distribution of the latest bar.
+    #[wasm_bindgen(getter, js_name = accumulationDistribution)]
+    pub fn accumulation_distribution(&self) -> f64 {
+        self.accumulation_distribution
+    }
+
+    #[wasm_bindgen(getter, js_name = shortPeriod)]
+    pub fn short_period(&self) -> usize {
+        self.short_period
+    }
+
+    #[wasm_bindgen(getter, js_name = longPeriod)]
+    pub fn long_period(&self) -> usize {
+        self.highs.period()
+    }
+
+    /// Whether `longPeriod` bars have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.highs.is_full()
+    }
+
+    /// Forgets every pushed bar; the next value starts from the constructor seed again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        for window in [
+            &mut self.highs,
+            &mut self.lows,
+            &mut self.close,
+            &mut self.volume,
+        ] {
+            window.clear();
+        }
+        self.started = false;
+        self.value = f64::NAN;
+        self.accumulation_distribution = f64::NAN;
+    }
+}
+
+open_bar!(ChaikinOscillatorStream, (high: f64, low: f64, close: f64, volume: f64) -> f64);
+extend!(ChaikinOscillatorStream, (highs, lows, close, volumes) -> Vec<f64>);
+stream_state!(ChaikinOscillatorStream {
+    highs,
+    lows,
+    close,
+    volume,
+    short_period,
+    short_period_model,
+    long_period_model,
+    seed,
+    started,
+    value,
+    accumulation_distribution,
+    committed
+});
+
+/// Percentage price oscillator, matching `momentumIndicators.bulk.percentagePriceOscillator`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct PercentagePriceOscillatorStream {
+    short: MovingConstant,
+    long: MovingConstant,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl PercentagePriceOscillatorStream {
+    /// Throws unless 0 < `shortPeriod` < `longPeriod`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        short_period: usize,
+        long_period: usize,
+        constant_model_type: crate::ConstantModelLike,
+    ) -> IndicatorResult<PercentagePriceOscillatorStream> {
+        check_period_nonzero("shortPeriod", short_period)?;
+        check_less_than("shortPeriod", short_period, "longPeriod", long_period)?;
+        let constant_model_type =
+            crate::constant_model_type("constantModelType", &constant_model_type)?;
+        Ok(Self {
+            short: MovingConstant::new(constant_model_type, short_period),
+            long: MovingConstant::new(constant_model_type, long_period),
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest PPO (NaN until `longPeriod` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        let short = self.short.push(price);
+        let long = self.long.push(price);
+        if self.long.is_full() {
+            self.value = ((short - long) / long) * 100.0;
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter, js_name = shortPeriod)]
+    pub fn short_period(&self) -> usize {
+        self.short.period()
+    }
+
+    #[wasm_bindgen(getter, js_name = longPeriod)]
+    pub fn long_period(&self) -> usize {
+        self.long.period()
+    }
+
+    /// Whether `longPeriod` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.long.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.short.clear();
+        self.long.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(PercentagePriceOscillatorStream, (price: f64) -> f64);
+extend!(PercentagePriceOscillatorStream, (prices) -> Vec<f64>);
+stream_state!(PercentagePriceOscillatorStream {
+    short,
+    long,
+    value,
+    committed
+});
+
+/// Chande momentum oscillator of the latest `period` prices, matching
+/// `momentumIndicators.bulk.chandeMomentumOscillator`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct ChandeMomentumOscillatorStream {
+    prices: Window,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl ChandeMomentumOscillatorStream {
+    /// Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(period: usize) -> IndicatorResult<ChandeMomentumOscillatorStream> {
+        check_period_nonzero("period", period)?;
+        Ok(Self {
+            prices: Window::new(period),
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest CMO (NaN until `period` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        self.prices.push(price);
+        if self.prices.is_full() {
+            self.value = single::chande_momentum_oscillator(self.prices.as_slice());
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period()
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(ChandeMomentumOscillatorStream, (price: f64) -> f64);
+extend!(ChandeMomentumOscillatorStream, (prices) -> Vec<f64>);
+stream_state!(ChandeMomentumOscillatorStream {
+    prices,
+    value,
+    committed
+});
@@ -1,12 +1,46 @@
-use js_sys::Array;
+use crate::averages::MovingAverage;
+use crate::error::{
+    check_even_period, check_fraction, check_less_than, check_min_length, check_not_empty,
+    check_period, check_period_nonzero, check_positive, check_same_length, ErrorCode,
+    IndicatorError, IndicatorResult,
+};
+use crate::missing::{bulk, bulk_seeded};
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsValue;
+
+// The volume of `VolumeWeighted`, which needs one per price; the other types take none
+fn volume_for(ma_type: MovingAverage, volume: Option<&[f64]>) -> IndicatorResult<Option<&[f64]>> {
+    match (ma_type, volume) {
+        (MovingAverage::VolumeWeighted, None) => Err(IndicatorError::new(
+            ErrorCode::InvalidParameter,
+            "volume",
+            "volume is required by MovingAverageType.VolumeWeighted".to_string(),
+        )),
+        (MovingAverage::VolumeWeighted, volume) | (_, volume @ None) => Ok(volume),
+        (_, Some(_)) => Err(IndicatorError::new(
+            ErrorCode::InvalidParameter,
+            "volume",
+            "volume is only taken by MovingAverageType.VolumeWeighted".to_string(),
+        )),
+    }
+}
 
 // -------- SINGLE --------
 
 #[wasm_bindgen(js_name = ma_single_movingAverage)]
-pub fn ma_single_moving_average(prices: Vec<f64>, ma_type: crate::MovingAverageType) -> f64 {
-    rust_ti::moving_average::single::moving_average(&prices, ma_type.into())
+pub fn ma_single_moving_average(
+    prices: &[f64],
+    ma_type: crate::MovingAverageLike,
+    volume: Option<Vec<f64>>,
+) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    let ma_type = crate::moving_average_type("maType", &ma_type)?;
+    match volume_for(ma_type, volume.as_deref())? {
+        Some(volume) => {
+            check_same_length("prices", prices.len(), "volume", volume.len())?;
+            Ok(ma_type.average(prices, volume))
+        }
+        None => Ok(ma_type.average(prices, &[])),
+    }
 }
 
 #[wasm_bindgen(js_name = ma_single_mcginleyDynamic)]
@@ -14,41 +48,266 @@ pub fn ma_single_mcginley_dynamic(
     latest_price: f64,
     previous_mcginley_dynamic: f64,
     period: usize,
-) -> f64 {
-    rust_ti::moving_average::single::mcginley_dynamic(
+) -> IndicatorResult<f64> {
+    check_period_nonzero("period", period)?;
+    Ok(rust_ti::moving_average::single::mcginley_dynamic(
         latest_price,
         previous_mcginley_dynamic,
         period,
-    )
+    ))
+}
+
+#[wasm_bindgen(js_name = ma_single_volumeWeightedMovingAverage)]
+pub fn ma_single_volume_weighted_moving_average(
+    prices: &[f64],
+    volume: &[f64],
+) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    check_same_length("prices", prices.len(), "volume", volume.len())?;
+    Ok(crate::averages::volume_weighted(prices, volume))
+}
+
+#[wasm_bindgen(js_name = ma_single_kaufmanAdaptiveMovingAverage)]
+pub fn ma_single_kaufman_adaptive_moving_average(
+    prices: &[f64],
+    previous_kama: f64,
+    fast_period: usize,
+    slow_period: usize,
+) -> IndicatorResult<f64> {
+    check_min_length("prices", prices.len(), 2)?;
+    check_period_nonzero("fastPeriod", fast_period)?;
+    check_less_than("fastPeriod", fast_period, "slowPeriod", slow_period)?;
+    Ok(crate::adaptive::kaufman(
+        prices,
+        previous_kama,
+        fast_period,
+        slow_period,
+    ))
+}
+
+#[wasm_bindgen(js_name = ma_single_fractalAdaptiveMovingAverage)]
+pub fn ma_single_fractal_adaptive_moving_average(
+    prices: &[f64],
+    previous_frama: f64,
+) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    check_even_period("period", prices.len())?;
+    Ok(crate::adaptive::fractal(prices, previous_frama))
+}
+
+#[wasm_bindgen(js_name = ma_single_variableIndexDynamicAverage)]
+pub fn ma_single_variable_index_dynamic_average(
+    prices: &[f64],
+    previous_vidya: f64,
+    period: usize,
+) -> IndicatorResult<f64> {
+    check_min_length("prices", prices.len(), 2)?;
+    check_period_nonzero("period", period)?;
+    Ok(crate::adaptive::variable_index(
+        prices,
+        previous_vidya,
+        period,
+    ))
+}
+
+#[wasm_bindgen(js_name = ma_single_arnaudLegouxMovingAverage)]
+pub fn ma_single_arnaud_legoux_moving_average(
+    prices: &[f64],
+    offset: f64,
+    sigma: f64,
+) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    check_fraction("offset", offset)?;
+    check_positive("sigma", sigma)?;
+    Ok(crate::adaptive::arnaud_legoux(prices, offset, sigma))
+}
+
+#[wasm_bindgen(js_name = ma_single_tillsonT3)]
+pub fn ma_single_tillson_t3(prices: &[f64], volume_factor: f64) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    check_fraction("volumeFactor", volume_factor)?;
+    Ok(crate::adaptive::tillson_t3(prices, volume_factor))
 }
 
 // -------- BULK --------
 
 #[wasm_bindgen(js_name = ma_bulk_movingAverage)]
 pub fn ma_bulk_moving_average(
-    prices: Vec<f64>,
-    ma_type: crate::MovingAverageType,
-    period: usize,
-) -> Array {
-    let data = rust_ti::moving_average::bulk::moving_average(&prices, ma_type.into(), period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
+    prices: &[f64],
+    ma_type: crate::MovingAverageLike,
+    period: usize,
+    volume: Option<Vec<f64>>,
+) -> IndicatorResult<Vec<f64>> {
+    let ma_type = crate::moving_average_type("maType", &ma_type)?;
+    match volume_for(ma_type, volume.as_deref())? {
+        Some(volume) => bulk(["prices", "volume"], [prices, volume], |[prices, volume]| {
+            check_same_length("prices", prices.len(), "volume", volume.len())?;
+            check_period("period", period, prices.len())?;
+            Ok(prices
+                .windows(period)
+                .zip(volume.windows(period))
+                .map(|(prices, volume)| ma_type.average(prices, volume))
+                .collect())
+        }),
+        None => bulk(["prices"], [prices], |[prices]| {
+            check_period("period", period, prices.len())?;
+            Ok(prices
+                .windows(period)
+                .map(|window| ma_type.average(window, &[]))
+                .collect())
+        }),
     }
-    out
 }
 
 #[wasm_bindgen(js_name = ma_bulk_mcginleyDynamic)]
 pub fn ma_bulk_mcginley_dynamic(
-    prices: Vec<f64>,
+    prices: &[f64],
     previous_mcginley_dynamic: f64,
     period: usize,
-) -> Array {
-    let data =
-        rust_ti::moving_average::bulk::mcginley_dynamic(&prices, previous_mcginley_dynamic, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk_seeded(
+        ["prices"],
+        [prices],
+        previous_mcginley_dynamic,
+        |value| *value,
+        |[prices], previous_mcginley_dynamic| {
+            check_period("period", period, prices.len())?;
+            Ok(rust_ti::moving_average::bulk::mcginley_dynamic(
+                prices,
+                previous_mcginley_dynamic,
+                period,
+            ))
+        },
+    )
+}
+
+#[wasm_bindgen(js_name = ma_bulk_volumeWeightedMovingAverage)]
+pub fn ma_bulk_volume_weighted_moving_average(
+    prices: &[f64],
+    volume: &[f64],
+    period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices", "volume"], [prices, volume], |[prices, volume]| {
+        check_same_length("prices", prices.len(), "volume", volume.len())?;
+        check_period("period", period, prices.len())?;
+        Ok(prices
+            .windows(period)
+            .zip(volume.windows(period))
+            .map(|(prices, volume)| crate::averages::volume_weighted(prices, volume))
+            .collect())
+    })
+}
+
+#[wasm_bindgen(js_name = ma_bulk_kaufmanAdaptiveMovingAverage)]
+pub fn ma_bulk_kaufman_adaptive_moving_average(
+    prices: &[f64],
+    previous_kama: f64,
+    period: usize,
+    fast_period: usize,
+    slow_period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk_seeded(
+        ["prices"],
+        [prices],
+        previous_kama,
+        |value| *value,
+        |[prices], previous_kama| {
+            check_period_nonzero("period", period)?;
+            check_min_length("prices", prices.len(), period + 1)?;
+            check_period_nonzero("fastPeriod", fast_period)?;
+            check_less_than("fastPeriod", fast_period, "slowPeriod", slow_period)?;
+            Ok(crate::adaptive::chain(
+                prices,
+                period + 1,
+                previous_kama,
+                |window, previous| {
+                    crate::adaptive::kaufman(window, previous, fast_period, slow_period)
+                },
+            ))
+        },
+    )
+}
+
+#[wasm_bindgen(js_name = ma_bulk_fractalAdaptiveMovingAverage)]
+pub fn ma_bulk_fractal_adaptive_moving_average(
+    prices: &[f64],
+    previous_frama: f64,
+    period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk_seeded(
+        ["prices"],
+        [prices],
+        previous_frama,
+        |value| *value,
+        |[prices], previous_frama| {
+            check_even_period("period", period)?;
+            check_period("period", period, prices.len())?;
+            Ok(crate::adaptive::chain(
+                prices,
+                period,
+                previous_frama,
+                crate::adaptive::fractal,
+            ))
+        },
+    )
+}
+
+#[wasm_bindgen(js_name = ma_bulk_variableIndexDynamicAverage)]
+pub fn ma_bulk_variable_index_dynamic_average(
+    prices: &[f64],
+    previous_vidya: f64,
+    period: usize,
+    cmo_period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk_seeded(
+        ["prices"],
+        [prices],
+        previous_vidya,
+        |value| *value,
+        |[prices], previous_vidya| {
+            check_period_nonzero("period", period)?;
+            check_period_nonzero("cmoPeriod", cmo_period)?;
+            check_min_length("prices", prices.len(), cmo_period + 1)?;
+            Ok(crate::adaptive::chain(
+                prices,
+                cmo_period + 1,
+                previous_vidya,
+                |window, previous| crate::adaptive::variable_index(window, previous, period),
+            ))
+        },
+    )
+}
+
+#[wasm_bindgen(js_name = ma_bulk_arnaudLegouxMovingAverage)]
+pub fn ma_bulk_arnaud_legoux_moving_average(
+    prices: &[f64],
+    period: usize,
+    offset: f64,
+    sigma: f64,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        check_fraction("offset", offset)?;
+        check_positive("sigma", sigma)?;
+        Ok(prices
+            .windows(period)
+            .map(|window| crate::adaptive::arnaud_legoux(window, offset, sigma))
+            .collect())
+    })
+}
+
+#[wasm_bindgen(js_name = ma_bulk_tillsonT3)]
+pub fn ma_bulk_tillson_t3(
+    prices: &[f64],
+    period: usize,
+    volume_factor: f64,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        check_fraction("volumeFactor", volume_factor)?;
+        Ok(prices
+            .windows(period)
+            .map(|window| crate::adaptive::tillson_t3(window, volume_factor))
+            .collect())
+    })
 }
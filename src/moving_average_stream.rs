@@ -0,0 +1,606 @@
+use crate::error::{
+    check_even_period, check_fraction, check_less_than, check_period_nonzero, check_positive,
+    IndicatorResult,
+};
+use crate::state::stream_state;
+use crate::stream::{extend, open_bar, MovingConstant, Window};
+use wasm_bindgen::prelude::*;
+
+// Streaming moving averages.
+//
+// Each class keeps the latest `period` prices in WASM memory; `push(price)` returns the value the
+// bulk function returns for that bar (NaN until `period` prices have been pushed). The simple,
+// exponential and smoothed averages sum their window in RustTI's order on every push (O(period)),
+// since a running sum would drift from the bulk output by rounding. The median keeps two ordered
+// halves of its window (O(log period) per push) and the mode keeps occurrence counts. The
+// adaptive averages rerun their bulk step over the window on every push, from the value they
+// returned last. All of them match the bulk functions bit for bit.
+
+/// A stream class over one `MovingConstant` of the RustTI constant model `$model`.
+macro_rules! moving_constant_stream {
+    ($(#[$doc:meta])* $stream:ident, $model:ident) => {
+        $(#[$doc])*
+        #[wasm_bindgen]
+        #[derive(Clone, Debug)]
+        pub struct $stream {
+            model: MovingConstant,
+            value: f64,
+            committed: Option<Box<Self>>,
+        }
+
+        #[wasm_bindgen]
+        impl $stream {
+            /// Throws unless `period` is greater than 0.
+            #[wasm_bindgen(constructor)]
+            pub fn new(period: usize) -> IndicatorResult<$stream> {
+                check_period_nonzero("period", period)?;
+                Ok(Self {
+                    model: MovingConstant::new(rust_ti::ConstantModelType::$model, period),
+                    value: f64::NAN,
+                    committed: None,
+                })
+            }
+
+            /// Adds the next price and returns the latest value (NaN until `period` prices).
+            pub fn push(&mut self, price: f64) -> f64 {
+                self.revert_open_bar();
+                self.value = self.model.push(price);
+                self.value
+            }
+
+            /// Latest value returned by `push`.
+            #[wasm_bindgen(getter)]
+            pub fn value(&self) -> f64 {
+                self.value
+            }
+
+            #[wasm_bindgen(getter)]
+            pub fn period(&self) -> usize {
+                self.model.period()
+            }
+
+            /// Whether `period` prices have been pushed.
+            #[wasm_bindgen(getter, js_name = isReady)]
+            pub fn is_ready(&self) -> bool {
+                self.model.is_full()
+            }
+
+            /// Forgets every pushed price.
+            pub fn reset(&mut self) {
+                self.committed = None;
+                self.model.clear();
+                self.value = f64::NAN;
+            }
+        }
+
+        open_bar!($stream, (price: f64) -> f64);
+        extend!($stream, (prices) -> Vec<f64>);
+        stream_state!($stream {
+            model,
+            value,
+            committed
+        });
+    };
+}
+
+moving_constant_stream!(
+    /// Simple moving average of the latest `period` prices, matching
+    /// `standardIndicators.bulk.simpleMovingAverage`.
+    SimpleMovingAverageStream,
+    SimpleMovingAverage
+);
+
+moving_constant_stream!(
+    /// Exponential moving average of the latest `period` prices, matching
+    /// `standardIndicators.bulk.exponentialMovingAverage`.
+    ExponentialMovingAverageStream,
+    ExponentialMovingAverage
+);
+
+moving_constant_stream!(
+    /// Smoothed moving average of the latest `period` prices, matching
+    /// `standardIndicators.bulk.smoothedMovingAverage`.
+    SmoothedMovingAverageStream,
+    SmoothedMovingAverage
+);
+
+moving_constant_stream!(
+    /// Median of the latest `period` prices (NaN ignored), matching the `SimpleMovingMedian`
+    /// constant model of the bulk functions.
+    MedianStream,
+    SimpleMovingMedian
+);
+
+moving_constant_stream!(
+    /// Mode of the latest `period` prices rounded to integers (ties averaged), matching the
+    /// `SimpleMovingMode` constant model of the bulk functions.
+    ModeStream,
+    SimpleMovingMode
+);
+
+/// McGinley dynamic, matching `movingAverage.bulk.mcginleyDynamic`: NaN for the first
+/// `period - 1` prices, then one recursive O(1) update per price.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct McGinleyDynamicStream {
+    period: usize,
+    seed: f64,
+    pushed: usize,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl McGinleyDynamicStream {
+    /// `previousMcginleyDynamic` seeds the first value like the bulk argument (0 to start from
+    /// the price). Throws unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        period: usize,
+        previous_mcginley_dynamic: Option<f64>,
+    ) -> IndicatorResult<McGinleyDynamicStream> {
+        check_period_nonzero("period", period)?;
+        Ok(Self {
+            period,
+            seed: previous_mcginley_dynamic.unwrap_or(0.0),
+            pushed: 0,
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest McGinley dynamic (NaN until `period` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        self.pushed += 1;
+        if self.pushed < self.period {
+            return self.value;
+        }
+        let previous = if self.pushed == self.period {
+            self.seed
+        } else {
+            self.value
+        };
+        self.value =
+            rust_ti::moving_average::single::mcginley_dynamic(price, previous, self.period);
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.pushed >= self.period
+    }
+
+    /// Forgets every pushed price; the next value starts from the constructor seed again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.pushed = 0;
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(McGinleyDynamicStream, (price: f64) -> f64);
+extend!(McGinleyDynamicStream, (prices) -> Vec<f64>);
+stream_state!(McGinleyDynamicStream {
+    period,
+    seed,
+    pushed,
+    value,
+    committed
+});
+
+/// Kaufman adaptive moving average, matching `movingAverage.bulk.kaufmanAdaptiveMovingAverage`:
+/// NaN for the first `period` prices, then one step over the latest `period + 1` prices.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct KaufmanAdaptiveMovingAverageStream {
+    prices: Window,
+    fast_period: usize,
+    slow_period: usize,
+    seed: f64,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl KaufmanAdaptiveMovingAverageStream {
+    /// `previousKama` seeds the first value like the bulk argument (0 to start from the price).
+    /// Throws unless `period` and `fastPeriod` are greater than 0 and `fastPeriod` is less than
+    /// `slowPeriod`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        period: usize,
+        fast_period: usize,
+        slow_period: usize,
+        previous_kama: Option<f64>,
+    ) -> IndicatorResult<KaufmanAdaptiveMovingAverageStream> {
+        check_period_nonzero("period", period)?;
+        check_period_nonzero("fastPeriod", fast_period)?;
+        check_less_than("fastPeriod", fast_period, "slowPeriod", slow_period)?;
+        Ok(Self {
+            prices: Window::new(period + 1),
+            fast_period,
+            slow_period,
+            seed: previous_kama.unwrap_or(0.0),
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest KAMA (NaN until `period + 1` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        let started = self.prices.is_full();
+        self.prices.push(price);
+        if self.prices.is_full() {
+            let previous = if started { self.value } else { self.seed };
+            self.value = crate::adaptive::kaufman(
+                self.prices.as_slice(),
+                previous,
+                self.fast_period,
+                self.slow_period,
+            );
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Efficiency ratio period (the window holds one more price).
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period() - 1
+    }
+
+    /// Whether `period + 1` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price; the next value starts from the constructor seed again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(KaufmanAdaptiveMovingAverageStream, (price: f64) -> f64);
+extend!(KaufmanAdaptiveMovingAverageStream, (prices) -> Vec<f64>);
+stream_state!(KaufmanAdaptiveMovingAverageStream {
+    prices,
+    fast_period,
+    slow_period,
+    seed,
+    value,
+    committed
+});
+
+/// Fractal adaptive moving average, matching `movingAverage.bulk.fractalAdaptiveMovingAverage`:
+/// NaN for the first `period - 1` prices, then one step over the latest `period` prices.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct FractalAdaptiveMovingAverageStream {
+    prices: Window,
+    seed: f64,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl FractalAdaptiveMovingAverageStream {
+    /// `previousFrama` seeds the first value like the bulk argument (0 to start from the price).
+    /// Throws unless `period` is an even number greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        period: usize,
+        previous_frama: Option<f64>,
+    ) -> IndicatorResult<FractalAdaptiveMovingAverageStream> {
+        check_even_period("period", period)?;
+        Ok(Self {
+            prices: Window::new(period),
+            seed: previous_frama.unwrap_or(0.0),
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest FRAMA (NaN until `period` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        let started = self.prices.is_full();
+        self.prices.push(price);
+        if self.prices.is_full() {
+            let previous = if started { self.value } else { self.seed };
+            self.value = crate::adaptive::fractal(self.prices.as_slice(), previous);
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period()
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price; the next value starts from the constructor seed again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(FractalAdaptiveMovingAverageStream, (price: f64) -> f64);
+extend!(FractalAdaptiveMovingAverageStream, (prices) -> Vec<f64>);
+stream_state!(FractalAdaptiveMovingAverageStream {
+    prices,
+    seed,
+    value,
+    committed
+});
+
+/// Variable index dynamic average, matching `movingAverage.bulk.variableIndexDynamicAverage`:
+/// NaN for the first `cmoPeriod` prices, then one step over the latest `cmoPeriod + 1` prices.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct VariableIndexDynamicAverageStream {
+    prices: Window,
+    period: usize,
+    seed: f64,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl VariableIndexDynamicAverageStream {
+    /// `previousVidya` seeds the first value like the bulk argument (0 to start from the price).
+    /// Throws unless `period` and `cmoPeriod` are greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        period: usize,
+        cmo_period: usize,
+        previous_vidya: Option<f64>,
+    ) -> IndicatorResult<VariableIndexDynamicAverageStream> {
+        check_period_nonzero("period", period)?;
+        check_period_nonzero("cmoPeriod", cmo_period)?;
+        Ok(Self {
+            prices: Window::new(cmo_period + 1),
+            period,
+            seed: previous_vidya.unwrap_or(0.0),
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest VIDYA (NaN until `cmoPeriod + 1` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        let started = self.prices.is_full();
+        self.prices.push(price);
+        if self.prices.is_full() {
+            let previous = if started { self.value } else { self.seed };
+            self.value =
+                crate::adaptive::variable_index(self.prices.as_slice(), previous, self.period);
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Smoothing period.
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Chande momentum oscillator period (the window holds one more price).
+    #[wasm_bindgen(getter, js_name = cmoPeriod)]
+    pub fn cmo_period(&self) -> usize {
+        self.prices.period() - 1
+    }
+
+    /// Whether `cmoPeriod + 1` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price; the next value starts from the constructor seed again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(VariableIndexDynamicAverageStream, (price: f64) -> f64);
+extend!(VariableIndexDynamicAverageStream, (prices) -> Vec<f64>);
+stream_state!(VariableIndexDynamicAverageStream {
+    prices,
+    period,
+    seed,
+    value,
+    committed
+});
+
+/// Arnaud Legoux moving average of the latest `period` prices, matching
+/// `movingAverage.bulk.arnaudLegouxMovingAverage`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct ArnaudLegouxMovingAverageStream {
+    prices: Window,
+    offset: f64,
+    sigma: f64,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl ArnaudLegouxMovingAverageStream {
+    /// Throws unless `period` is greater than 0, `offset` is between 0 and 1 and `sigma` is
+    /// greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        period: usize,
+        offset: f64,
+        sigma: f64,
+    ) -> IndicatorResult<ArnaudLegouxMovingAverageStream> {
+        check_period_nonzero("period", period)?;
+        check_fraction("offset", offset)?;
+        check_positive("sigma", sigma)?;
+        Ok(Self {
+            prices: Window::new(period),
+            offset,
+            sigma,
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest ALMA (NaN until `period` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        self.prices.push(price);
+        if self.prices.is_full() {
+            self.value =
+                crate::adaptive::arnaud_legoux(self.prices.as_slice(), self.offset, self.sigma);
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period()
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(ArnaudLegouxMovingAverageStream, (price: f64) -> f64);
+extend!(ArnaudLegouxMovingAverageStream, (prices) -> Vec<f64>);
+stream_state!(ArnaudLegouxMovingAverageStream {
+    prices,
+    offset,
+    sigma,
+    value,
+    committed
+});
+
+/// Tillson T3 of the latest `period` prices, matching `movingAverage.bulk.tillsonT3`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct TillsonT3Stream {
+    prices: Window,
+    volume_factor: f64,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl TillsonT3Stream {
+    /// Throws unless `period` is greater than 0 and `volumeFactor` is between 0 and 1.
+    #[wasm_bindgen(constructor)]
+    pub fn new(period: usize, volume_factor: f64) -> IndicatorResult<TillsonT3Stream> {
+        check_period_nonzero("period", period)?;
+        check_fraction("volumeFactor", volume_factor)?;
+        Ok(Self {
+            prices: Window::new(period),
+            volume_factor,
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next price and returns the latest T3 (NaN until `period` prices).
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.revert_open_bar();
+        self.prices.push(price);
+        if self.prices.is_full() {
+            self.value = crate::adaptive::tillson_t3(self.prices.as_slice(), self.volume_factor);
+        }
+        self.value
+    }
+
+    /// Latest value returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.prices.period()
+    }
+
+    /// Whether `period` prices have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    /// Forgets every pushed price.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.prices.clear();
+        self.value = f64::NAN;
+    }
+}
+
+open_bar!(TillsonT3Stream, (price: f64) -> f64);
+extend!(TillsonT3Stream, (prices) -> Vec<f64>);
+stream_state!(TillsonT3Stream {
+    prices,
+    volume_factor,
+    value,
+    committed
+});
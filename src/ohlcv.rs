@@ -0,0 +1,891 @@
+use crate::columns::columns;
+use crate::error::{check_not_empty, check_same_length, IndicatorResult};
+use js_sys::{Array, Object};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsValue;
+
+// Resident OHLCV candles.
+//
+// Every module function copies its input arrays from JS into WASM memory on each call. An
+// `OhlcvSeries` copies the candles once and its methods pass the stored columns straight to the
+// bulk bindings, so computing many indicators on the same candles costs one copy in total.
+// Methods share the names, arguments (minus the price arrays) and outputs of the bulk functions;
+// `prices` is the close column.
+
+/// Open/high/low/close/volume columns (and optional timestamps) kept in WASM memory.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct OhlcvSeries {
+    open: Vec<f64>,
+    high: Vec<f64>,
+    low: Vec<f64>,
+    close: Vec<f64>,
+    volume: Vec<f64>,
+    timestamps: Option<Vec<f64>>,
+}
+
+#[wasm_bindgen]
+impl OhlcvSeries {
+    /// Throws unless every column (and `timestamps`, when given) has the length of `close`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        open: Vec<f64>,
+        high: Vec<f64>,
+        low: Vec<f64>,
+        close: Vec<f64>,
+        volume: Vec<f64>,
+        timestamps: Option<Vec<f64>>,
+    ) -> IndicatorResult<OhlcvSeries> {
+        check_not_empty("close", &close)?;
+        check_same_length("close", close.len(), "open", open.len())?;
+        check_same_length("close", close.len(), "high", high.len())?;
+        check_same_length("close", close.len(), "low", low.len())?;
+        check_same_length("close", close.len(), "volume", volume.len())?;
+        if let Some(timestamps) = &timestamps {
+            check_same_length("close", close.len(), "timestamps", timestamps.len())?;
+        }
+        Ok(Self {
+            open,
+            high,
+            low,
+            close,
+            volume,
+            timestamps,
+        })
+    }
+
+    /// Number of bars.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.close.len()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn open(&self) -> Vec<f64> {
+        self.open.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn high(&self) -> Vec<f64> {
+        self.high.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn low(&self) -> Vec<f64> {
+        self.low.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn close(&self) -> Vec<f64> {
+        self.close.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn volume(&self) -> Vec<f64> {
+        self.volume.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn timestamps(&self) -> Option<Vec<f64>> {
+        self.timestamps.clone()
+    }
+
+    /// `computeBatch` over the resident columns, see `crate::batch`.
+    #[wasm_bindgen(js_name = computeBatch)]
+    pub fn compute_batch(&self, specs: &JsValue) -> IndicatorResult<Object> {
+        crate::batch::run(
+            &crate::batch::BatchSeries {
+                open: Some(&self.open),
+                high: Some(&self.high),
+                low: Some(&self.low),
+                close: Some(&self.close),
+                volume: Some(&self.volume),
+            },
+            specs,
+        )
+    }
+
+    /// `sweep` over the resident columns, see `crate::sweep`.
+    #[wasm_bindgen(js_name = sweep)]
+    pub fn sweep(&self, spec: &JsValue) -> IndicatorResult<Object> {
+        crate::sweep::run(
+            &crate::batch::BatchSeries {
+                open: Some(&self.open),
+                high: Some(&self.high),
+                low: Some(&self.low),
+                close: Some(&self.close),
+                volume: Some(&self.volume),
+            },
+            spec,
+        )
+    }
+
+    // -------- candleIndicators --------
+
+    /// `candleIndicators.bulk.movingConstantEnvelopes` on the close column.
+    #[wasm_bindgen(js_name = movingConstantEnvelopes)]
+    pub fn moving_constant_envelopes(
+        &self,
+        constant_model_type: crate::FamilyModelLike,
+        difference: f64,
+        period: usize,
+    ) -> IndicatorResult<Array> {
+        crate::candle_indicators::candle_bulk_moving_constant_envelopes(
+            &self.close,
+            constant_model_type,
+            difference,
+            period,
+        )
+    }
+
+    /// `candleIndicators.bulk.movingConstantEnvelopesColumns` on the close column.
+    #[wasm_bindgen(js_name = movingConstantEnvelopesColumns)]
+    pub fn moving_constant_envelopes_columns(
+        &self,
+        constant_model_type: crate::FamilyModelLike,
+        difference: f64,
+        period: usize,
+    ) -> IndicatorResult<Object> {
+        crate::candle_indicators::candle_bulk_moving_constant_envelopes_columns(
+            &self.close,
+            constant_model_type,
+            difference,
+            period,
+        )
+    }
+
+    /// `candleIndicators.bulk.mcginleyDynamicEnvelopes` on the close column.
+    #[wasm_bindgen(js_name = mcginleyDynamicEnvelopes)]
+    pub fn mcginley_dynamic_envelopes(
+        &self,
+        difference: f64,
+        previous_mcginley_dynamic: f64,
+        period: usize,
+    ) -> IndicatorResult<Array> {
+        crate::candle_indicators::candle_bulk_mcginley_dynamic_envelopes(
+            &self.close,
+            difference,
+            previous_mcginley_dynamic,
+            period,
+        )
+    }
+
+    /// `candleIndicators.bulk.mcginleyDynamicEnvelopesColumns` on the close column.
+    #[wasm_bindgen(js_name = mcginleyDynamicEnvelopesColumns)]
+    pub fn mcginley_dynamic_envelopes_columns(
+        &self,
+        difference: f64,
+        previous_mcginley_dynamic: f64,
+        period: usize,
+    ) -> IndicatorResult<Object> {
+        crate::candle_indicators::candle_bulk_mcginley_dynamic_envelopes_columns(
+            &self.close,
+            difference,
+            previous_mcginley_dynamic,
+            period,
+        )
+    }
+
+    /// `candleIndicators.bulk.movingConstantBands` on the close column.
+    #[wasm_bindgen(js_name = movingConstantBands)]
+    pub fn moving_constant_bands(
+        &self,
+        constant_model_type: crate::FamilyModelLike,
+        deviation_model: crate::DeviationModel,
+        deviation_multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<Array> {
+        crate::candle_indicators::candle_bulk_moving_constant_bands(
+            &self.close,
+            constant_model_type,
+            deviation_model,
+            deviation_multiplier,
+            period,
+        )
+    }
+
+    /// `candleIndicators.bulk.movingConstantBandsColumns` on the close column.
+    #[wasm_bindgen(js_name = movingConstantBandsColumns)]
+    pub fn moving_constant_bands_columns(
+        &self,
+        constant_model_type: crate::FamilyModelLike,
+        deviation_model: crate::DeviationModel,
+        deviation_multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<Object> {
+        crate::candle_indicators::candle_bulk_moving_constant_bands_columns(
+            &self.close,
+            constant_model_type,
+            deviation_model,
+            deviation_multiplier,
+            period,
+        )
+    }
+
+    /// `candleIndicators.bulk.mcginleyDynamicBands` on the close column.
+    #[wasm_bindgen(js_name = mcginleyDynamicBands)]
+    pub fn mcginley_dynamic_bands(
+        &self,
+        deviation_model: crate::DeviationModel,
+        deviation_multiplier: f64,
+        previous_mcginley_dynamic: f64,
+        period: usize,
+    ) -> IndicatorResult<Array> {
+        crate::candle_indicators::candle_bulk_mcginley_dynamic_bands(
+            &self.close,
+            deviation_model,
+            deviation_multiplier,
+            previous_mcginley_dynamic,
+            period,
+        )
+    }
+
+    /// `candleIndicators.bulk.mcginleyDynamicBandsColumns` on the close column.
+    #[wasm_bindgen(js_name = mcginleyDynamicBandsColumns)]
+    pub fn mcginley_dynamic_bands_columns(
+        &self,
+        deviation_model: crate::DeviationModel,
+        deviation_multiplier: f64,
+        previous_mcginley_dynamic: f64,
+        period: usize,
+    ) -> IndicatorResult<Object> {
+        crate::candle_indicators::candle_bulk_mcginley_dynamic_bands_columns(
+            &self.close,
+            deviation_model,
+            deviation_multiplier,
+            previous_mcginley_dynamic,
+            period,
+        )
+    }
+
+    /// `candleIndicators.bulk.ichimokuCloud` on the high, low and close columns.
+    #[wasm_bindgen(js_name = ichimokuCloud)]
+    pub fn ichimoku_cloud(
+        &self,
+        conversion_period: usize,
+        base_period: usize,
+        span_b_period: usize,
+    ) -> IndicatorResult<Array> {
+        crate::candle_indicators::candle_bulk_ichimoku_cloud(
+            &self.high,
+            &self.low,
+            &self.close,
+            conversion_period,
+            base_period,
+            span_b_period,
+        )
+    }
+
+    /// `candleIndicators.bulk.ichimokuCloudColumns` on the high, low and close columns.
+    #[wasm_bindgen(js_name = ichimokuCloudColumns)]
+    pub fn ichimoku_cloud_columns(
+        &self,
+        conversion_period: usize,
+        base_period: usize,
+        span_b_period: usize,
+    ) -> IndicatorResult<Object> {
+        crate::candle_indicators::candle_bulk_ichimoku_cloud_columns(
+            &self.high,
+            &self.low,
+            &self.close,
+            conversion_period,
+            base_period,
+            span_b_period,
+        )
+    }
+
+    /// `candleIndicators.bulk.donchianChannels` on the high and low columns.
+    #[wasm_bindgen(js_name = donchianChannels)]
+    pub fn donchian_channels(&self, period: usize) -> IndicatorResult<Array> {
+        crate::candle_indicators::candle_bulk_donchian_channels(&self.high, &self.low, period)
+    }
+
+    /// `candleIndicators.bulk.donchianChannelsColumns` on the high and low columns.
+    #[wasm_bindgen(js_name = donchianChannelsColumns)]
+    pub fn donchian_channels_columns(&self, period: usize) -> IndicatorResult<Object> {
+        crate::candle_indicators::candle_bulk_donchian_channels_columns(
+            &self.high, &self.low, period,
+        )
+    }
+
+    /// `candleIndicators.bulk.keltnerChannel` on the high, low and close columns.
+    #[wasm_bindgen(js_name = keltnerChannel)]
+    pub fn keltner_channel(
+        &self,
+        constant_model_type: crate::ConstantModelLike,
+        atr_constant_model_type: crate::ConstantModelLike,
+        multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<Array> {
+        crate::candle_indicators::candle_bulk_keltner_channel(
+            &self.high,
+            &self.low,
+            &self.close,
+            constant_model_type,
+            atr_constant_model_type,
+            multiplier,
+            period,
+        )
+    }
+
+    /// `candleIndicators.bulk.keltnerChannelColumns` on the high, low and close columns.
+    #[wasm_bindgen(js_name = keltnerChannelColumns)]
+    pub fn keltner_channel_columns(
+        &self,
+        constant_model_type: crate::ConstantModelLike,
+        atr_constant_model_type: crate::ConstantModelLike,
+        multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<Object> {
+        crate::candle_indicators::candle_bulk_keltner_channel_columns(
+            &self.high,
+            &self.low,
+            &self.close,
+            constant_model_type,
+            atr_constant_model_type,
+            multiplier,
+            period,
+        )
+    }
+
+    /// `candleIndicators.bulk.supertrend` on the high, low and close columns.
+    #[wasm_bindgen(js_name = supertrend)]
+    pub fn supertrend(
+        &self,
+        constant_model_type: crate::ConstantModelLike,
+        multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::candle_indicators::candle_bulk_supertrend(
+            &self.high,
+            &self.low,
+            &self.close,
+            constant_model_type,
+            multiplier,
+            period,
+        )
+    }
+
+    // -------- momentumIndicators --------
+
+    /// `momentumIndicators.bulk.relativeStrengthIndex` on the close column.
+    #[wasm_bindgen(js_name = relativeStrengthIndex)]
+    pub fn relative_strength_index(
+        &self,
+        constant_model_type: crate::FamilyModelLike,
+        period: usize,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::momentum_indicators::momentum_bulk_relative_strength_index(
+            &self.close,
+            constant_model_type,
+            period,
+        )
+    }
+
+    /// `momentumIndicators.bulk.stochasticOscillator` on the close column.
+    #[wasm_bindgen(js_name = stochasticOscillator)]
+    pub fn stochastic_oscillator(&self, period: usize) -> IndicatorResult<Vec<f64>> {
+        crate::momentum_indicators::momentum_bulk_stochastic_oscillator(&self.close, period)
+    }
+
+    /// `momentumIndicators.bulk.williamsPercentR` on the high, low and close columns.
+    #[wasm_bindgen(js_name = williamsPercentR)]
+    pub fn williams_percent_r(&self, period: usize) -> IndicatorResult<Vec<f64>> {
+        crate::momentum_indicators::momentum_bulk_williams_percent_r(
+            &self.high,
+            &self.low,
+            &self.close,
+            period,
+        )
+    }
+
+    /// `momentumIndicators.bulk.moneyFlowIndex` on the close and volume columns.
+    #[wasm_bindgen(js_name = moneyFlowIndex)]
+    pub fn money_flow_index(&self, period: usize) -> IndicatorResult<Vec<f64>> {
+        crate::momentum_indicators::momentum_bulk_money_flow_index(
+            &self.close,
+            &self.volume,
+            period,
+        )
+    }
+
+    /// `momentumIndicators.bulk.rateOfChange` on the close column.
+    #[wasm_bindgen(js_name = rateOfChange)]
+    pub fn rate_of_change(&self) -> IndicatorResult<Vec<f64>> {
+        crate::momentum_indicators::momentum_bulk_rate_of_change(&self.close)
+    }
+
+    /// `momentumIndicators.bulk.onBalanceVolume` on the close and volume columns.
+    #[wasm_bindgen(js_name = onBalanceVolume)]
+    pub fn on_balance_volume(&self, previous_on_balance_volume: f64) -> IndicatorResult<Vec<f64>> {
+        crate::momentum_indicators::momentum_bulk_on_balance_volume(
+            &self.close,
+            &self.volume,
+            previous_on_balance_volume,
+        )
+    }
+
+    /// `momentumIndicators.bulk.commodityChannelIndex` on the close column.
+    #[wasm_bindgen(js_name = commodityChannelIndex)]
+    pub fn commodity_channel_index(
+        &self,
+        constant_model_type: crate::ConstantModelLike,
+        deviation_model: crate::DeviationModel,
+        constant_multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::momentum_indicators::momentum_bulk_commodity_channel_index(
+            &self.close,
+            constant_model_type,
+            deviation_model,
+            constant_multiplier,
+            period,
+        )
+    }
+
+    /// `momentumIndicators.bulk.mcginleyDynamicCommodityChannelIndex` on the close column.
+    #[wasm_bindgen(js_name = mcginleyDynamicCommodityChannelIndex)]
+    pub fn mcginley_dynamic_commodity_channel_index(
+        &self,
+        previous_mcginley_dynamic: f64,
+        deviation_model: crate::DeviationModel,
+        constant_multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<Array> {
+        crate::momentum_indicators::momentum_bulk_mcginley_dynamic_commodity_channel_index(
+            &self.close,
+            previous_mcginley_dynamic,
+            deviation_model,
+            constant_multiplier,
+            period,
+        )
+    }
+
+    /// `momentumIndicators.bulk.mcginleyDynamicCommodityChannelIndexColumns` on the close column.
+    #[wasm_bindgen(js_name = mcginleyDynamicCommodityChannelIndexColumns)]
+    pub fn mcginley_dynamic_commodity_channel_index_columns(
+        &self,
+        previous_mcginley_dynamic: f64,
+        deviation_model: crate::DeviationModel,
+        constant_multiplier: f64,
+        period: usize,
+    ) -> IndicatorResult<Object> {
+        crate::momentum_indicators::momentum_bulk_mcginley_dynamic_commodity_channel_index_columns(
+            &self.close,
+            previous_mcginley_dynamic,
+            deviation_model,
+            constant_multiplier,
+            period,
+        )
+    }
+
+    /// `momentumIndicators.bulk.macdLine` on the close column.
+    #[wasm_bindgen(js_name = macdLine)]
+    pub fn macd_line(
+        &self,
+        short_period: usize,
+        short_period_model: crate::FamilyModelLike,
+        long_period: usize,
+        long_period_model: crate::FamilyModelLike,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::momentum_indicators::momentum_bulk_macd_line(
+            &self.close,
+            short_period,
+            short_period_model,
+            long_period,
+            long_period_model,
+        )
+    }
+
+    /// `momentumIndicators.bulk.mcginleyDynamicMacdLine` on the close column.
+    #[wasm_bindgen(js_name = mcginleyDynamicMacdLine)]
+    pub fn mcginley_dynamic_macd_line(
+        &self,
+        short_period: usize,
+        previous_short_mcginley: f64,
+        long_period: usize,
+        previous_long_mcginley: f64,
+    ) -> IndicatorResult<Array> {
+        crate::momentum_indicators::momentum_bulk_mcginley_dynamic_macd_line(
+            &self.close,
+            short_period,
+            previous_short_mcginley,
+            long_period,
+            previous_long_mcginley,
+        )
+    }
+
+    /// `momentumIndicators.bulk.mcginleyDynamicMacdLineColumns` on the close column.
+    #[wasm_bindgen(js_name = mcginleyDynamicMacdLineColumns)]
+    pub fn mcginley_dynamic_macd_line_columns(
+        &self,
+        short_period: usize,
+        previous_short_mcginley: f64,
+        long_period: usize,
+        previous_long_mcginley: f64,
+    ) -> IndicatorResult<Object> {
+        crate::momentum_indicators::momentum_bulk_mcginley_dynamic_macd_line_columns(
+            &self.close,
+            short_period,
+            previous_short_mcginley,
+            long_period,
+            previous_long_mcginley,
+        )
+    }
+
+    /// `momentumIndicators.bulk.chaikinOscillator` on the high, low, close and volume columns.
+    #[wasm_bindgen(js_name = chaikinOscillator)]
+    pub fn chaikin_oscillator(
+        &self,
+        short_period: usize,
+        long_period: usize,
+        previous_accumulation_distribution: f64,
+        short_period_model: crate::ConstantModelLike,
+        long_period_model: crate::ConstantModelLike,
+    ) -> IndicatorResult<Array> {
+        crate::momentum_indicators::momentum_bulk_chaikin_oscillator(
+            &self.high,
+            &self.low,
+            &self.close,
+            &self.volume,
+            short_period,
+            long_period,
+            previous_accumulation_distribution,
+            short_period_model,
+            long_period_model,
+        )
+    }
+
+    /// `momentumIndicators.bulk.chaikinOscillatorColumns` on the high, low, close and volume columns.
+    #[wasm_bindgen(js_name = chaikinOscillatorColumns)]
+    pub fn chaikin_oscillator_columns(
+        &self,
+        short_period: usize,
+        long_period: usize,
+        previous_accumulation_distribution: f64,
+        short_period_model: crate::ConstantModelLike,
+        long_period_model: crate::ConstantModelLike,
+    ) -> IndicatorResult<Object> {
+        crate::momentum_indicators::momentum_bulk_chaikin_oscillator_columns(
+            &self.high,
+            &self.low,
+            &self.close,
+            &self.volume,
+            short_period,
+            long_period,
+            previous_accumulation_distribution,
+            short_period_model,
+            long_period_model,
+        )
+    }
+
+    /// `momentumIndicators.bulk.percentagePriceOscillator` on the close column.
+    #[wasm_bindgen(js_name = percentagePriceOscillator)]
+    pub fn percentage_price_oscillator(
+        &self,
+        short_period: usize,
+        long_period: usize,
+        constant_model_type: crate::ConstantModelLike,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::momentum_indicators::momentum_bulk_percentage_price_oscillator(
+            &self.close,
+            short_period,
+            long_period,
+            constant_model_type,
+        )
+    }
+
+    /// `momentumIndicators.bulk.chandeMomentumOscillator` on the close column.
+    #[wasm_bindgen(js_name = chandeMomentumOscillator)]
+    pub fn chande_momentum_oscillator(&self, period: usize) -> IndicatorResult<Vec<f64>> {
+        crate::momentum_indicators::momentum_bulk_chande_momentum_oscillator(&self.close, period)
+    }
+
+    // -------- otherIndicators --------
+
+    /// `otherIndicators.bulk.returnOnInvestment` on the close column.
+    #[wasm_bindgen(js_name = returnOnInvestment)]
+    pub fn return_on_investment(&self, investment: f64) -> IndicatorResult<Array> {
+        crate::other_indicators::other_bulk_return_on_investment(&self.close, investment)
+    }
+
+    /// `otherIndicators.bulk.returnOnInvestmentColumns` on the close column.
+    #[wasm_bindgen(js_name = returnOnInvestmentColumns)]
+    pub fn return_on_investment_columns(&self, investment: f64) -> IndicatorResult<Object> {
+        crate::other_indicators::other_bulk_return_on_investment_columns(&self.close, investment)
+    }
+
+    /// `otherIndicators.bulk.trueRange` on the close, high and low columns.
+    #[wasm_bindgen(js_name = trueRange)]
+    pub fn true_range(&self) -> IndicatorResult<Vec<f64>> {
+        crate::other_indicators::other_bulk_true_range(&self.close, &self.high, &self.low)
+    }
+
+    /// `otherIndicators.bulk.averageTrueRange` on the close, high and low columns.
+    #[wasm_bindgen(js_name = averageTrueRange)]
+    pub fn average_true_range(
+        &self,
+        constant_model_type: crate::ConstantModelLike,
+        period: usize,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::other_indicators::other_bulk_average_true_range(
+            &self.close,
+            &self.high,
+            &self.low,
+            constant_model_type,
+            period,
+        )
+    }
+
+    /// `otherIndicators.bulk.internalBarStrength` on the high, low and close columns.
+    #[wasm_bindgen(js_name = internalBarStrength)]
+    pub fn internal_bar_strength(&self) -> IndicatorResult<Vec<f64>> {
+        crate::other_indicators::other_bulk_internal_bar_strength(
+            &self.high,
+            &self.low,
+            &self.close,
+        )
+    }
+
+    /// `otherIndicators.bulk.positivityIndicator` comparing each open with the previous close.
+    ///
+    /// The first bar has no previous close, so rows start at the second bar (padded from the
+    /// first bar with `setAlignedOutput(true)`).
+    #[wasm_bindgen(js_name = positivityIndicator)]
+    pub fn positivity_indicator(
+        &self,
+        signal_period: usize,
+        constant_model_type: crate::ConstantModelLike,
+    ) -> IndicatorResult<Array> {
+        let data = crate::other_indicators::positivity_indicator_bar_rows(
+            &self.open,
+            &self.close,
+            signal_period,
+            constant_model_type,
+        )?;
+        let out = Array::new();
+        for (pi, sig) in data {
+            let inner = Array::new();
+            inner.push(&JsValue::from_f64(pi));
+            inner.push(&JsValue::from_f64(sig));
+            out.push(&inner);
+        }
+        Ok(out)
+    }
+
+    /// `otherIndicators.bulk.positivityIndicatorColumns` comparing each open with the previous close.
+    #[wasm_bindgen(js_name = positivityIndicatorColumns)]
+    pub fn positivity_indicator_columns(
+        &self,
+        signal_period: usize,
+        constant_model_type: crate::ConstantModelLike,
+    ) -> IndicatorResult<Object> {
+        let data = crate::other_indicators::positivity_indicator_bar_rows(
+            &self.open,
+            &self.close,
+            signal_period,
+            constant_model_type,
+        )?;
+        Ok(columns(
+            ["positivityIndicator", "signal"],
+            data.into_iter().map(|(pi, sig)| [pi, sig]),
+        ))
+    }
+
+    // -------- strengthIndicators --------
+
+    /// `strengthIndicators.bulk.accumulationDistribution` on the high, low, close and volume columns.
+    #[wasm_bindgen(js_name = accumulationDistribution)]
+    pub fn accumulation_distribution(
+        &self,
+        previous_accumulation_distribution: f64,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::strength_indicators::strength_bulk_accumulation_distribution(
+            &self.high,
+            &self.low,
+            &self.close,
+            &self.volume,
+            previous_accumulation_distribution,
+        )
+    }
+
+    /// `strengthIndicators.bulk.positiveVolumeIndex` on the close and volume columns.
+    #[wasm_bindgen(js_name = positiveVolumeIndex)]
+    pub fn positive_volume_index(
+        &self,
+        previous_positive_volume_index: f64,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::strength_indicators::strength_bulk_positive_volume_index(
+            &self.close,
+            &self.volume,
+            previous_positive_volume_index,
+        )
+    }
+
+    /// `strengthIndicators.bulk.negativeVolumeIndex` on the close and volume columns.
+    #[wasm_bindgen(js_name = negativeVolumeIndex)]
+    pub fn negative_volume_index(
+        &self,
+        previous_negative_volume_index: f64,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::strength_indicators::strength_bulk_negative_volume_index(
+            &self.close,
+            &self.volume,
+            previous_negative_volume_index,
+        )
+    }
+
+    /// `strengthIndicators.bulk.relativeVigorIndex` on the open, high, low and close columns.
+    #[wasm_bindgen(js_name = relativeVigorIndex)]
+    pub fn relative_vigor_index(
+        &self,
+        constant_model_type: crate::ConstantModelLike,
+        period: usize,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::strength_indicators::strength_bulk_relative_vigor_index(
+            &self.open,
+            &self.high,
+            &self.low,
+            &self.close,
+            constant_model_type,
+            period,
+        )
+    }
+
+    // -------- trendIndicators --------
+
+    /// `trendIndicators.bulk.aroonUp` on the high column.
+    #[wasm_bindgen(js_name = aroonUp)]
+    pub fn aroon_up(&self, period: usize) -> IndicatorResult<Vec<f64>> {
+        crate::trend_indicators::trend_bulk_aroon_up(&self.high, period)
+    }
+
+    /// `trendIndicators.bulk.aroonDown` on the low column.
+    #[wasm_bindgen(js_name = aroonDown)]
+    pub fn aroon_down(&self, period: usize) -> IndicatorResult<Vec<f64>> {
+        crate::trend_indicators::trend_bulk_aroon_down(&self.low, period)
+    }
+
+    /// `trendIndicators.bulk.aroonIndicator` on the high and low columns.
+    #[wasm_bindgen(js_name = aroonIndicator)]
+    pub fn aroon_indicator(&self, period: usize) -> IndicatorResult<Array> {
+        crate::trend_indicators::trend_bulk_aroon_indicator(&self.high, &self.low, period)
+    }
+
+    /// `trendIndicators.bulk.aroonIndicatorColumns` on the high and low columns.
+    #[wasm_bindgen(js_name = aroonIndicatorColumns)]
+    pub fn aroon_indicator_columns(&self, period: usize) -> IndicatorResult<Object> {
+        crate::trend_indicators::trend_bulk_aroon_indicator_columns(&self.high, &self.low, period)
+    }
+
+    /// `trendIndicators.bulk.parabolicTimePriceSystem` on the high and low columns.
+    #[wasm_bindgen(js_name = parabolicTimePriceSystem)]
+    pub fn parabolic_time_price_system(
+        &self,
+        acceleration_factor_start: f64,
+        acceleration_factor_max: f64,
+        acceleration_factor_step: f64,
+        start_position: crate::Position,
+        previous_sar: f64,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::trend_indicators::trend_bulk_parabolic_time_price_system(
+            &self.high,
+            &self.low,
+            acceleration_factor_start,
+            acceleration_factor_max,
+            acceleration_factor_step,
+            start_position,
+            previous_sar,
+        )
+    }
+
+    /// `trendIndicators.bulk.directionalMovementSystem` on the high, low and close columns.
+    #[wasm_bindgen(js_name = directionalMovementSystem)]
+    pub fn directional_movement_system(
+        &self,
+        period: usize,
+        constant_model_type: crate::ConstantModelLike,
+    ) -> IndicatorResult<Array> {
+        crate::trend_indicators::trend_bulk_directional_movement_system(
+            &self.high,
+            &self.low,
+            &self.close,
+            period,
+            constant_model_type,
+        )
+    }
+
+    /// `trendIndicators.bulk.directionalMovementSystemColumns` on the high, low and close columns.
+    #[wasm_bindgen(js_name = directionalMovementSystemColumns)]
+    pub fn directional_movement_system_columns(
+        &self,
+        period: usize,
+        constant_model_type: crate::ConstantModelLike,
+    ) -> IndicatorResult<Object> {
+        crate::trend_indicators::trend_bulk_directional_movement_system_columns(
+            &self.high,
+            &self.low,
+            &self.close,
+            period,
+            constant_model_type,
+        )
+    }
+
+    /// `trendIndicators.bulk.volumePriceTrend` on the close column and the volume of each price change
+    /// (every bar but the first).
+    #[wasm_bindgen(js_name = volumePriceTrend)]
+    pub fn volume_price_trend(
+        &self,
+        previous_volume_price_trend: f64,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::trend_indicators::trend_bulk_volume_price_trend(
+            &self.close,
+            &self.volume[1..],
+            previous_volume_price_trend,
+        )
+    }
+
+    /// `trendIndicators.bulk.trueStrengthIndex` on the close column.
+    #[wasm_bindgen(js_name = trueStrengthIndex)]
+    pub fn true_strength_index(
+        &self,
+        first_constant_model: crate::ConstantModelLike,
+        first_period: usize,
+        second_constant_model: crate::ConstantModelLike,
+        second_period: usize,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::trend_indicators::trend_bulk_true_strength_index(
+            &self.close,
+            first_constant_model,
+            first_period,
+            second_constant_model,
+            second_period,
+        )
+    }
+
+    // -------- volatilityIndicators --------
+
+    /// `volatilityIndicators.bulk.ulcerIndex` on the close column.
+    #[wasm_bindgen(js_name = ulcerIndex)]
+    pub fn ulcer_index(&self, period: usize) -> IndicatorResult<Vec<f64>> {
+        crate::volatility_indicators::volatility_bulk_ulcer_index(&self.close, period)
+    }
+
+    /// `volatilityIndicators.bulk.volatilitySystem` on the high, low and close columns.
+    #[wasm_bindgen(js_name = volatilitySystem)]
+    pub fn volatility_system(
+        &self,
+        period: usize,
+        constant_multiplier: f64,
+        constant_model_type: crate::ConstantModelLike,
+    ) -> IndicatorResult<Vec<f64>> {
+        crate::volatility_indicators::volatility_bulk_volatility_system(
+            &self.high,
+            &self.low,
+            &self.close,
+            period,
+            constant_multiplier,
+            constant_model_type,
+        )
+    }
+}
@@ -1,4 +1,11 @@
-use js_sys::Array;
+use crate::align::aligned;
+use crate::columns::columns;
+use crate::error::{
+    check_min_length, check_not_empty, check_period, check_same_length, IndicatorResult,
+};
+use crate::missing::bulk;
+use crate::points::ReturnOnInvestmentPoint;
+use js_sys::{Array, Object};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsValue;
 
@@ -19,6 +26,17 @@ pub fn other_single_return_on_investment(
     arr
 }
 
+/// return_on_investment -> ReturnOnInvestmentPoint
+#[wasm_bindgen(js_name = other_single_returnOnInvestmentPoint)]
+pub fn other_single_return_on_investment_point(
+    start_price: f64,
+    end_price: f64,
+    investment: f64,
+) -> ReturnOnInvestmentPoint {
+    rust_ti::other_indicators::single::return_on_investment(start_price, end_price, investment)
+        .into()
+}
+
 /// true_range -> number
 #[wasm_bindgen(js_name = other_single_trueRange)]
 pub fn other_single_true_range(close: f64, high: f64, low: f64) -> f64 {
@@ -28,17 +46,22 @@ pub fn other_single_true_range(close: f64, high: f64, low: f64) -> f64 {
 /// average_true_range -> number
 #[wasm_bindgen(js_name = other_single_averageTrueRange)]
 pub fn other_single_average_true_range(
-    close: Vec<f64>,
-    high: Vec<f64>,
-    low: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
-) -> f64 {
-    rust_ti::other_indicators::single::average_true_range(
-        &close,
-        &high,
-        &low,
-        constant_model_type.into(),
-    )
+    close: &[f64],
+    high: &[f64],
+    low: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<f64> {
+    check_not_empty("close", close)?;
+    check_same_length("close", close.len(), "high", high.len())?;
+    check_same_length("close", close.len(), "low", low.len())?;
+    let constant_model_type =
+        crate::constant_model_type("constantModelType", &constant_model_type)?;
+    Ok(rust_ti::other_indicators::single::average_true_range(
+        close,
+        high,
+        low,
+        constant_model_type,
+    ))
 }
 
 /// internal_bar_strength -> number
@@ -49,10 +72,19 @@ pub fn other_single_internal_bar_strength(high: f64, low: f64, close: f64) -> f6
 
 // -------- BULK --------
 
+fn return_on_investment_rows(prices: &[f64], investment: f64) -> IndicatorResult<Vec<(f64, f64)>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_min_length("prices", prices.len(), 2)?;
+        Ok(rust_ti::other_indicators::bulk::return_on_investment(
+            prices, investment,
+        ))
+    })
+}
+
 /// return_on_investment -> Array<[final_value, percent_return]>
 #[wasm_bindgen(js_name = other_bulk_returnOnInvestment)]
-pub fn other_bulk_return_on_investment(prices: Vec<f64>, investment: f64) -> Array {
-    let data = rust_ti::other_indicators::bulk::return_on_investment(&prices, investment);
+pub fn other_bulk_return_on_investment(prices: &[f64], investment: f64) -> IndicatorResult<Array> {
+    let data = return_on_investment_rows(prices, investment)?;
     let out = Array::new();
     for (final_value, percent_return) in data {
         let inner = Array::new();
@@ -60,68 +92,147 @@ pub fn other_bulk_return_on_investment(prices: Vec<f64>, investment: f64) -> Arr
         inner.push(&JsValue::from_f64(percent_return));
         out.push(&inner);
     }
-    out
+    Ok(out)
 }
 
-/// true_range -> Array<number>
+/// return_on_investment -> { finalValue, percentReturn }
+#[wasm_bindgen(js_name = other_bulk_returnOnInvestmentColumns)]
+pub fn other_bulk_return_on_investment_columns(
+    prices: &[f64],
+    investment: f64,
+) -> IndicatorResult<Object> {
+    let data = return_on_investment_rows(prices, investment)?;
+    Ok(columns(
+        ["finalValue", "percentReturn"],
+        data.into_iter()
+            .map(|(final_value, percent_return)| [final_value, percent_return]),
+    ))
+}
+
+/// true_range -> Float64Array
 #[wasm_bindgen(js_name = other_bulk_trueRange)]
-pub fn other_bulk_true_range(close: Vec<f64>, high: Vec<f64>, low: Vec<f64>) -> Array {
-    let data = rust_ti::other_indicators::bulk::true_range(&close, &high, &low);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn other_bulk_true_range(
+    close: &[f64],
+    high: &[f64],
+    low: &[f64],
+) -> IndicatorResult<Vec<f64>> {
+    bulk(
+        ["close", "high", "low"],
+        [close, high, low],
+        |[close, high, low]| {
+            check_not_empty("close", close)?;
+            check_same_length("close", close.len(), "high", high.len())?;
+            check_same_length("close", close.len(), "low", low.len())?;
+            Ok(rust_ti::other_indicators::bulk::true_range(
+                close, high, low,
+            ))
+        },
+    )
 }
 
-/// average_true_range -> Array<number>
+/// average_true_range -> Float64Array
 #[wasm_bindgen(js_name = other_bulk_averageTrueRange)]
 pub fn other_bulk_average_true_range(
-    close: Vec<f64>,
-    high: Vec<f64>,
-    low: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    close: &[f64],
+    high: &[f64],
+    low: &[f64],
+    constant_model_type: crate::ConstantModelLike,
     period: usize,
-) -> Array {
-    let data = rust_ti::other_indicators::bulk::average_true_range(
-        &close,
-        &high,
-        &low,
-        constant_model_type.into(),
-        period,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(
+        ["close", "high", "low"],
+        [close, high, low],
+        |[close, high, low]| {
+            check_not_empty("close", close)?;
+            check_same_length("close", close.len(), "high", high.len())?;
+            check_same_length("close", close.len(), "low", low.len())?;
+            check_period("period", period, close.len())?;
+            let constant_model_type =
+                crate::constant_model_type("constantModelType", &constant_model_type)?;
+            Ok(rust_ti::other_indicators::bulk::average_true_range(
+                close,
+                high,
+                low,
+                constant_model_type,
+                period,
+            ))
+        },
+    )
 }
 
-/// internal_bar_strength -> Array<number>
+/// internal_bar_strength -> Float64Array
 #[wasm_bindgen(js_name = other_bulk_internalBarStrength)]
-pub fn other_bulk_internal_bar_strength(high: Vec<f64>, low: Vec<f64>, close: Vec<f64>) -> Array {
-    let data = rust_ti::other_indicators::bulk::internal_bar_strength(&high, &low, &close);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn other_bulk_internal_bar_strength(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+) -> IndicatorResult<Vec<f64>> {
+    bulk(
+        ["high", "low", "close"],
+        [high, low, close],
+        |[high, low, close]| {
+            check_not_empty("high", high)?;
+            check_same_length("high", high.len(), "low", low.len())?;
+            check_same_length("high", high.len(), "close", close.len())?;
+            Ok(rust_ti::other_indicators::bulk::internal_bar_strength(
+                high, low, close,
+            ))
+        },
+    )
+}
+
+pub(crate) fn positivity_indicator_rows(
+    open: &[f64],
+    previous_close: &[f64],
+    signal_period: usize,
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<Vec<(f64, f64)>> {
+    bulk(
+        ["open", "previousClose"],
+        [open, previous_close],
+        |[open, previous_close]| {
+            check_same_length("open", open.len(), "previousClose", previous_close.len())?;
+            check_period("signalPeriod", signal_period, open.len())?;
+            let constant_model_type =
+                crate::constant_model_type("constantModelType", &constant_model_type)?;
+            Ok(rust_ti::other_indicators::bulk::positivity_indicator(
+                open,
+                previous_close,
+                signal_period,
+                constant_model_type,
+            ))
+        },
+    )
+}
+
+// Positivity indicator over aligned open/close columns: each open is compared with the previous
+// bar's close, so rows start at the second bar (alignment pads to the full bar count)
+pub(crate) fn positivity_indicator_bar_rows(
+    open: &[f64],
+    close: &[f64],
+    signal_period: usize,
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<Vec<(f64, f64)>> {
+    check_same_length("close", close.len(), "open", open.len())?;
+    check_min_length("close", close.len(), 2)?;
+    let rows = positivity_indicator_rows(
+        &open[1..],
+        &close[..close.len() - 1],
+        signal_period,
+        constant_model_type,
+    )?;
+    Ok(aligned(rows, close.len()))
 }
 
 /// positivity_indicator -> Array<[pi, signal]>
 #[wasm_bindgen(js_name = other_bulk_positivityIndicator)]
 pub fn other_bulk_positivity_indicator(
-    open: Vec<f64>,
-    previous_close: Vec<f64>,
+    open: &[f64],
+    previous_close: &[f64],
     signal_period: usize,
-    constant_model_type: crate::ConstantModelType,
-) -> Array {
-    let data = rust_ti::other_indicators::bulk::positivity_indicator(
-        &open,
-        &previous_close,
-        signal_period,
-        constant_model_type.into(),
-    );
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<Array> {
+    let data = positivity_indicator_rows(open, previous_close, signal_period, constant_model_type)?;
     let out = Array::new();
     for (pi, sig) in data {
         let inner = Array::new();
@@ -129,5 +240,20 @@ pub fn other_bulk_positivity_indicator(
         inner.push(&JsValue::from_f64(sig));
         out.push(&inner);
     }
-    out
+    Ok(out)
+}
+
+/// positivity_indicator -> { positivityIndicator, signal }
+#[wasm_bindgen(js_name = other_bulk_positivityIndicatorColumns)]
+pub fn other_bulk_positivity_indicator_columns(
+    open: &[f64],
+    previous_close: &[f64],
+    signal_period: usize,
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<Object> {
+    let data = positivity_indicator_rows(open, previous_close, signal_period, constant_model_type)?;
+    Ok(columns(
+        ["positivityIndicator", "signal"],
+        data.into_iter().map(|(pi, sig)| [pi, sig]),
+    ))
 }
@@ -0,0 +1,177 @@
+use crate::batch::{get, numbers, read_json, run_spec, BatchSeries, Output, Spec, COLUMNS};
+use crate::error::{check_same_length, ErrorCode, IndicatorError, IndicatorResult};
+use js_sys::{Array, Float64Array, Object, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::{JsCast, JsValue};
+
+// Multi-asset panels.
+//
+// `computePanel(panel, spec)` runs one bulk indicator over every symbol of a panel in one call.
+// Each panel column (`close`, `high`, ...) is either one flat array of `symbols × bars` values
+// with `panel.shape = [symbols, bars]`, or an array of per-symbol arrays whose lengths may
+// differ. Results come back as flat `symbols × bars` matrices with rows right-aligned: the last
+// column is every symbol's latest bar and shorter histories are left-padded with NaN.
+//
+// `spec` is a `computeBatch` spec (`indicator`, `params`, `inputs`) applied to each symbol.
+
+/// Runs a bulk indicator over every symbol of `panel` and returns the results as matrices.
+///
+/// Returns `{ shape, values }` for single-output indicators and `{ shape, columns }`, with one
+/// matrix per output name, for multi-output ones.
+#[wasm_bindgen(js_name = computePanel)]
+pub fn compute_panel(panel: &JsValue, spec: &JsValue) -> IndicatorResult<Object> {
+    let spec: Spec = read_json("spec", spec)?;
+    let columns = read_panel(panel)?;
+    let symbols = columns.iter().flatten().map(|c| c.len()).max().unwrap_or(0);
+    let bars = columns
+        .iter()
+        .flatten()
+        .flatten()
+        .map(|row| row.len())
+        .max()
+        .unwrap_or(0);
+
+    // One matrix per output name; single-output indicators have one unnamed matrix
+    let mut outputs: Vec<(String, Vec<f64>)> = Vec::new();
+    let mut single = false;
+    for symbol in 0..symbols {
+        let [open, high, low, close, volume] =
+            std::array::from_fn(|i| columns[i].as_ref().map(|c| c[symbol].as_slice()));
+        let series = BatchSeries {
+            open,
+            high,
+            low,
+            close,
+            volume,
+        };
+        let output = match run_spec(&series, &[], &spec) {
+            Ok((_, output)) => output,
+            Err(e) => return Err(e.with_value("symbol", symbol as f64)),
+        };
+        let rows: Vec<(String, Vec<f64>)> = match output {
+            Output::Series(values) => {
+                single = true;
+                vec![(String::new(), values)]
+            }
+            Output::Columns(object) => Object::keys(&object)
+                .iter()
+                .map(|key| {
+                    let values = Reflect::get(&object, &key).unwrap_or(JsValue::UNDEFINED);
+                    (
+                        key.as_string().unwrap_or_default(),
+                        Float64Array::new(&values).to_vec(),
+                    )
+                })
+                .collect(),
+        };
+        if outputs.is_empty() {
+            outputs = rows
+                .iter()
+                .map(|(name, _)| (name.clone(), Vec::with_capacity(symbols * bars)))
+                .collect();
+        }
+        for ((_, matrix), (_, values)) in outputs.iter_mut().zip(rows) {
+            matrix.resize(matrix.len() + bars - values.len(), f64::NAN);
+            matrix.extend(values);
+        }
+    }
+
+    let out = Object::new();
+    let shape = Array::of2(
+        &JsValue::from_f64(symbols as f64),
+        &JsValue::from_f64(bars as f64),
+    );
+    let _ = Reflect::set(&out, &"shape".into(), &shape);
+    match outputs.first() {
+        Some((_, matrix)) if single => {
+            let _ = Reflect::set(
+                &out,
+                &"values".into(),
+                &Float64Array::from(matrix.as_slice()),
+            );
+        }
+        _ => {
+            let columns = Object::new();
+            for (name, matrix) in &outputs {
+                let _ = Reflect::set(
+                    &columns,
+                    &JsValue::from_str(name),
+                    &Float64Array::from(matrix.as_slice()),
+                );
+            }
+            let _ = Reflect::set(&out, &"columns".into(), &columns);
+        }
+    }
+    Ok(out)
+}
+
+// Per-symbol rows of every column present on `panel`
+fn read_panel(panel: &JsValue) -> IndicatorResult<[Option<Vec<Vec<f64>>>; 5]> {
+    let shape = get(panel, "shape");
+    let mut columns: [Option<Vec<Vec<f64>>>; 5] = Default::default();
+    let mut symbols: Option<(&'static str, usize)> = None;
+    for (column, name) in columns.iter_mut().zip(COLUMNS) {
+        let value = get(panel, name);
+        if value.is_undefined() || value.is_null() {
+            continue;
+        }
+        let rows: Vec<Vec<f64>> = if Array::is_array(&value)
+            && Array::from(&value)
+                .iter()
+                .next()
+                .is_some_and(|row| row.is_object())
+        {
+            Array::from(&value)
+                .iter()
+                .map(|row| numbers(name, &row))
+                .collect::<IndicatorResult<_>>()?
+        } else {
+            let flat = numbers(name, &value)?;
+            let [rows, bars] = read_shape(&shape)?;
+            check_same_length("shape", rows * bars, name, flat.len())?;
+            flat.chunks(bars.max(1)).map(|row| row.to_vec()).collect()
+        };
+        match symbols {
+            Some((reference, expected)) => {
+                check_same_length(reference, expected, name, rows.len())?
+            }
+            None => symbols = Some((name, rows.len())),
+        }
+        *column = Some(rows);
+    }
+    if symbols.is_none() {
+        return Err(IndicatorError::new(
+            ErrorCode::EmptyInput,
+            "panel",
+            "panel has no open, high, low, close or volume column".to_string(),
+        ));
+    }
+    Ok(columns)
+}
+
+// `[symbols, bars]` of a flat panel
+fn read_shape(shape: &JsValue) -> IndicatorResult<[usize; 2]> {
+    let dims: Vec<f64> = if Array::is_array(shape) {
+        shape
+            .unchecked_ref::<Array>()
+            .iter()
+            .filter_map(|v| v.as_f64())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    match dims.as_slice() {
+        [rows, bars]
+            if [*rows, *bars]
+                .iter()
+                .all(|d| *d >= 1.0 && d.fract() == 0.0 && *d <= u32::MAX as f64) =>
+        {
+            Ok([*rows as usize, *bars as usize])
+        }
+        _ => Err(IndicatorError::new(
+            ErrorCode::InvalidParameter,
+            "shape",
+            "shape must be [symbols, bars] for flat panel columns".to_string(),
+        )),
+    }
+}
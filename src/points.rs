@@ -0,0 +1,259 @@
+use wasm_bindgen::prelude::*;
+
+// Named-field results for multi-output single functions.
+//
+// The positional `[a, b, c]` arrays are kept for compatibility; the `*Point` bindings return one
+// of these classes instead so fields are read by name (`point.upper`) rather than by index.
+
+/// Lower band, central line and upper band.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BandPoint {
+    #[wasm_bindgen(readonly)]
+    pub lower: f64,
+    #[wasm_bindgen(readonly)]
+    pub middle: f64,
+    #[wasm_bindgen(readonly)]
+    pub upper: f64,
+}
+
+impl From<(f64, f64, f64)> for BandPoint {
+    fn from((lower, middle, upper): (f64, f64, f64)) -> Self {
+        Self {
+            lower,
+            middle,
+            upper,
+        }
+    }
+}
+
+/// Bands around a McGinley dynamic instead of a moving constant.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct McGinleyBandPoint {
+    #[wasm_bindgen(readonly)]
+    pub lower: f64,
+    #[wasm_bindgen(readonly)]
+    pub mcginley: f64,
+    #[wasm_bindgen(readonly)]
+    pub upper: f64,
+}
+
+impl From<(f64, f64, f64)> for McGinleyBandPoint {
+    fn from((lower, mcginley, upper): (f64, f64, f64)) -> Self {
+        Self {
+            lower,
+            mcginley,
+            upper,
+        }
+    }
+}
+
+/// Ichimoku Kinko Hyo lines for the latest bar.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IchimokuPoint {
+    #[wasm_bindgen(readonly, js_name = spanA)]
+    pub span_a: f64,
+    #[wasm_bindgen(readonly, js_name = spanB)]
+    pub span_b: f64,
+    #[wasm_bindgen(readonly)]
+    pub base: f64,
+    #[wasm_bindgen(readonly)]
+    pub conversion: f64,
+    #[wasm_bindgen(readonly, js_name = laggingClose)]
+    pub lagging_close: f64,
+}
+
+impl From<(f64, f64, f64, f64, f64)> for IchimokuPoint {
+    fn from((span_a, span_b, base, conversion, lagging_close): (f64, f64, f64, f64, f64)) -> Self {
+        Self {
+            span_a,
+            span_b,
+            base,
+            conversion,
+            lagging_close,
+        }
+    }
+}
+
+/// CCI computed around a McGinley dynamic, with the McGinley value for the next call.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct McGinleyCommodityChannelIndexPoint {
+    #[wasm_bindgen(readonly)]
+    pub cci: f64,
+    #[wasm_bindgen(readonly)]
+    pub mcginley: f64,
+}
+
+impl From<(f64, f64)> for McGinleyCommodityChannelIndexPoint {
+    fn from((cci, mcginley): (f64, f64)) -> Self {
+        Self { cci, mcginley }
+    }
+}
+
+/// MACD line from two McGinley dynamics, with both McGinley values for the next call.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct McGinleyMacdLinePoint {
+    #[wasm_bindgen(readonly)]
+    pub macd: f64,
+    #[wasm_bindgen(readonly, js_name = shortMcginley)]
+    pub short_mcginley: f64,
+    #[wasm_bindgen(readonly, js_name = longMcginley)]
+    pub long_mcginley: f64,
+}
+
+impl From<(f64, f64, f64)> for McGinleyMacdLinePoint {
+    fn from((macd, short_mcginley, long_mcginley): (f64, f64, f64)) -> Self {
+        Self {
+            macd,
+            short_mcginley,
+            long_mcginley,
+        }
+    }
+}
+
+/// Chaikin oscillator with the latest accumulation/distribution value.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChaikinOscillatorPoint {
+    #[wasm_bindgen(readonly)]
+    pub oscillator: f64,
+    #[wasm_bindgen(readonly, js_name = accumulationDistribution)]
+    pub accumulation_distribution: f64,
+}
+
+impl From<(f64, f64)> for ChaikinOscillatorPoint {
+    fn from((oscillator, accumulation_distribution): (f64, f64)) -> Self {
+        Self {
+            oscillator,
+            accumulation_distribution,
+        }
+    }
+}
+
+/// Value of the investment at the last price and its percentage return.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReturnOnInvestmentPoint {
+    #[wasm_bindgen(readonly, js_name = finalValue)]
+    pub final_value: f64,
+    #[wasm_bindgen(readonly, js_name = percentReturn)]
+    pub percent_return: f64,
+}
+
+impl From<(f64, f64)> for ReturnOnInvestmentPoint {
+    fn from((final_value, percent_return): (f64, f64)) -> Self {
+        Self {
+            final_value,
+            percent_return,
+        }
+    }
+}
+
+/// MACD line, signal line and histogram.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MacdPoint {
+    #[wasm_bindgen(readonly)]
+    pub macd: f64,
+    #[wasm_bindgen(readonly)]
+    pub signal: f64,
+    #[wasm_bindgen(readonly)]
+    pub histogram: f64,
+}
+
+impl From<(f64, f64, f64)> for MacdPoint {
+    fn from((macd, signal, histogram): (f64, f64, f64)) -> Self {
+        Self {
+            macd,
+            signal,
+            histogram,
+        }
+    }
+}
+
+/// Aroon up, Aroon down and their oscillator.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AroonPoint {
+    #[wasm_bindgen(readonly, js_name = aroonUp)]
+    pub aroon_up: f64,
+    #[wasm_bindgen(readonly, js_name = aroonDown)]
+    pub aroon_down: f64,
+    #[wasm_bindgen(readonly, js_name = aroonOscillator)]
+    pub aroon_oscillator: f64,
+}
+
+impl From<(f64, f64, f64)> for AroonPoint {
+    fn from((aroon_up, aroon_down, aroon_oscillator): (f64, f64, f64)) -> Self {
+        Self {
+            aroon_up,
+            aroon_down,
+            aroon_oscillator,
+        }
+    }
+}
+
+/// Positive and negative directional indicators, ADX and ADXR.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DirectionalMovementPoint {
+    #[wasm_bindgen(readonly)]
+    pub pdi: f64,
+    #[wasm_bindgen(readonly)]
+    pub ndi: f64,
+    #[wasm_bindgen(readonly)]
+    pub adx: f64,
+    #[wasm_bindgen(readonly)]
+    pub adxr: f64,
+}
+
+impl From<(f64, f64, f64, f64)> for DirectionalMovementPoint {
+    fn from((pdi, ndi, adx, adxr): (f64, f64, f64, f64)) -> Self {
+        Self {
+            pdi,
+            ndi,
+            adx,
+            adxr,
+        }
+    }
+}
+
+/// Running volume indicators of one bar.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VolumeFlowPoint {
+    #[wasm_bindgen(readonly, js_name = onBalanceVolume)]
+    pub on_balance_volume: f64,
+    #[wasm_bindgen(readonly, js_name = accumulationDistribution)]
+    pub accumulation_distribution: f64,
+    #[wasm_bindgen(readonly, js_name = positiveVolumeIndex)]
+    pub positive_volume_index: f64,
+    #[wasm_bindgen(readonly, js_name = negativeVolumeIndex)]
+    pub negative_volume_index: f64,
+    #[wasm_bindgen(readonly, js_name = volumePriceTrend)]
+    pub volume_price_trend: f64,
+}
+
+impl From<(f64, f64, f64, f64, f64)> for VolumeFlowPoint {
+    fn from(
+        (
+            on_balance_volume,
+            accumulation_distribution,
+            positive_volume_index,
+            negative_volume_index,
+            volume_price_trend,
+        ): (f64, f64, f64, f64, f64),
+    ) -> Self {
+        Self {
+            on_balance_volume,
+            accumulation_distribution,
+            positive_volume_index,
+            negative_volume_index,
+            volume_price_trend,
+        }
+    }
+}
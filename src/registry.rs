@@ -0,0 +1,1547 @@
+use crate::error::{ErrorCode, IndicatorError, IndicatorResult};
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsValue;
+
+// Indicator registry.
+//
+// Static description of every indicator in the namespaced API: which of single/bulk exist, the
+// positional input arrays and parameters of each, parameter types with defaults, valid ranges and
+// enum choices, and the output field names (the `*Columns` keys for multi-output indicators).
+// UIs and config validators can be generated from `listIndicators()` instead of `index.d.ts`.
+// chartTrends functions are analysis helpers rather than indicators and are not listed.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ParamType {
+    Integer,
+    Number,
+    ConstantModelType,
+    ConstantModelFamily,
+    DeviationModel,
+    MovingAverageType,
+    Position,
+}
+
+impl ParamType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ParamType::Integer => "integer",
+            ParamType::Number => "number",
+            ParamType::ConstantModelType => "ConstantModelType",
+            ParamType::ConstantModelFamily => "ConstantModelFamily",
+            ParamType::DeviationModel => "DeviationModel",
+            ParamType::MovingAverageType => "MovingAverageType",
+            ParamType::Position => "Position",
+        }
+    }
+
+    /// Variant names of the JS enum, empty for numeric parameters.
+    pub(crate) fn choices(&self) -> &'static [&'static str] {
+        match self {
+            ParamType::Integer | ParamType::Number => &[],
+            ParamType::ConstantModelType => &[
+                "SimpleMovingAverage",
+                "SmoothedMovingAverage",
+                "ExponentialMovingAverage",
+                "SimpleMovingMedian",
+                "SimpleMovingMode",
+            ],
+            // Either enum: the ConstantModelType variants, then the families
+            ParamType::ConstantModelFamily => &[
+                "SimpleMovingAverage",
+                "SmoothedMovingAverage",
+                "ExponentialMovingAverage",
+                "SimpleMovingMedian",
+                "SimpleMovingMode",
+                "WeightedMovingAverage",
+                "HullMovingAverage",
+                "DoubleExponentialMovingAverage",
+                "TripleExponentialMovingAverage",
+                "ZeroLagExponentialMovingAverage",
+                "TriangularMovingAverage",
+            ],
+            ParamType::DeviationModel => &[
+                "StandardDeviation",
+                "MeanAbsoluteDeviation",
+                "MedianAbsoluteDeviation",
+                "ModeAbsoluteDeviation",
+                "UlcerIndex",
+                "LogStandardDeviation",
+                "LaplaceStdEquivalent",
+                "CauchyIQRScale",
+            ],
+            ParamType::MovingAverageType => &[
+                "Simple",
+                "Smoothed",
+                "Exponential",
+                "Weighted",
+                "Hull",
+                "DoubleExponential",
+                "TripleExponential",
+                "ZeroLagExponential",
+                "Triangular",
+                "VolumeWeighted",
+            ],
+            ParamType::Position => &["Long", "Short"],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ParamDefault {
+    None,
+    Number(f64),
+    Choice(&'static str),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ParamSpec {
+    pub(crate) name: &'static str,
+    pub(crate) kind: ParamType,
+    pub(crate) default: ParamDefault,
+    pub(crate) min: Option<f64>,
+    pub(crate) max: Option<f64>,
+}
+
+impl ParamSpec {
+    const fn integer(name: &'static str, default: f64, min: f64) -> Self {
+        Self {
+            name,
+            kind: ParamType::Integer,
+            default: ParamDefault::Number(default),
+            min: Some(min),
+            max: None,
+        }
+    }
+
+    const fn number(
+        name: &'static str,
+        default: Option<f64>,
+        min: Option<f64>,
+        max: Option<f64>,
+    ) -> Self {
+        Self {
+            name,
+            kind: ParamType::Number,
+            default: match default {
+                Some(v) => ParamDefault::Number(v),
+                None => ParamDefault::None,
+            },
+            min,
+            max,
+        }
+    }
+
+    const fn choice(name: &'static str, kind: ParamType, default: &'static str) -> Self {
+        Self {
+            name,
+            kind,
+            default: ParamDefault::Choice(default),
+            min: None,
+            max: None,
+        }
+    }
+}
+
+/// Positional arguments of a single or bulk function: input arrays first, then parameters, then
+/// the input arrays that may be left out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Signature {
+    pub(crate) inputs: &'static [&'static str],
+    pub(crate) params: &'static [&'static str],
+    pub(crate) optional: &'static [&'static str],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct IndicatorSpec {
+    pub(crate) module: &'static str,
+    pub(crate) name: &'static str,
+    pub(crate) single: Option<Signature>,
+    pub(crate) bulk: Option<Signature>,
+    pub(crate) params: &'static [ParamSpec],
+    pub(crate) outputs: &'static [&'static str],
+}
+
+/// Looks up `"module.name"`, e.g. `"momentumIndicators.relativeStrengthIndex"`.
+pub(crate) fn find(indicator: &str) -> IndicatorResult<&'static IndicatorSpec> {
+    INDICATORS
+        .iter()
+        .find(|spec| {
+            indicator
+                .split_once('.')
+                .is_some_and(|(module, name)| spec.module == module && spec.name == name)
+        })
+        .ok_or_else(|| {
+            IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                "indicator",
+                format!("indicator ({}) is not a registered indicator", indicator),
+            )
+        })
+}
+
+/// Every registered indicator, in namespace order.
+#[wasm_bindgen(js_name = listIndicators)]
+pub fn list_indicators() -> Array {
+    INDICATORS.iter().map(to_object).collect()
+}
+
+/// Metadata of one indicator by namespaced name, e.g. `"trendIndicators.aroonIndicator"`.
+#[wasm_bindgen(js_name = describeIndicator)]
+pub fn describe_indicator(indicator: &str) -> IndicatorResult<Object> {
+    Ok(to_object(find(indicator)?))
+}
+
+// -------- JS CONVERSION --------
+
+fn set(target: &Object, key: &str, value: &JsValue) {
+    let _ = Reflect::set(target, &JsValue::from_str(key), value);
+}
+
+fn strings(values: &[&str]) -> Array {
+    values.iter().map(|v| JsValue::from_str(v)).collect()
+}
+
+fn signature_object(signature: &Option<Signature>) -> JsValue {
+    match signature {
+        Some(signature) => {
+            let out = Object::new();
+            set(&out, "inputs", &strings(signature.inputs));
+            set(&out, "params", &strings(signature.params));
+            set(&out, "optional", &strings(signature.optional));
+            out.into()
+        }
+        None => JsValue::UNDEFINED,
+    }
+}
+
+fn param_object(param: &ParamSpec) -> Object {
+    let out = Object::new();
+    set(&out, "name", &param.name.into());
+    set(&out, "type", &param.kind.as_str().into());
+    match param.default {
+        ParamDefault::None => {}
+        ParamDefault::Number(v) => set(&out, "default", &v.into()),
+        ParamDefault::Choice(v) => set(&out, "default", &v.into()),
+    }
+    if let Some(min) = param.min {
+        set(&out, "min", &min.into());
+    }
+    if let Some(max) = param.max {
+        set(&out, "max", &max.into());
+    }
+    if !param.kind.choices().is_empty() {
+        set(&out, "choices", &strings(param.kind.choices()));
+    }
+    out
+}
+
+fn to_object(spec: &IndicatorSpec) -> Object {
+    let out = Object::new();
+    set(&out, "module", &spec.module.into());
+    set(&out, "name", &spec.name.into());
+    set(&out, "single", &signature_object(&spec.single));
+    set(&out, "bulk", &signature_object(&spec.bulk));
+    let params: Array = spec.params.iter().map(param_object).collect();
+    set(&out, "params", &params);
+    set(&out, "outputs", &strings(spec.outputs));
+    out
+}
+
+// -------- REGISTRY --------
+
+pub(crate) static INDICATORS: &[IndicatorSpec] = &[
+    IndicatorSpec {
+        module: "candleIndicators",
+        name: "movingConstantEnvelopes",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["constantModelType", "difference"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["constantModelType", "difference", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelFamily,
+                "SimpleMovingAverage",
+            ),
+            ParamSpec::number("difference", Some(3.0), Some(0.0), None),
+            ParamSpec::integer("period", 20.0, 1.0),
+        ],
+        outputs: &["lower", "middle", "upper"],
+    },
+    IndicatorSpec {
+        module: "candleIndicators",
+        name: "mcginleyDynamicEnvelopes",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["difference", "previousMcginleyDynamic"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["difference", "previousMcginleyDynamic", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::number("difference", Some(3.0), Some(0.0), None),
+            ParamSpec::number("previousMcginleyDynamic", Some(0.0), None, None),
+            ParamSpec::integer("period", 20.0, 1.0),
+        ],
+        outputs: &["lower", "mcginley", "upper"],
+    },
+    IndicatorSpec {
+        module: "candleIndicators",
+        name: "movingConstantBands",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["constantModelType", "deviationModel", "deviationMultiplier"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[
+                "constantModelType",
+                "deviationModel",
+                "deviationMultiplier",
+                "period",
+            ],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelFamily,
+                "SimpleMovingAverage",
+            ),
+            ParamSpec::choice(
+                "deviationModel",
+                ParamType::DeviationModel,
+                "StandardDeviation",
+            ),
+            ParamSpec::number("deviationMultiplier", Some(2.0), Some(0.0), None),
+            ParamSpec::integer("period", 20.0, 1.0),
+        ],
+        outputs: &["lower", "middle", "upper"],
+    },
+    IndicatorSpec {
+        module: "candleIndicators",
+        name: "mcginleyDynamicBands",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[
+                "deviationModel",
+                "deviationMultiplier",
+                "previousMcginleyDynamic",
+            ],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[
+                "deviationModel",
+                "deviationMultiplier",
+                "previousMcginleyDynamic",
+                "period",
+            ],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "deviationModel",
+                ParamType::DeviationModel,
+                "StandardDeviation",
+            ),
+            ParamSpec::number("deviationMultiplier", Some(2.0), Some(0.0), None),
+            ParamSpec::number("previousMcginleyDynamic", Some(0.0), None, None),
+            ParamSpec::integer("period", 20.0, 1.0),
+        ],
+        outputs: &["lower", "mcginley", "upper"],
+    },
+    IndicatorSpec {
+        module: "candleIndicators",
+        name: "ichimokuCloud",
+        single: Some(Signature {
+            inputs: &["highs", "lows", "close"],
+            params: &["conversionPeriod", "basePeriod", "spanBPeriod"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["highs", "lows", "close"],
+            params: &["conversionPeriod", "basePeriod", "spanBPeriod"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::integer("conversionPeriod", 9.0, 1.0),
+            ParamSpec::integer("basePeriod", 26.0, 1.0),
+            ParamSpec::integer("spanBPeriod", 52.0, 1.0),
+        ],
+        outputs: &["spanA", "spanB", "base", "conversion", "displacedClose"],
+    },
+    IndicatorSpec {
+        module: "candleIndicators",
+        name: "donchianChannels",
+        single: Some(Signature {
+            inputs: &["highs", "lows"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["highs", "lows"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 20.0, 1.0)],
+        outputs: &["lower", "middle", "upper"],
+    },
+    IndicatorSpec {
+        module: "candleIndicators",
+        name: "keltnerChannel",
+        single: Some(Signature {
+            inputs: &["highs", "lows", "close"],
+            params: &["constantModelType", "atrConstantModelType", "multiplier"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["highs", "lows", "close"],
+            params: &[
+                "constantModelType",
+                "atrConstantModelType",
+                "multiplier",
+                "period",
+            ],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "ExponentialMovingAverage",
+            ),
+            ParamSpec::choice(
+                "atrConstantModelType",
+                ParamType::ConstantModelType,
+                "SimpleMovingAverage",
+            ),
+            ParamSpec::number("multiplier", Some(2.0), Some(0.0), None),
+            ParamSpec::integer("period", 20.0, 1.0),
+        ],
+        outputs: &["lower", "middle", "upper"],
+    },
+    IndicatorSpec {
+        module: "candleIndicators",
+        name: "supertrend",
+        single: Some(Signature {
+            inputs: &["highs", "lows", "close"],
+            params: &["constantModelType", "multiplier"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["highs", "lows", "close"],
+            params: &["constantModelType", "multiplier", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "SimpleMovingAverage",
+            ),
+            ParamSpec::number("multiplier", Some(3.0), Some(0.0), None),
+            ParamSpec::integer("period", 10.0, 1.0),
+        ],
+        outputs: &["supertrend"],
+    },
+    IndicatorSpec {
+        module: "correlationIndicators",
+        name: "correlateAssetPrices",
+        single: Some(Signature {
+            inputs: &["pricesAssetA", "pricesAssetB"],
+            params: &["constantModelType", "deviationModel"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["pricesAssetA", "pricesAssetB"],
+            params: &["constantModelType", "deviationModel", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "SimpleMovingAverage",
+            ),
+            ParamSpec::choice(
+                "deviationModel",
+                ParamType::DeviationModel,
+                "StandardDeviation",
+            ),
+            ParamSpec::integer("period", 20.0, 1.0),
+        ],
+        outputs: &["correlateAssetPrices"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "relativeStrengthIndex",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["constantModelType"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["constantModelType", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelFamily,
+                "SmoothedMovingAverage",
+            ),
+            ParamSpec::integer("period", 14.0, 1.0),
+        ],
+        outputs: &["relativeStrengthIndex"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "stochasticOscillator",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 14.0, 1.0)],
+        outputs: &["stochasticOscillator"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "slowStochastic",
+        single: Some(Signature {
+            inputs: &["stochastics"],
+            params: &["constantModelType"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["stochastics"],
+            params: &["constantModelType", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "SimpleMovingAverage",
+            ),
+            ParamSpec::integer("period", 3.0, 1.0),
+        ],
+        outputs: &["slowStochastic"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "slowestStochastic",
+        single: Some(Signature {
+            inputs: &["slowStochastics"],
+            params: &["constantModelType"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["slowStochastics"],
+            params: &["constantModelType", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "SimpleMovingAverage",
+            ),
+            ParamSpec::integer("period", 3.0, 1.0),
+        ],
+        outputs: &["slowestStochastic"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "williamsPercentR",
+        single: Some(Signature {
+            inputs: &["high", "low", "close"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["high", "low", "close"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 14.0, 1.0)],
+        outputs: &["williamsPercentR"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "moneyFlowIndex",
+        single: Some(Signature {
+            inputs: &["prices", "volume"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices", "volume"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 14.0, 1.0)],
+        outputs: &["moneyFlowIndex"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "rateOfChange",
+        single: Some(Signature {
+            inputs: &["currentPrice", "previousPrice"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        params: &[],
+        outputs: &["rateOfChange"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "onBalanceVolume",
+        single: Some(Signature {
+            inputs: &["currentPrice", "previousPrice", "currentVolume"],
+            params: &["previousOnBalanceVolume"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices", "volume"],
+            params: &["previousOnBalanceVolume"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::number(
+            "previousOnBalanceVolume",
+            Some(0.0),
+            None,
+            None,
+        )],
+        outputs: &["onBalanceVolume"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "commodityChannelIndex",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["constantModelType", "deviationModel", "constantMultiplier"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[
+                "constantModelType",
+                "deviationModel",
+                "constantMultiplier",
+                "period",
+            ],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "SimpleMovingAverage",
+            ),
+            ParamSpec::choice(
+                "deviationModel",
+                ParamType::DeviationModel,
+                "MeanAbsoluteDeviation",
+            ),
+            ParamSpec::number("constantMultiplier", Some(0.015), Some(0.0), None),
+            ParamSpec::integer("period", 20.0, 1.0),
+        ],
+        outputs: &["commodityChannelIndex"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "mcginleyDynamicCommodityChannelIndex",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[
+                "previousMcginleyDynamic",
+                "deviationModel",
+                "constantMultiplier",
+            ],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[
+                "previousMcginleyDynamic",
+                "deviationModel",
+                "constantMultiplier",
+                "period",
+            ],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::number("previousMcginleyDynamic", Some(0.0), None, None),
+            ParamSpec::choice(
+                "deviationModel",
+                ParamType::DeviationModel,
+                "MeanAbsoluteDeviation",
+            ),
+            ParamSpec::number("constantMultiplier", Some(0.015), Some(0.0), None),
+            ParamSpec::integer("period", 20.0, 1.0),
+        ],
+        outputs: &["cci", "mcginley"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "macdLine",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["shortPeriod", "shortPeriodModel", "longPeriodModel"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[
+                "shortPeriod",
+                "shortPeriodModel",
+                "longPeriod",
+                "longPeriodModel",
+            ],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::integer("shortPeriod", 12.0, 1.0),
+            ParamSpec::choice(
+                "shortPeriodModel",
+                ParamType::ConstantModelFamily,
+                "ExponentialMovingAverage",
+            ),
+            ParamSpec::integer("longPeriod", 26.0, 1.0),
+            ParamSpec::choice(
+                "longPeriodModel",
+                ParamType::ConstantModelFamily,
+                "ExponentialMovingAverage",
+            ),
+        ],
+        outputs: &["macdLine"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "signalLine",
+        single: Some(Signature {
+            inputs: &["macds"],
+            params: &["constantModelType"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["macds"],
+            params: &["constantModelType", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelFamily,
+                "ExponentialMovingAverage",
+            ),
+            ParamSpec::integer("period", 9.0, 1.0),
+        ],
+        outputs: &["signalLine"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "mcginleyDynamicMacdLine",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[
+                "shortPeriod",
+                "previousShortMcginley",
+                "previousLongMcginley",
+            ],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[
+                "shortPeriod",
+                "previousShortMcginley",
+                "longPeriod",
+                "previousLongMcginley",
+            ],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::integer("shortPeriod", 12.0, 1.0),
+            ParamSpec::number("previousShortMcginley", Some(0.0), None, None),
+            ParamSpec::integer("longPeriod", 26.0, 1.0),
+            ParamSpec::number("previousLongMcginley", Some(0.0), None, None),
+        ],
+        outputs: &["macd", "shortMcginley", "longMcginley"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "chaikinOscillator",
+        single: Some(Signature {
+            inputs: &["highs", "lows", "close", "volume"],
+            params: &[
+                "shortPeriod",
+                "previousAccumulationDistribution",
+                "shortPeriodModel",
+                "longPeriodModel",
+            ],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["highs", "lows", "close", "volume"],
+            params: &[
+                "shortPeriod",
+                "longPeriod",
+                "previousAccumulationDistribution",
+                "shortPeriodModel",
+                "longPeriodModel",
+            ],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::integer("shortPeriod", 3.0, 1.0),
+            ParamSpec::integer("longPeriod", 10.0, 1.0),
+            ParamSpec::number("previousAccumulationDistribution", Some(0.0), None, None),
+            ParamSpec::choice(
+                "shortPeriodModel",
+                ParamType::ConstantModelType,
+                "ExponentialMovingAverage",
+            ),
+            ParamSpec::choice(
+                "longPeriodModel",
+                ParamType::ConstantModelType,
+                "ExponentialMovingAverage",
+            ),
+        ],
+        outputs: &["oscillator", "accumulationDistribution"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "percentagePriceOscillator",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["shortPeriod", "constantModelType"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["shortPeriod", "longPeriod", "constantModelType"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::integer("shortPeriod", 12.0, 1.0),
+            ParamSpec::integer("longPeriod", 26.0, 1.0),
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "ExponentialMovingAverage",
+            ),
+        ],
+        outputs: &["percentagePriceOscillator"],
+    },
+    IndicatorSpec {
+        module: "momentumIndicators",
+        name: "chandeMomentumOscillator",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 14.0, 1.0)],
+        outputs: &["chandeMomentumOscillator"],
+    },
+    IndicatorSpec {
+        module: "movingAverage",
+        name: "movingAverage",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["maType"],
+            optional: &["volume"],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["maType", "period"],
+            optional: &["volume"],
+        }),
+        params: &[
+            ParamSpec::choice("maType", ParamType::MovingAverageType, "Simple"),
+            ParamSpec::integer("period", 14.0, 1.0),
+        ],
+        outputs: &["movingAverage"],
+    },
+    IndicatorSpec {
+        module: "movingAverage",
+        name: "mcginleyDynamic",
+        single: Some(Signature {
+            inputs: &["latestPrice"],
+            params: &["previousMcginleyDynamic", "period"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["previousMcginleyDynamic", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::number("previousMcginleyDynamic", Some(0.0), None, None),
+            ParamSpec::integer("period", 10.0, 1.0),
+        ],
+        outputs: &["mcginleyDynamic"],
+    },
+    IndicatorSpec {
+        module: "movingAverage",
+        name: "volumeWeightedMovingAverage",
+        single: Some(Signature {
+            inputs: &["prices", "volume"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices", "volume"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 20.0, 1.0)],
+        outputs: &["volumeWeightedMovingAverage"],
+    },
+    IndicatorSpec {
+        module: "movingAverage",
+        name: "kaufmanAdaptiveMovingAverage",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["previousKama", "fastPeriod", "slowPeriod"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["previousKama", "period", "fastPeriod", "slowPeriod"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::number("previousKama", Some(0.0), None, None),
+            ParamSpec::integer("period", 10.0, 1.0),
+            ParamSpec::integer("fastPeriod", 2.0, 1.0),
+            ParamSpec::integer("slowPeriod", 30.0, 2.0),
+        ],
+        outputs: &["kaufmanAdaptiveMovingAverage"],
+    },
+    IndicatorSpec {
+        module: "movingAverage",
+        name: "fractalAdaptiveMovingAverage",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["previousFrama"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["previousFrama", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::number("previousFrama", Some(0.0), None, None),
+            ParamSpec::integer("period", 16.0, 2.0),
+        ],
+        outputs: &["fractalAdaptiveMovingAverage"],
+    },
+    IndicatorSpec {
+        module: "movingAverage",
+        name: "variableIndexDynamicAverage",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["previousVidya", "period"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["previousVidya", "period", "cmoPeriod"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::number("previousVidya", Some(0.0), None, None),
+            ParamSpec::integer("period", 14.0, 1.0),
+            ParamSpec::integer("cmoPeriod", 9.0, 1.0),
+        ],
+        outputs: &["variableIndexDynamicAverage"],
+    },
+    IndicatorSpec {
+        module: "movingAverage",
+        name: "arnaudLegouxMovingAverage",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["offset", "sigma"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["period", "offset", "sigma"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::integer("period", 9.0, 1.0),
+            ParamSpec::number("offset", Some(0.85), Some(0.0), Some(1.0)),
+            ParamSpec::number("sigma", Some(6.0), Some(0.0), None),
+        ],
+        outputs: &["arnaudLegouxMovingAverage"],
+    },
+    IndicatorSpec {
+        module: "movingAverage",
+        name: "tillsonT3",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["volumeFactor"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["period", "volumeFactor"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::integer("period", 5.0, 1.0),
+            ParamSpec::number("volumeFactor", Some(0.7), Some(0.0), Some(1.0)),
+        ],
+        outputs: &["tillsonT3"],
+    },
+    IndicatorSpec {
+        module: "otherIndicators",
+        name: "returnOnInvestment",
+        single: Some(Signature {
+            inputs: &["startPrice", "endPrice"],
+            params: &["investment"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["investment"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::number(
+            "investment",
+            Some(1000.0),
+            Some(0.0),
+            None,
+        )],
+        outputs: &["finalValue", "percentReturn"],
+    },
+    IndicatorSpec {
+        module: "otherIndicators",
+        name: "trueRange",
+        single: Some(Signature {
+            inputs: &["close", "high", "low"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["close", "high", "low"],
+            params: &[],
+            optional: &[],
+        }),
+        params: &[],
+        outputs: &["trueRange"],
+    },
+    IndicatorSpec {
+        module: "otherIndicators",
+        name: "averageTrueRange",
+        single: Some(Signature {
+            inputs: &["close", "high", "low"],
+            params: &["constantModelType"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["close", "high", "low"],
+            params: &["constantModelType", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "SmoothedMovingAverage",
+            ),
+            ParamSpec::integer("period", 14.0, 1.0),
+        ],
+        outputs: &["averageTrueRange"],
+    },
+    IndicatorSpec {
+        module: "otherIndicators",
+        name: "internalBarStrength",
+        single: Some(Signature {
+            inputs: &["high", "low", "close"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["high", "low", "close"],
+            params: &[],
+            optional: &[],
+        }),
+        params: &[],
+        outputs: &["internalBarStrength"],
+    },
+    IndicatorSpec {
+        module: "otherIndicators",
+        name: "positivityIndicator",
+        single: None,
+        bulk: Some(Signature {
+            inputs: &["open", "previousClose"],
+            params: &["signalPeriod", "constantModelType"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::integer("signalPeriod", 5.0, 1.0),
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "SimpleMovingAverage",
+            ),
+        ],
+        outputs: &["positivityIndicator", "signal"],
+    },
+    IndicatorSpec {
+        module: "standardIndicators",
+        name: "simpleMovingAverage",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 14.0, 1.0)],
+        outputs: &["simpleMovingAverage"],
+    },
+    IndicatorSpec {
+        module: "standardIndicators",
+        name: "smoothedMovingAverage",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 14.0, 1.0)],
+        outputs: &["smoothedMovingAverage"],
+    },
+    IndicatorSpec {
+        module: "standardIndicators",
+        name: "exponentialMovingAverage",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 14.0, 1.0)],
+        outputs: &["exponentialMovingAverage"],
+    },
+    IndicatorSpec {
+        module: "standardIndicators",
+        name: "bollingerBands",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        params: &[],
+        outputs: &["lower", "middle", "upper"],
+    },
+    IndicatorSpec {
+        module: "standardIndicators",
+        name: "macd",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        params: &[],
+        outputs: &["macd", "signal", "histogram"],
+    },
+    IndicatorSpec {
+        module: "standardIndicators",
+        name: "rsi",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        params: &[],
+        outputs: &["rsi"],
+    },
+    IndicatorSpec {
+        module: "strengthIndicators",
+        name: "accumulationDistribution",
+        single: Some(Signature {
+            inputs: &["high", "low", "close", "volume"],
+            params: &["previousAccumulationDistribution"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["high", "low", "close", "volume"],
+            params: &["previousAccumulationDistribution"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::number(
+            "previousAccumulationDistribution",
+            Some(0.0),
+            None,
+            None,
+        )],
+        outputs: &["accumulationDistribution"],
+    },
+    IndicatorSpec {
+        module: "strengthIndicators",
+        name: "volumeIndex",
+        single: Some(Signature {
+            inputs: &["currentClose", "previousClose"],
+            params: &["previousVolumeIndex"],
+            optional: &[],
+        }),
+        bulk: None,
+        params: &[ParamSpec::number(
+            "previousVolumeIndex",
+            Some(0.0),
+            None,
+            None,
+        )],
+        outputs: &["volumeIndex"],
+    },
+    IndicatorSpec {
+        module: "strengthIndicators",
+        name: "relativeVigorIndex",
+        single: Some(Signature {
+            inputs: &["open", "high", "low", "close"],
+            params: &["constantModelType"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["open", "high", "low", "close"],
+            params: &["constantModelType", "period"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "SimpleMovingAverage",
+            ),
+            ParamSpec::integer("period", 10.0, 4.0),
+        ],
+        outputs: &["relativeVigorIndex"],
+    },
+    IndicatorSpec {
+        module: "strengthIndicators",
+        name: "positiveVolumeIndex",
+        single: None,
+        bulk: Some(Signature {
+            inputs: &["close", "volume"],
+            params: &["previousPositiveVolumeIndex"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::number(
+            "previousPositiveVolumeIndex",
+            Some(0.0),
+            None,
+            None,
+        )],
+        outputs: &["positiveVolumeIndex"],
+    },
+    IndicatorSpec {
+        module: "strengthIndicators",
+        name: "negativeVolumeIndex",
+        single: None,
+        bulk: Some(Signature {
+            inputs: &["close", "volume"],
+            params: &["previousNegativeVolumeIndex"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::number(
+            "previousNegativeVolumeIndex",
+            Some(0.0),
+            None,
+            None,
+        )],
+        outputs: &["negativeVolumeIndex"],
+    },
+    IndicatorSpec {
+        module: "trendIndicators",
+        name: "aroonUp",
+        single: Some(Signature {
+            inputs: &["highs"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["highs"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 25.0, 1.0)],
+        outputs: &["aroonUp"],
+    },
+    IndicatorSpec {
+        module: "trendIndicators",
+        name: "aroonDown",
+        single: Some(Signature {
+            inputs: &["lows"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["lows"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 25.0, 1.0)],
+        outputs: &["aroonDown"],
+    },
+    IndicatorSpec {
+        module: "trendIndicators",
+        name: "aroonOscillator",
+        single: Some(Signature {
+            inputs: &["aroonUp", "aroonDown"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["aroonUp", "aroonDown"],
+            params: &[],
+            optional: &[],
+        }),
+        params: &[],
+        outputs: &["aroonOscillator"],
+    },
+    IndicatorSpec {
+        module: "trendIndicators",
+        name: "aroonIndicator",
+        single: Some(Signature {
+            inputs: &["highs", "lows"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["highs", "lows"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 25.0, 1.0)],
+        outputs: &["aroonUp", "aroonDown", "aroonOscillator"],
+    },
+    IndicatorSpec {
+        module: "trendIndicators",
+        name: "longParabolicTimePriceSystem",
+        single: Some(Signature {
+            inputs: &["low"],
+            params: &["previousSar", "extremePoint", "accelerationFactor"],
+            optional: &[],
+        }),
+        bulk: None,
+        params: &[
+            ParamSpec::number("previousSar", Some(0.0), None, None),
+            ParamSpec::number("extremePoint", None, None, None),
+            ParamSpec::number("accelerationFactor", Some(0.02), Some(0.0), Some(1.0)),
+        ],
+        outputs: &["longParabolicTimePriceSystem"],
+    },
+    IndicatorSpec {
+        module: "trendIndicators",
+        name: "shortParabolicTimePriceSystem",
+        single: Some(Signature {
+            inputs: &["high"],
+            params: &["previousSar", "extremePoint", "accelerationFactor"],
+            optional: &[],
+        }),
+        bulk: None,
+        params: &[
+            ParamSpec::number("previousSar", Some(0.0), None, None),
+            ParamSpec::number("extremePoint", None, None, None),
+            ParamSpec::number("accelerationFactor", Some(0.02), Some(0.0), Some(1.0)),
+        ],
+        outputs: &["shortParabolicTimePriceSystem"],
+    },
+    IndicatorSpec {
+        module: "trendIndicators",
+        name: "volumePriceTrend",
+        single: Some(Signature {
+            inputs: &["currentPrice", "previousPrice", "volume"],
+            params: &["previousVolumePriceTrend"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices", "volumes"],
+            params: &["previousVolumePriceTrend"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::number(
+            "previousVolumePriceTrend",
+            Some(0.0),
+            None,
+            None,
+        )],
+        outputs: &["volumePriceTrend"],
+    },
+    IndicatorSpec {
+        module: "trendIndicators",
+        name: "trueStrengthIndex",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &["firstConstantModel", "firstPeriod", "secondConstantModel"],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &[
+                "firstConstantModel",
+                "firstPeriod",
+                "secondConstantModel",
+                "secondPeriod",
+            ],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::choice(
+                "firstConstantModel",
+                ParamType::ConstantModelType,
+                "ExponentialMovingAverage",
+            ),
+            ParamSpec::integer("firstPeriod", 25.0, 1.0),
+            ParamSpec::choice(
+                "secondConstantModel",
+                ParamType::ConstantModelType,
+                "ExponentialMovingAverage",
+            ),
+            ParamSpec::integer("secondPeriod", 13.0, 1.0),
+        ],
+        outputs: &["trueStrengthIndex"],
+    },
+    IndicatorSpec {
+        module: "trendIndicators",
+        name: "parabolicTimePriceSystem",
+        single: None,
+        bulk: Some(Signature {
+            inputs: &["highs", "lows"],
+            params: &[
+                "accelerationFactorStart",
+                "accelerationFactorMax",
+                "accelerationFactorStep",
+                "startPosition",
+                "previousSar",
+            ],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::number("accelerationFactorStart", Some(0.02), Some(0.0), Some(1.0)),
+            ParamSpec::number("accelerationFactorMax", Some(0.2), Some(0.0), Some(1.0)),
+            ParamSpec::number("accelerationFactorStep", Some(0.02), Some(0.0), Some(1.0)),
+            ParamSpec::choice("startPosition", ParamType::Position, "Long"),
+            ParamSpec::number("previousSar", Some(0.0), None, None),
+        ],
+        outputs: &["parabolicTimePriceSystem"],
+    },
+    IndicatorSpec {
+        module: "trendIndicators",
+        name: "directionalMovementSystem",
+        single: None,
+        bulk: Some(Signature {
+            inputs: &["highs", "lows", "close"],
+            params: &["period", "constantModelType"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::integer("period", 14.0, 1.0),
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "SmoothedMovingAverage",
+            ),
+        ],
+        outputs: &["pdi", "ndi", "adx", "adxr"],
+    },
+    IndicatorSpec {
+        module: "volatilityIndicators",
+        name: "ulcerIndex",
+        single: Some(Signature {
+            inputs: &["prices"],
+            params: &[],
+            optional: &[],
+        }),
+        bulk: Some(Signature {
+            inputs: &["prices"],
+            params: &["period"],
+            optional: &[],
+        }),
+        params: &[ParamSpec::integer("period", 14.0, 1.0)],
+        outputs: &["ulcerIndex"],
+    },
+    IndicatorSpec {
+        module: "volatilityIndicators",
+        name: "volatilitySystem",
+        single: None,
+        bulk: Some(Signature {
+            inputs: &["high", "low", "close"],
+            params: &["period", "constantMultiplier", "constantModelType"],
+            optional: &[],
+        }),
+        params: &[
+            ParamSpec::integer("period", 14.0, 1.0),
+            ParamSpec::number("constantMultiplier", Some(3.0), Some(0.0), None),
+            ParamSpec::choice(
+                "constantModelType",
+                ParamType::ConstantModelType,
+                "SmoothedMovingAverage",
+            ),
+        ],
+        outputs: &["volatilitySystem"],
+    },
+];
@@ -1,84 +1,132 @@
-use js_sys::Array;
+use crate::columns::columns;
+use crate::error::{
+    check_exact_length, check_min_length, check_not_empty, check_period, IndicatorResult,
+};
+use crate::missing::bulk;
+use crate::points::{BandPoint, MacdPoint};
+use js_sys::{Array, Object};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsValue;
 
 // -------- SINGLE --------
 
 #[wasm_bindgen(js_name = standard_single_simpleMovingAverage)]
-pub fn standard_single_simple_moving_average(prices: Vec<f64>) -> f64 {
-    rust_ti::standard_indicators::single::simple_moving_average(&prices)
+pub fn standard_single_simple_moving_average(prices: &[f64]) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    Ok(rust_ti::standard_indicators::single::simple_moving_average(
+        prices,
+    ))
 }
 
 #[wasm_bindgen(js_name = standard_single_smoothedMovingAverage)]
-pub fn standard_single_smoothed_moving_average(prices: Vec<f64>) -> f64 {
-    rust_ti::standard_indicators::single::smoothed_moving_average(&prices)
+pub fn standard_single_smoothed_moving_average(prices: &[f64]) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    Ok(rust_ti::standard_indicators::single::smoothed_moving_average(prices))
 }
 
 #[wasm_bindgen(js_name = standard_single_exponentialMovingAverage)]
-pub fn standard_single_exponential_moving_average(prices: Vec<f64>) -> f64 {
-    rust_ti::standard_indicators::single::exponential_moving_average(&prices)
+pub fn standard_single_exponential_moving_average(prices: &[f64]) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    Ok(rust_ti::standard_indicators::single::exponential_moving_average(prices))
+}
+
+fn bollinger_bands_value(prices: &[f64]) -> IndicatorResult<(f64, f64, f64)> {
+    check_exact_length("prices", prices.len(), 20)?;
+    Ok(rust_ti::standard_indicators::single::bollinger_bands(
+        prices,
+    ))
 }
 
 #[wasm_bindgen(js_name = standard_single_bollingerBands)]
-pub fn standard_single_bollinger_bands(prices: Vec<f64>) -> Array {
-    let (l, m, u) = rust_ti::standard_indicators::single::bollinger_bands(&prices);
+pub fn standard_single_bollinger_bands(prices: &[f64]) -> IndicatorResult<Array> {
+    let (l, m, u) = bollinger_bands_value(prices)?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(l));
     arr.push(&JsValue::from_f64(m));
     arr.push(&JsValue::from_f64(u));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = standard_single_bollingerBandsPoint)]
+pub fn standard_single_bollinger_bands_point(prices: &[f64]) -> IndicatorResult<BandPoint> {
+    Ok(bollinger_bands_value(prices)?.into())
+}
+
+fn macd_value(prices: &[f64]) -> IndicatorResult<(f64, f64, f64)> {
+    check_exact_length("prices", prices.len(), 34)?;
+    Ok(rust_ti::standard_indicators::single::macd(prices))
 }
 
 #[wasm_bindgen(js_name = standard_single_macd)]
-pub fn standard_single_macd(prices: Vec<f64>) -> Array {
-    let (macd, signal, hist) = rust_ti::standard_indicators::single::macd(&prices);
+pub fn standard_single_macd(prices: &[f64]) -> IndicatorResult<Array> {
+    let (macd, signal, hist) = macd_value(prices)?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(macd));
     arr.push(&JsValue::from_f64(signal));
     arr.push(&JsValue::from_f64(hist));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = standard_single_macdPoint)]
+pub fn standard_single_macd_point(prices: &[f64]) -> IndicatorResult<MacdPoint> {
+    Ok(macd_value(prices)?.into())
 }
 
 #[wasm_bindgen(js_name = standard_single_rsi)]
-pub fn standard_single_rsi(prices: Vec<f64>) -> f64 {
-    rust_ti::standard_indicators::single::rsi(&prices)
+pub fn standard_single_rsi(prices: &[f64]) -> IndicatorResult<f64> {
+    check_exact_length("prices", prices.len(), 14)?;
+    Ok(rust_ti::standard_indicators::single::rsi(prices))
 }
 
 // -------- BULK --------
 
 #[wasm_bindgen(js_name = standard_bulk_simpleMovingAverage)]
-pub fn standard_bulk_simple_moving_average(prices: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::standard_indicators::bulk::simple_moving_average(&prices, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn standard_bulk_simple_moving_average(
+    prices: &[f64],
+    period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        Ok(rust_ti::standard_indicators::bulk::simple_moving_average(
+            prices, period,
+        ))
+    })
 }
 
 #[wasm_bindgen(js_name = standard_bulk_smoothedMovingAverage)]
-pub fn standard_bulk_smoothed_moving_average(prices: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::standard_indicators::bulk::smoothed_moving_average(&prices, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn standard_bulk_smoothed_moving_average(
+    prices: &[f64],
+    period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        Ok(rust_ti::standard_indicators::bulk::smoothed_moving_average(
+            prices, period,
+        ))
+    })
 }
 
 #[wasm_bindgen(js_name = standard_bulk_exponentialMovingAverage)]
-pub fn standard_bulk_exponential_moving_average(prices: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::standard_indicators::bulk::exponential_moving_average(&prices, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn standard_bulk_exponential_moving_average(
+    prices: &[f64],
+    period: usize,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        Ok(rust_ti::standard_indicators::bulk::exponential_moving_average(prices, period))
+    })
+}
+
+fn bollinger_bands_rows(prices: &[f64]) -> IndicatorResult<Vec<(f64, f64, f64)>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_min_length("prices", prices.len(), 20)?;
+        Ok(rust_ti::standard_indicators::bulk::bollinger_bands(prices))
+    })
 }
 
 #[wasm_bindgen(js_name = standard_bulk_bollingerBands)]
-pub fn standard_bulk_bollinger_bands(prices: Vec<f64>) -> Array {
-    let data = rust_ti::standard_indicators::bulk::bollinger_bands(&prices);
+pub fn standard_bulk_bollinger_bands(prices: &[f64]) -> IndicatorResult<Array> {
+    let data = bollinger_bands_rows(prices)?;
     let out = Array::new();
     for (l, m, u) in data {
         let t = Array::new();
@@ -87,12 +135,28 @@ pub fn standard_bulk_bollinger_bands(prices: Vec<f64>) -> Array {
         t.push(&JsValue::from_f64(u));
         out.push(&t);
     }
-    out
+    Ok(out)
+}
+
+#[wasm_bindgen(js_name = standard_bulk_bollingerBandsColumns)]
+pub fn standard_bulk_bollinger_bands_columns(prices: &[f64]) -> IndicatorResult<Object> {
+    let data = bollinger_bands_rows(prices)?;
+    Ok(columns(
+        ["lower", "middle", "upper"],
+        data.into_iter().map(|(l, m, u)| [l, m, u]),
+    ))
+}
+
+fn macd_rows(prices: &[f64]) -> IndicatorResult<Vec<(f64, f64, f64)>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_min_length("prices", prices.len(), 34)?;
+        Ok(rust_ti::standard_indicators::bulk::macd(prices))
+    })
 }
 
 #[wasm_bindgen(js_name = standard_bulk_macd)]
-pub fn standard_bulk_macd(prices: Vec<f64>) -> Array {
-    let data = rust_ti::standard_indicators::bulk::macd(&prices);
+pub fn standard_bulk_macd(prices: &[f64]) -> IndicatorResult<Array> {
+    let data = macd_rows(prices)?;
     let out = Array::new();
     for (macd, signal, hist) in data {
         let t = Array::new();
@@ -101,15 +165,23 @@ pub fn standard_bulk_macd(prices: Vec<f64>) -> Array {
         t.push(&JsValue::from_f64(hist));
         out.push(&t);
     }
-    out
+    Ok(out)
+}
+
+#[wasm_bindgen(js_name = standard_bulk_macdColumns)]
+pub fn standard_bulk_macd_columns(prices: &[f64]) -> IndicatorResult<Object> {
+    let data = macd_rows(prices)?;
+    Ok(columns(
+        ["macd", "signal", "histogram"],
+        data.into_iter()
+            .map(|(macd, signal, hist)| [macd, signal, hist]),
+    ))
 }
 
 #[wasm_bindgen(js_name = standard_bulk_rsi)]
-pub fn standard_bulk_rsi(prices: Vec<f64>) -> Array {
-    let data = rust_ti::standard_indicators::bulk::rsi(&prices);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn standard_bulk_rsi(prices: &[f64]) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_min_length("prices", prices.len(), 14)?;
+        Ok(rust_ti::standard_indicators::bulk::rsi(prices))
+    })
 }
@@ -0,0 +1,436 @@
+use crate::averages::{ConstantModel, Family};
+use crate::error::{ErrorCode, IndicatorError, IndicatorResult};
+use crate::points::{
+    BandPoint, DirectionalMovementPoint, IchimokuPoint, McGinleyBandPoint, VolumeFlowPoint,
+};
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+// Serialized state of the streaming classes.
+//
+// `toState()` writes the whole state of a stream, including a forming bar given to `update`, as
+// a compact little-endian byte array: the format version, the class name and then every field in
+// declaration order. `fromState` checks the version and the class name before reading the fields
+// back, so a restarted service continues exactly where the saved stream stopped instead of
+// replaying its history. Bump `STATE_VERSION` whenever the fields of a stream change.
+
+/// Format version written by `toState()` and required by `fromState`.
+pub(crate) const STATE_VERSION: u8 = 5;
+
+pub(crate) trait State: Sized {
+    fn write(&self, out: &mut Vec<u8>);
+    fn read(state: &mut StateReader) -> IndicatorResult<Self>;
+}
+
+pub(crate) struct StateReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> StateReader<'a> {
+    fn take<const N: usize>(&mut self) -> IndicatorResult<[u8; N]> {
+        if self.bytes.len() < N {
+            return Err(invalid_state(
+                "ends before every field was read".to_string(),
+            ));
+        }
+        let (head, rest) = self.bytes.split_at(N);
+        self.bytes = rest;
+        Ok(head.try_into().expect("split at N"))
+    }
+
+    /// Fails on an enum tag outside `0..variants`.
+    pub(crate) fn tag(&mut self, variants: u8) -> IndicatorResult<u8> {
+        let [tag] = self.take::<1>()?;
+        if tag >= variants {
+            return Err(invalid_state(format!("has an unknown variant ({})", tag)));
+        }
+        Ok(tag)
+    }
+}
+
+pub(crate) fn invalid_state(reason: String) -> IndicatorError {
+    IndicatorError::new(
+        ErrorCode::InvalidState,
+        "state",
+        format!("state {}", reason),
+    )
+}
+
+/// `STATE_VERSION`, the class name and `value`.
+pub(crate) fn to_state<T: State>(class: &str, value: &T) -> Vec<u8> {
+    let mut out = vec![STATE_VERSION];
+    class.len().write(&mut out);
+    out.extend_from_slice(class.as_bytes());
+    value.write(&mut out);
+    out
+}
+
+/// Reads back what `to_state` wrote for `class`.
+pub(crate) fn from_state<T: State>(class: &str, bytes: &[u8]) -> IndicatorResult<T> {
+    let mut state = StateReader { bytes };
+    let [version] = state.take::<1>()?;
+    if version != STATE_VERSION {
+        return Err(IndicatorError::new(
+            ErrorCode::InvalidState,
+            "state",
+            format!(
+                "state format version ({}) is not the supported version ({})",
+                version, STATE_VERSION
+            ),
+        )
+        .with_value("version", version as f64)
+        .with_value("expected", STATE_VERSION as f64));
+    }
+    let length = usize::read(&mut state)?;
+    if state.bytes.len() < length || &state.bytes[..length] != class.as_bytes() {
+        let found = state.bytes.get(..length).unwrap_or(state.bytes);
+        return Err(invalid_state(format!(
+            "was written by {} instead of {}",
+            String::from_utf8_lossy(found),
+            class
+        )));
+    }
+    state.bytes = &state.bytes[length..];
+    let value = T::read(&mut state)?;
+    if !state.bytes.is_empty() {
+        return Err(invalid_state(format!(
+            "has {} bytes after the last field",
+            state.bytes.len()
+        )));
+    }
+    Ok(value)
+}
+
+/// Implements `State` for a struct by writing and reading `fields` in order.
+macro_rules! state_fields {
+    ($type:ident { $($field:ident),* $(,)? }) => {
+        impl crate::state::State for $type {
+            fn write(&self, out: &mut Vec<u8>) {
+                $(crate::state::State::write(&self.$field, out);)*
+            }
+
+            fn read(
+                state: &mut crate::state::StateReader,
+            ) -> crate::error::IndicatorResult<Self> {
+                Ok(Self {
+                    $($field: crate::state::State::read(state)?,)*
+                })
+            }
+        }
+    };
+}
+
+/// Implements `State` for a stream class from its `fields` and adds `toState()` / `fromState`.
+macro_rules! stream_state {
+    ($stream:ident { $($field:ident),* $(,)? }) => {
+        crate::state::state_fields!($stream { $($field),* });
+
+        #[wasm_bindgen]
+        impl $stream {
+            /// Whole state, including a forming bar given to `update`, as a versioned byte array
+            /// for `fromState`.
+            #[wasm_bindgen(js_name = toState)]
+            pub fn to_state(&self) -> Vec<u8> {
+                crate::state::to_state(stringify!($stream), self)
+            }
+
+            /// Stream restored from `toState()`, returning the same values from the next bar on.
+            /// Throws `InvalidState` for another format version, another class or a damaged state.
+            #[wasm_bindgen(js_name = fromState)]
+            pub fn from_state(state: &[u8]) -> crate::error::IndicatorResult<$stream> {
+                crate::state::from_state(stringify!($stream), state)
+            }
+        }
+    };
+}
+
+pub(crate) use {state_fields, stream_state};
+
+impl State for f64 {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok(f64::from_le_bytes(state.take()?))
+    }
+}
+
+impl State for i64 {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok(i64::from_le_bytes(state.take()?))
+    }
+}
+
+impl State for usize {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&(*self as u64).to_le_bytes());
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        let value = u64::from_le_bytes(state.take()?);
+        usize::try_from(value).map_err(|_| invalid_state(format!("has a bad length ({})", value)))
+    }
+}
+
+impl State for bool {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok(state.tag(2)? == 1)
+    }
+}
+
+impl<T: State> State for Option<T> {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.is_some().write(out);
+        if let Some(value) = self {
+            value.write(out);
+        }
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok(match bool::read(state)? {
+            true => Some(T::read(state)?),
+            false => None,
+        })
+    }
+}
+
+impl<T: State> State for Box<T> {
+    fn write(&self, out: &mut Vec<u8>) {
+        (**self).write(out);
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok(Box::new(T::read(state)?))
+    }
+}
+
+impl<A: State, B: State> State for (A, B) {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.0.write(out);
+        self.1.write(out);
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok((A::read(state)?, B::read(state)?))
+    }
+}
+
+impl<T: State> State for Vec<T> {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.len().write(out);
+        for value in self {
+            value.write(out);
+        }
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        let length = usize::read(state)?;
+        // Every value takes at least one byte, so a damaged length cannot over-allocate
+        let mut values = Vec::with_capacity(length.min(state.bytes.len()));
+        for _ in 0..length {
+            values.push(T::read(state)?);
+        }
+        Ok(values)
+    }
+}
+
+impl<T: State> State for VecDeque<T> {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.len().write(out);
+        for value in self {
+            value.write(out);
+        }
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok(Vec::read(state)?.into())
+    }
+}
+
+impl<T: State, const N: usize> State for [T; N] {
+    fn write(&self, out: &mut Vec<u8>) {
+        for value in self {
+            value.write(out);
+        }
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        let mut values = Vec::with_capacity(N);
+        for _ in 0..N {
+            values.push(T::read(state)?);
+        }
+        Ok(values.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+// Entries sorted by key, so the same state always gives the same bytes
+impl<K: State + Ord + Hash + Copy, V: State> State for HashMap<K, V> {
+    fn write(&self, out: &mut Vec<u8>) {
+        let mut keys: Vec<&K> = self.keys().collect();
+        keys.sort();
+        keys.len().write(out);
+        for key in keys {
+            key.write(out);
+            self[key].write(out);
+        }
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok(Vec::<(K, V)>::read(state)?.into_iter().collect())
+    }
+}
+
+impl State for crate::Position {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok(match state.tag(2)? {
+            0 => crate::Position::Long,
+            _ => crate::Position::Short,
+        })
+    }
+}
+
+impl State for crate::DeviationModel {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        use crate::DeviationModel::*;
+        Ok(match state.tag(8)? {
+            0 => StandardDeviation,
+            1 => MeanAbsoluteDeviation,
+            2 => MedianAbsoluteDeviation,
+            3 => ModeAbsoluteDeviation,
+            4 => UlcerIndex,
+            5 => LogStandardDeviation,
+            6 => LaplaceStdEquivalent,
+            _ => CauchyIQRScale,
+        })
+    }
+}
+
+impl State for rust_ti::ConstantModelType {
+    fn write(&self, out: &mut Vec<u8>) {
+        use rust_ti::ConstantModelType::*;
+        match self {
+            SimpleMovingAverage => out.push(0),
+            SmoothedMovingAverage => out.push(1),
+            ExponentialMovingAverage => out.push(2),
+            PersonalisedMovingAverage {
+                alpha_num,
+                alpha_den,
+            } => {
+                out.push(3);
+                alpha_num.write(out);
+                alpha_den.write(out);
+            }
+            SimpleMovingMedian => out.push(4),
+            SimpleMovingMode => out.push(5),
+        }
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        let tag = state.tag(6)?;
+        constant_model_type(tag, state)
+    }
+}
+
+fn constant_model_type(
+    tag: u8,
+    state: &mut StateReader,
+) -> IndicatorResult<rust_ti::ConstantModelType> {
+    use rust_ti::ConstantModelType::*;
+    Ok(match tag {
+        0 => SimpleMovingAverage,
+        1 => SmoothedMovingAverage,
+        2 => ExponentialMovingAverage,
+        3 => PersonalisedMovingAverage {
+            alpha_num: f64::read(state)?,
+            alpha_den: f64::read(state)?,
+        },
+        4 => SimpleMovingMedian,
+        _ => SimpleMovingMode,
+    })
+}
+
+impl State for Family {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok(family(state.tag(6)?))
+    }
+}
+
+fn family(tag: u8) -> Family {
+    match tag {
+        0 => Family::Weighted,
+        1 => Family::Hull,
+        2 => Family::DoubleExponential,
+        3 => Family::TripleExponential,
+        4 => Family::ZeroLagExponential,
+        _ => Family::Triangular,
+    }
+}
+
+// RustTI models are written with their own tags (0 to 5) and the families after them
+impl State for ConstantModel {
+    fn write(&self, out: &mut Vec<u8>) {
+        match self {
+            ConstantModel::RustTi(model) => model.write(out),
+            ConstantModel::Family(family) => out.push(6 + *family as u8),
+        }
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        Ok(match state.tag(12)? {
+            tag @ 0..=5 => ConstantModel::RustTi(constant_model_type(tag, state)?),
+            tag => ConstantModel::Family(family(tag - 6)),
+        })
+    }
+}
+
+state_fields!(BandPoint {
+    lower,
+    middle,
+    upper
+});
+state_fields!(McGinleyBandPoint {
+    lower,
+    mcginley,
+    upper
+});
+state_fields!(IchimokuPoint {
+    span_a,
+    span_b,
+    base,
+    conversion,
+    lagging_close
+});
+state_fields!(DirectionalMovementPoint {
+    pdi,
+    ndi,
+    adx,
+    adxr
+});
+state_fields!(VolumeFlowPoint {
+    on_balance_volume,
+    accumulation_distribution,
+    positive_volume_index,
+    negative_volume_index,
+    volume_price_trend
+});
@@ -0,0 +1,748 @@
+use crate::averages::{ConstantModel, Family};
+use crate::error::{check_same_length, IndicatorResult};
+use crate::points::{
+    BandPoint, DirectionalMovementPoint, IchimokuPoint, McGinleyBandPoint, VolumeFlowPoint,
+};
+use crate::state::{invalid_state, State, StateReader};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+// Shared state for the streaming (`*Stream`) classes.
+//
+// Streams take one bar per `push` and return the value the matching bulk function produces for
+// that bar, or NaN while the window is still filling, i.e. the bulk output with
+// `setAlignedOutput(true)`. To stay bit-identical to RustTI every value is computed with the same
+// operations in the same order as RustTI's single function over the window; what a stream saves is
+// re-sending and copying the window from JS on every tick, and any work that does not depend on
+// the order of the sum (weights, the median halves in O(log period), mode counts in O(1)) is kept
+// between pushes.
+//
+// A bar that is still forming is given to `update` instead: each stream keeps its state as of the
+// last closed bar in a `committed` field while an open bar is applied, and every `update` and the
+// closing `commit`/`push` start again from it, so a bar revised many times is only counted once.
+//
+// `extend` pushes a run of closed bars at once, so a stream doubles as the handle of a bulk result
+// that grows with the history: its outputs concatenated over every call are the bulk output over
+// all bars pushed so far.
+
+/// The latest `period` values, kept contiguous so RustTI's single functions can read them.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Window {
+    // Up to `2 * period` values; the window is the tail from `start`, and the evicted head is
+    // dropped once it reaches `period` values, so each push moves O(1) values on average. Grown
+    // as values arrive rather than reserved for the period
+    values: Vec<f64>,
+    period: usize,
+    start: usize,
+}
+
+impl Window {
+    pub(crate) fn new(period: usize) -> Self {
+        Self {
+            values: Vec::new(),
+            period,
+            start: 0,
+        }
+    }
+
+    pub(crate) fn period(&self) -> usize {
+        self.period
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.values.len() - self.start == self.period
+    }
+
+    /// Appends `value` and returns the value that dropped out of a full window.
+    pub(crate) fn push(&mut self, value: f64) -> Option<f64> {
+        let evicted = self.is_full().then(|| self.values[self.start]);
+        if evicted.is_some() {
+            self.start += 1;
+        }
+        if self.start == self.period {
+            self.values.drain(..self.start);
+            self.start = 0;
+        }
+        self.values.push(value);
+        evicted
+    }
+
+    /// Values from oldest to newest.
+    pub(crate) fn as_slice(&self) -> &[f64] {
+        &self.values[self.start..]
+    }
+
+    /// Values from oldest to newest.
+    pub(crate) fn iter(&self) -> impl DoubleEndedIterator<Item = f64> + '_ {
+        self.as_slice().iter().copied()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.values.clear();
+        self.start = 0;
+    }
+}
+
+/// Rolling maximum or minimum of the latest `period` values, ignoring NaN like RustTI's `max` and
+/// `min`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RollingExtreme {
+    // Candidates as (push index, value), from the current extreme to the newest value
+    candidates: VecDeque<(usize, f64)>,
+    period: usize,
+    pushed: usize,
+    maximum: bool,
+}
+
+impl RollingExtreme {
+    pub(crate) fn maximum(period: usize) -> Self {
+        Self::new(period, true)
+    }
+
+    pub(crate) fn minimum(period: usize) -> Self {
+        Self::new(period, false)
+    }
+
+    fn new(period: usize, maximum: bool) -> Self {
+        Self {
+            candidates: VecDeque::new(),
+            period,
+            pushed: 0,
+            maximum,
+        }
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.pushed >= self.period
+    }
+
+    /// Adds `value` and returns the extreme of the latest `period` values (NaN if all are NaN).
+    pub(crate) fn push(&mut self, value: f64) -> f64 {
+        if !value.is_nan() {
+            while let Some((_, last)) = self.candidates.back() {
+                let dominated = if self.maximum {
+                    *last <= value
+                } else {
+                    *last >= value
+                };
+                if !dominated {
+                    break;
+                }
+                self.candidates.pop_back();
+            }
+            self.candidates.push_back((self.pushed, value));
+        }
+        self.pushed += 1;
+        while let Some((index, _)) = self.candidates.front() {
+            if index + self.period >= self.pushed {
+                break;
+            }
+            self.candidates.pop_front();
+        }
+        self.candidates.front().map_or(f64::NAN, |(_, v)| *v)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.candidates.clear();
+        self.pushed = 0;
+    }
+}
+
+/// Rolling constant model (mean, weighted average, median, mode or a ti_engine moving average
+/// family) of the latest values.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct MovingConstant {
+    window: Window,
+    model: Model,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Model {
+    // Summed over the window on every push, in RustTI's order
+    Mean,
+    // Weight of the newest..oldest value and their sum, accumulated in RustTI's order
+    Weighted {
+        weights: Vec<f64>,
+        denominator: f64,
+    },
+    // Window values other than NaN: the lower half (with the middle value of an odd count) and
+    // the upper half
+    Median {
+        lower: Multiset,
+        upper: Multiset,
+    },
+    // Occurrences of each rounded value, and the sum and number of values per occurrence count
+    Mode {
+        counts: HashMap<i64, usize>,
+        levels: HashMap<usize, (i64, usize)>,
+        max: usize,
+    },
+    // Recomputed over the window on every push
+    Family(Family),
+}
+
+/// Multiset of non-NaN values ordered by `f64::total_cmp`, with O(log n) updates.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Multiset {
+    counts: BTreeMap<i64, usize>,
+    len: usize,
+}
+
+impl Multiset {
+    // Order-preserving key: `f64::total_cmp` on the bits
+    fn key(value: f64) -> i64 {
+        let bits = value.to_bits() as i64;
+        bits ^ (((bits >> 63) as u64) >> 1) as i64
+    }
+
+    fn value(key: i64) -> f64 {
+        f64::from_bits((key ^ (((key >> 63) as u64) >> 1) as i64) as u64)
+    }
+
+    fn insert(&mut self, value: f64) {
+        *self.counts.entry(Self::key(value)).or_insert(0) += 1;
+        self.len += 1;
+    }
+
+    fn remove(&mut self, value: f64) -> bool {
+        let key = Self::key(value);
+        let Some(count) = self.counts.get_mut(&key) else {
+            return false;
+        };
+        *count -= 1;
+        if *count == 0 {
+            self.counts.remove(&key);
+        }
+        self.len -= 1;
+        true
+    }
+
+    fn first(&self) -> Option<f64> {
+        self.counts.keys().next().map(|key| Self::value(*key))
+    }
+
+    fn last(&self) -> Option<f64> {
+        self.counts.keys().next_back().map(|key| Self::value(*key))
+    }
+
+    fn clear(&mut self) {
+        self.counts.clear();
+        self.len = 0;
+    }
+
+    // Moves values between the halves of a median until `lower` holds the middle value
+    fn balance(lower: &mut Multiset, upper: &mut Multiset) {
+        while lower.len > upper.len + 1 {
+            let moved = lower.last().expect("lower half is not empty");
+            lower.remove(moved);
+            upper.insert(moved);
+        }
+        while upper.len > lower.len {
+            let moved = upper.first().expect("upper half is not empty");
+            upper.remove(moved);
+            lower.insert(moved);
+        }
+    }
+}
+
+impl MovingConstant {
+    pub(crate) fn new(model: impl Into<ConstantModel>, period: usize) -> Self {
+        use rust_ti::ConstantModelType::*;
+        let model = match model.into() {
+            ConstantModel::RustTi(SimpleMovingAverage) => Model::Mean,
+            ConstantModel::RustTi(SmoothedMovingAverage) => Model::weighted(1.0, 0.0, period),
+            ConstantModel::RustTi(ExponentialMovingAverage) => Model::weighted(2.0, 1.0, period),
+            ConstantModel::RustTi(PersonalisedMovingAverage {
+                alpha_num,
+                alpha_den,
+            }) => Model::weighted(alpha_num, alpha_den, period),
+            ConstantModel::RustTi(SimpleMovingMedian) => Model::Median {
+                lower: Multiset::default(),
+                upper: Multiset::default(),
+            },
+            ConstantModel::RustTi(SimpleMovingMode) => Model::Mode {
+                counts: HashMap::new(),
+                levels: HashMap::new(),
+                max: 0,
+            },
+            ConstantModel::Family(family) => Model::Family(family),
+        };
+        Self {
+            window: Window::new(period),
+            model,
+        }
+    }
+
+    pub(crate) fn period(&self) -> usize {
+        self.window.period()
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.window.is_full()
+    }
+
+    /// Adds `value` and returns the model over the latest `period` values (NaN until full).
+    pub(crate) fn push(&mut self, value: f64) -> f64 {
+        let evicted = self.window.push(value);
+        self.model.push(value, evicted);
+        if !self.window.is_full() {
+            return f64::NAN;
+        }
+        let period = self.window.period();
+        match &self.model {
+            Model::Mean => self.window.iter().sum::<f64>() / period as f64,
+            Model::Weighted { .. } if period == 1 => value,
+            Model::Weighted {
+                weights,
+                denominator,
+            } => {
+                let mut sum = 0.0;
+                for (value, weight) in self.window.iter().rev().zip(weights) {
+                    sum += value * weight;
+                }
+                sum / denominator
+            }
+            Model::Median { lower, upper } => match (lower.last(), upper.first()) {
+                (None, _) => f64::NAN,
+                (Some(middle), Some(next)) if lower.len == upper.len => (middle + next) / 2.0,
+                (Some(middle), _) => middle,
+            },
+            Model::Mode { levels, max, .. } => {
+                let (sum, count) = levels[max];
+                sum as f64 / count as f64
+            }
+            Model::Family(family) => family.average(self.window.as_slice()),
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.window.clear();
+        self.model.clear();
+    }
+}
+
+impl Model {
+    fn weighted(alpha_num: f64, alpha_den: f64, period: usize) -> Self {
+        let alpha = alpha_num / (period as f64 + alpha_den);
+        let multiplicator = 1.0 - alpha;
+        let weights: Vec<f64> = (0..period)
+            .map(|index| multiplicator.powi(index as i32))
+            .collect();
+        let mut denominator = 0.0;
+        for weight in &weights {
+            denominator += weight;
+        }
+        Model::Weighted {
+            weights,
+            denominator,
+        }
+    }
+
+    // Updates the model for `value` added to the window and `evicted` dropped from it
+    fn push(&mut self, value: f64, evicted: Option<f64>) {
+        match self {
+            Model::Mean | Model::Weighted { .. } => {}
+            Model::Median { lower, upper } => {
+                if let Some(evicted) = evicted.filter(|v| !v.is_nan()) {
+                    if lower
+                        .last()
+                        .is_some_and(|middle| evicted.total_cmp(&middle).is_le())
+                    {
+                        lower.remove(evicted);
+                    } else {
+                        upper.remove(evicted);
+                    }
+                    Multiset::balance(lower, upper);
+                }
+                if !value.is_nan() {
+                    if lower
+                        .last()
+                        .is_none_or(|middle| value.total_cmp(&middle).is_le())
+                    {
+                        lower.insert(value);
+                    } else {
+                        upper.insert(value);
+                    }
+                    Multiset::balance(lower, upper);
+                }
+            }
+            Model::Mode {
+                counts,
+                levels,
+                max,
+            } => {
+                let key = value.round() as i64;
+                let count = counts.entry(key).or_insert(0);
+                Self::level(levels, *count, key, false);
+                *count += 1;
+                Self::level(levels, *count, key, true);
+                *max = (*max).max(*count);
+                if let Some(evicted) = evicted {
+                    let key = evicted.round() as i64;
+                    let count = counts.get_mut(&key).expect("evicted value was counted");
+                    Self::level(levels, *count, key, false);
+                    *count -= 1;
+                    Self::level(levels, *count, key, true);
+                    if *count == 0 {
+                        counts.remove(&key);
+                    }
+                    if levels.get(max).is_none_or(|(_, n)| *n == 0) {
+                        *max -= 1;
+                    }
+                }
+            }
+            Model::Family(_) => {}
+        }
+    }
+
+    fn clear(&mut self) {
+        match self {
+            Model::Mean | Model::Weighted { .. } => {}
+            Model::Median { lower, upper } => {
+                lower.clear();
+                upper.clear();
+            }
+            Model::Mode {
+                counts,
+                levels,
+                max,
+            } => {
+                counts.clear();
+                levels.clear();
+                *max = 0;
+            }
+            Model::Family(_) => {}
+        }
+    }
+
+    // Adds `key` to, or removes it from, the values occurring `count` times
+    fn level(levels: &mut HashMap<usize, (i64, usize)>, count: usize, key: i64, add: bool) {
+        if count == 0 {
+            return;
+        }
+        let (sum, n) = levels.entry(count).or_insert((0, 0));
+        if add {
+            *sum += key;
+            *n += 1;
+        } else {
+            *sum -= key;
+            *n -= 1;
+        }
+    }
+}
+
+/// Adds `commit` and `update` to a stream class. The class keeps a `committed: Option<Box<Self>>`
+/// field (None until `update` is called, cleared by `reset`) and its `push` starts with
+/// `self.revert_open_bar()`, so closing a bar replaces the forming one instead of adding to it.
+macro_rules! open_bar {
+    ($stream:ident, ($($arg:ident: $ty:ty),*) -> $out:ty) => {
+        #[wasm_bindgen]
+        impl $stream {
+            /// Adds a closed bar, replacing the forming bar given to `update` if there is one. Same
+            /// as `push`.
+            pub fn commit(&mut self, $($arg: $ty),*) -> $out {
+                self.push($($arg),*)
+            }
+
+            /// Revises the still-forming latest bar: recomputes from the state after the last
+            /// committed bar, so repeated updates within one bar are never counted twice. Getters
+            /// reflect the revised bar until the next `update`, `commit`/`push` or `reset`.
+            pub fn update(&mut self, $($arg: $ty),*) -> $out {
+                let committed = match self.committed.take() {
+                    Some(committed) => *committed,
+                    None => self.clone(),
+                };
+                *self = committed.clone();
+                let value = self.push($($arg),*);
+                self.committed = Some(Box::new(committed));
+                value
+            }
+        }
+
+        impl $stream {
+            // Goes back to the last committed bar, dropping the forming one
+            fn revert_open_bar(&mut self) {
+                if let Some(committed) = self.committed.take() {
+                    *self = *committed;
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use open_bar;
+
+/// Adds `extend` to a stream class, taking one array per `push` argument (named after the bulk
+/// function's inputs) and returning `$js`: `Vec<f64>` for numbers or the `*Columns` object for
+/// points.
+macro_rules! extend {
+    ($stream:ident, ($($values:ident),*) -> $js:ty) => {
+        #[wasm_bindgen]
+        impl $stream {
+            /// Pushes a run of closed bars, one array per `push` argument, and returns only their
+            /// outputs as the bulk function does: warm-up bars are dropped, or NaN with
+            /// `setAlignedOutput(true)`. Nothing is pushed if any bar is rejected.
+            pub fn extend(&mut self, $($values: &[f64]),*) -> crate::error::IndicatorResult<$js> {
+                let bars = crate::stream::bars(&[$((stringify!($values), $values)),*])?;
+                crate::stream::extend_bars(
+                    self,
+                    bars,
+                    |stream, bar| stream.push($($values[bar]),*),
+                    Self::is_ready,
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use extend;
+
+/// Number of bars in equally long `inputs`.
+pub(crate) fn bars(inputs: &[(&'static str, &[f64])]) -> IndicatorResult<usize> {
+    let (reference, first) = inputs[0];
+    for (argument, values) in &inputs[1..] {
+        check_same_length(reference, first.len(), argument, values.len())?;
+    }
+    Ok(first.len())
+}
+
+/// Pushes `bars` bars into a copy of `stream`, which replaces it once every bar was accepted, and
+/// returns the outputs of the bars where `is_ready` holds (all of them when output is aligned).
+pub(crate) fn extend_bars<S: Clone, T: Extended, R: Pushed<T>>(
+    stream: &mut S,
+    bars: usize,
+    mut push: impl FnMut(&mut S, usize) -> R,
+    is_ready: impl Fn(&S) -> bool,
+) -> IndicatorResult<T::Js> {
+    let aligned = crate::align::is_aligned_output();
+    let mut next = stream.clone();
+    let mut values = Vec::with_capacity(bars);
+    for bar in 0..bars {
+        let value = push(&mut next, bar).pushed()?;
+        if aligned || is_ready(&next) {
+            values.push(value);
+        }
+    }
+    *stream = next;
+    Ok(T::to_js(values))
+}
+
+/// What `push` returns, with or without validation.
+pub(crate) trait Pushed<T> {
+    fn pushed(self) -> IndicatorResult<T>;
+}
+
+impl<T: Extended> Pushed<T> for T {
+    fn pushed(self) -> IndicatorResult<T> {
+        Ok(self)
+    }
+}
+
+impl<T: Extended> Pushed<T> for IndicatorResult<T> {
+    fn pushed(self) -> IndicatorResult<T> {
+        self
+    }
+}
+
+/// JS value `extend` returns for the outputs of a run of bars.
+pub(crate) trait Extended: Sized {
+    type Js;
+    fn to_js(values: Vec<Self>) -> Self::Js;
+}
+
+impl Extended for f64 {
+    type Js = Vec<f64>;
+
+    fn to_js(values: Vec<Self>) -> Vec<f64> {
+        values
+    }
+}
+
+// Points become the object of the matching `*Columns` bulk function
+macro_rules! extended_columns {
+    ($point:ident, [$($name:literal: $field:ident),*]) => {
+        impl Extended for $point {
+            type Js = js_sys::Object;
+
+            fn to_js(values: Vec<Self>) -> js_sys::Object {
+                crate::columns::columns([$($name),*], values.iter().map(|p| [$(p.$field),*]))
+            }
+        }
+    };
+}
+
+extended_columns!(BandPoint, ["lower": lower, "middle": middle, "upper": upper]);
+extended_columns!(McGinleyBandPoint, ["lower": lower, "mcginley": mcginley, "upper": upper]);
+extended_columns!(
+    IchimokuPoint,
+    [
+        "spanA": span_a,
+        "spanB": span_b,
+        "base": base,
+        "conversion": conversion,
+        "displacedClose": lagging_close
+    ]
+);
+extended_columns!(
+    DirectionalMovementPoint,
+    ["pdi": pdi, "ndi": ndi, "adx": adx, "adxr": adxr]
+);
+extended_columns!(
+    VolumeFlowPoint,
+    [
+        "onBalanceVolume": on_balance_volume,
+        "accumulationDistribution": accumulation_distribution,
+        "positiveVolumeIndex": positive_volume_index,
+        "negativeVolumeIndex": negative_volume_index,
+        "volumePriceTrend": volume_price_trend
+    ]
+);
+
+// Restored state is checked, and whatever can be derived from a window is rebuilt from it rather
+// than read, so a damaged state is rejected instead of panicking on a later push
+
+// A period from 1 to the largest a constructor takes from JS (a u32)
+fn read_period(state: &mut StateReader, what: &str) -> IndicatorResult<usize> {
+    let period = usize::read(state)?;
+    if period == 0 || period > u32::MAX as usize {
+        return Err(invalid_state(format!(
+            "has a {} with a period of {}",
+            what, period
+        )));
+    }
+    Ok(period)
+}
+
+impl State for Window {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.period.write(out);
+        self.as_slice().to_vec().write(out);
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        let period = read_period(state, "window")?;
+        let values = Vec::<f64>::read(state)?;
+        if values.len() > period {
+            return Err(invalid_state(format!(
+                "has a window of {} values for a period of {}",
+                values.len(),
+                period
+            )));
+        }
+        Ok(Window {
+            values,
+            period,
+            start: 0,
+        })
+    }
+}
+
+impl State for RollingExtreme {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.candidates.write(out);
+        self.period.write(out);
+        self.pushed.write(out);
+        self.maximum.write(out);
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        let extreme = Self {
+            candidates: State::read(state)?,
+            period: read_period(state, "rolling extreme")?,
+            pushed: State::read(state)?,
+            maximum: State::read(state)?,
+        };
+        // Candidates are the pushes still in the window, each value beyond the newer ones
+        let mut newer: Option<(usize, f64)> = None;
+        for (index, value) in extreme.candidates.iter().rev().copied() {
+            let in_window =
+                index < extreme.pushed && index.saturating_add(extreme.period) >= extreme.pushed;
+            let ordered = newer.is_none_or(|(newer_index, newer_value)| {
+                index < newer_index
+                    && if extreme.maximum {
+                        value > newer_value
+                    } else {
+                        value < newer_value
+                    }
+            });
+            if value.is_nan() || !in_window || !ordered {
+                return Err(invalid_state(
+                    "has rolling extreme candidates out of order or outside the window".to_string(),
+                ));
+            }
+            newer = Some((index, value));
+        }
+        Ok(extreme)
+    }
+}
+
+impl State for MovingConstant {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.window.write(out);
+        self.model.write(out);
+    }
+
+    fn read(state: &mut StateReader) -> IndicatorResult<Self> {
+        let window = Window::read(state)?;
+        let model = Model::read(state, &window)?;
+        Ok(Self { window, model })
+    }
+}
+
+// Median halves and mode counts are not written: they are rebuilt from the window
+impl Model {
+    fn write(&self, out: &mut Vec<u8>) {
+        match self {
+            Model::Mean => out.push(0),
+            Model::Weighted { weights, .. } => {
+                out.push(1);
+                weights.write(out);
+            }
+            Model::Median { .. } => out.push(2),
+            Model::Mode { .. } => out.push(3),
+            Model::Family(family) => {
+                out.push(4);
+                family.write(out);
+            }
+        }
+    }
+
+    fn read(state: &mut StateReader, window: &Window) -> IndicatorResult<Self> {
+        let mut model = match state.tag(5)? {
+            0 => Model::Mean,
+            1 => {
+                let weights = Vec::<f64>::read(state)?;
+                if weights.len() != window.period() {
+                    return Err(invalid_state(format!(
+                        "has {} weights for a period of {}",
+                        weights.len(),
+                        window.period()
+                    )));
+                }
+                let mut denominator = 0.0;
+                for weight in &weights {
+                    denominator += weight;
+                }
+                Model::Weighted {
+                    weights,
+                    denominator,
+                }
+            }
+            2 => Model::Median {
+                lower: Multiset::default(),
+                upper: Multiset::default(),
+            },
+            3 => Model::Mode {
+                counts: HashMap::new(),
+                levels: HashMap::new(),
+                max: 0,
+            },
+            _ => Model::Family(State::read(state)?),
+        };
+        if matches!(model, Model::Median { .. } | Model::Mode { .. }) {
+            for value in window.iter() {
+                model.push(value, None);
+            }
+        }
+        Ok(model)
+    }
+}
@@ -1,6 +1,9 @@
-use js_sys::Array;
+use crate::error::{
+    check_min_length, check_not_empty, check_period, check_same_length, IndicatorError,
+    IndicatorResult,
+};
+use crate::missing::{bulk, bulk_seeded};
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsValue;
 
 // -------- SINGLE --------
 
@@ -36,101 +39,142 @@ pub fn strength_single_volume_index(
 
 #[wasm_bindgen(js_name = strength_single_relativeVigorIndex)]
 pub fn strength_single_relative_vigor_index(
-    open: Vec<f64>,
-    high: Vec<f64>,
-    low: Vec<f64>,
-    close: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
-) -> f64 {
-    rust_ti::strength_indicators::single::relative_vigor_index(
-        &open,
-        &high,
-        &low,
-        &close,
-        constant_model_type.into(),
-    )
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<f64> {
+    check_not_empty("open", open)?;
+    check_same_length("open", open.len(), "high", high.len())?;
+    check_same_length("open", open.len(), "low", low.len())?;
+    check_same_length("open", open.len(), "close", close.len())?;
+    check_min_length("open", open.len(), 4)?;
+    let constant_model_type =
+        crate::constant_model_type("constantModelType", &constant_model_type)?;
+    Ok(rust_ti::strength_indicators::single::relative_vigor_index(
+        open,
+        high,
+        low,
+        close,
+        constant_model_type,
+    ))
 }
 
 // -------- BULK --------
 
 #[wasm_bindgen(js_name = strength_bulk_accumulationDistribution)]
 pub fn strength_bulk_accumulation_distribution(
-    high: Vec<f64>,
-    low: Vec<f64>,
-    close: Vec<f64>,
-    volume: Vec<f64>,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    volume: &[f64],
     previous_accumulation_distribution: f64,
-) -> Array {
-    let data = rust_ti::strength_indicators::bulk::accumulation_distribution(
-        &high,
-        &low,
-        &close,
-        &volume,
+) -> IndicatorResult<Vec<f64>> {
+    bulk_seeded(
+        ["high", "low", "close", "volume"],
+        [high, low, close, volume],
         previous_accumulation_distribution,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+        |value| *value,
+        |[high, low, close, volume], previous_accumulation_distribution| {
+            check_not_empty("close", close)?;
+            check_same_length("close", close.len(), "high", high.len())?;
+            check_same_length("close", close.len(), "low", low.len())?;
+            check_same_length("close", close.len(), "volume", volume.len())?;
+            Ok(
+                rust_ti::strength_indicators::bulk::accumulation_distribution(
+                    high,
+                    low,
+                    close,
+                    volume,
+                    previous_accumulation_distribution,
+                ),
+            )
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = strength_bulk_positiveVolumeIndex)]
 pub fn strength_bulk_positive_volume_index(
-    close: Vec<f64>,
-    volume: Vec<f64>,
+    close: &[f64],
+    volume: &[f64],
     previous_positive_volume_index: f64,
-) -> Array {
-    let data = rust_ti::strength_indicators::bulk::positive_volume_index(
-        &close,
-        &volume,
+) -> IndicatorResult<Vec<f64>> {
+    bulk_seeded(
+        ["close", "volume"],
+        [close, volume],
         previous_positive_volume_index,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+        |value| *value,
+        |[close, volume], previous_positive_volume_index| {
+            check_not_empty("close", close)?;
+            check_same_length("close", close.len(), "volume", volume.len())?;
+            Ok(rust_ti::strength_indicators::bulk::positive_volume_index(
+                close,
+                volume,
+                previous_positive_volume_index,
+            ))
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = strength_bulk_negativeVolumeIndex)]
 pub fn strength_bulk_negative_volume_index(
-    close: Vec<f64>,
-    volume: Vec<f64>,
+    close: &[f64],
+    volume: &[f64],
     previous_negative_volume_index: f64,
-) -> Array {
-    let data = rust_ti::strength_indicators::bulk::negative_volume_index(
-        &close,
-        &volume,
+) -> IndicatorResult<Vec<f64>> {
+    bulk_seeded(
+        ["close", "volume"],
+        [close, volume],
         previous_negative_volume_index,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+        |value| *value,
+        |[close, volume], previous_negative_volume_index| {
+            check_not_empty("close", close)?;
+            check_same_length("close", close.len(), "volume", volume.len())?;
+            Ok(rust_ti::strength_indicators::bulk::negative_volume_index(
+                close,
+                volume,
+                previous_negative_volume_index,
+            ))
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = strength_bulk_relativeVigorIndex)]
 pub fn strength_bulk_relative_vigor_index(
-    open: Vec<f64>,
-    high: Vec<f64>,
-    low: Vec<f64>,
-    close: Vec<f64>,
-    constant_model_type: crate::ConstantModelType,
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    constant_model_type: crate::ConstantModelLike,
     period: usize,
-) -> Array {
-    let data = rust_ti::strength_indicators::bulk::relative_vigor_index(
-        &open,
-        &high,
-        &low,
-        &close,
-        constant_model_type.into(),
-        period,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(
+        ["open", "high", "low", "close"],
+        [open, high, low, close],
+        |[open, high, low, close]| {
+            check_not_empty("open", open)?;
+            check_same_length("open", open.len(), "high", high.len())?;
+            check_same_length("open", open.len(), "low", low.len())?;
+            check_same_length("open", open.len(), "close", close.len())?;
+            check_period("period", period, open.len())?;
+            if period < 4 {
+                return Err(IndicatorError::invalid_parameter(
+                    "period",
+                    period as f64,
+                    "must be at least 4",
+                ));
+            }
+            let constant_model_type =
+                crate::constant_model_type("constantModelType", &constant_model_type)?;
+            Ok(rust_ti::strength_indicators::bulk::relative_vigor_index(
+                open,
+                high,
+                low,
+                close,
+                constant_model_type,
+                period,
+            ))
+        },
+    )
 }
@@ -0,0 +1,188 @@
+use crate::batch::{copy_columns, get, parse_json, read_json, BatchSeries, Job, Output, Spec};
+use crate::error::{ErrorCode, IndicatorError, IndicatorResult};
+use crate::missing::{fill, get_missing_data, with_policy, MissingData};
+use js_sys::{Array, Float64Array, Object, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::{JsCast, JsValue};
+
+// Parameter sweeps.
+//
+// `sweep(series, spec)` runs one bulk indicator for every value (or pair of values) of one or two
+// parameters in a single WASM call. The candles are copied, the spec parsed and its input arrays
+// resolved once; a `ForwardFill` or `Interpolate` policy fills those inputs once too, and every
+// row then only propagates the gaps left. Each run sets its swept values on the shared job and
+// writes its row straight into one parameter × bar matrix. Rows are left-padded with NaN to the
+// number of bars, so column `j` is bar `j` whatever the period.
+//
+//     { indicator, params?, inputs?, sweep: { period: { start: 2, end: 200 }, ... } }
+//
+// `indicator`, `params`, `inputs` and `missingData` are read exactly like a `computeBatch` spec.
+
+/// Runs a single-output bulk indicator over every combination of the swept parameters.
+///
+/// Returns `{ values, shape, axes }`: `values` is the row-major matrix as one `Float64Array`,
+/// `shape` is `[axis lengths..., bars]` and `axes` lists each parameter name with its values.
+#[wasm_bindgen(js_name = sweep)]
+pub fn sweep(series: &JsValue, spec: &JsValue) -> IndicatorResult<Object> {
+    let columns = copy_columns(series)?;
+    run(&BatchSeries::borrow(&columns), spec)
+}
+
+pub(crate) fn run(series: &BatchSeries, spec: &JsValue) -> IndicatorResult<Object> {
+    let value = parse_json("spec", spec)?;
+    let spec: Spec = read_json("spec", &value)?;
+    let indicator = spec.indicator.as_str();
+    let mut job = Job::new(&spec, series, &[])?;
+    let axes = read_axes(&get(&value, "sweep"))?;
+    for (name, _) in &axes {
+        if !job.info().params.iter().any(|p| p.name == name) {
+            return Err(IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                "sweep",
+                format!("{} is not a parameter of {}", name, indicator),
+            ));
+        }
+    }
+
+    let mut policy = match spec.policy()? {
+        Some(policy) => policy,
+        None => get_missing_data(),
+    };
+    // Inputs that do not resolve here (e.g. candle positivity) are left to each run, which
+    // reports the error
+    let filled: Vec<Vec<f64>>;
+    if let Ok(inputs) = job.resolve() {
+        if matches!(policy, MissingData::ForwardFill | MissingData::Interpolate) {
+            filled = inputs.iter().map(|input| fill(policy, input)).collect();
+            job.prepare(filled.iter().map(Vec::as_slice).collect());
+            policy = MissingData::Propagate;
+        } else {
+            job.prepare(inputs);
+        }
+    }
+
+    let bars = series.length();
+    let rows: usize = axes.iter().map(|(_, values)| values.len()).product();
+    let mut matrix = Vec::with_capacity(rows * bars);
+    for row in 0..rows {
+        // Row-major: the last axis varies fastest
+        let mut rest = row;
+        for (name, values) in axes.iter().rev() {
+            job.set(name, values[rest % values.len()].clone());
+            rest /= values.len();
+        }
+        let output = match with_policy(policy, || job.run()) {
+            Ok(output) => output,
+            Err(e) => return Err(e.with_value("row", row as f64)),
+        };
+        let values = match output {
+            Output::Series(values) => values,
+            Output::Columns(_) => {
+                return Err(IndicatorError::new(
+                    ErrorCode::InvalidParameter,
+                    "indicator",
+                    format!(
+                        "{} has more than one output; sweep needs a single-output indicator",
+                        indicator
+                    ),
+                ))
+            }
+        };
+        let start = values.len().saturating_sub(bars);
+        matrix.resize(matrix.len() + bars - (values.len() - start), f64::NAN);
+        matrix.extend_from_slice(&values[start..]);
+    }
+
+    let shape = Array::new();
+    let axes_out = Array::new();
+    for (name, values) in &axes {
+        shape.push(&JsValue::from_f64(values.len() as f64));
+        let axis = Object::new();
+        let _ = Reflect::set(&axis, &"name".into(), &JsValue::from_str(name));
+        let _ = Reflect::set(&axis, &"values".into(), &values.iter().collect::<Array>());
+        axes_out.push(&axis);
+    }
+    shape.push(&JsValue::from_f64(bars as f64));
+    let out = Object::new();
+    let _ = Reflect::set(
+        &out,
+        &"values".into(),
+        &Float64Array::from(matrix.as_slice()),
+    );
+    let _ = Reflect::set(&out, &"shape".into(), &shape);
+    let _ = Reflect::set(&out, &"axes".into(), &axes_out);
+    Ok(out)
+}
+
+// Swept parameters in insertion order, each as a list of values or a `{ start, end, step }` range
+fn read_axes(sweep: &JsValue) -> IndicatorResult<Vec<(String, Vec<JsValue>)>> {
+    let keys = if sweep.is_object() {
+        Object::keys(sweep.unchecked_ref())
+    } else {
+        Array::new()
+    };
+    if keys.length() == 0 || keys.length() > 2 {
+        return Err(IndicatorError::new(
+            ErrorCode::InvalidParameter,
+            "sweep",
+            "sweep must name one or two parameters".to_string(),
+        ));
+    }
+    let mut axes = Vec::new();
+    for key in keys.iter() {
+        let name = key.as_string().unwrap_or_default();
+        let axis = get(sweep, &name);
+        let values: Vec<JsValue> = if Array::is_array(&axis) {
+            axis.unchecked_into::<Array>().iter().collect()
+        } else {
+            range(&axis)?.into_iter().map(JsValue::from_f64).collect()
+        };
+        if values.is_empty() {
+            return Err(IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                "sweep",
+                format!("sweep.{} has no values", name),
+            ));
+        }
+        axes.push((name, values));
+    }
+    Ok(axes)
+}
+
+// `start..=end` by `step` (default 1)
+fn range(axis: &JsValue) -> IndicatorResult<Vec<f64>> {
+    let bound = |name: &'static str| {
+        let value = get(axis, name);
+        value.as_f64().ok_or_else(|| {
+            IndicatorError::new(
+                ErrorCode::InvalidParameter,
+                name,
+                format!(
+                    "{} is required for a range, or pass a list of values instead",
+                    name
+                ),
+            )
+        })
+    };
+    let start = bound("start")?;
+    let end = bound("end")?;
+    let step = get(axis, "step").as_f64().unwrap_or(1.0);
+    if !(step.is_finite() && step > 0.0) {
+        return Err(IndicatorError::invalid_parameter(
+            "step",
+            step,
+            "must be a finite number greater than 0",
+        ));
+    }
+    crate::error::check_range("start", start, "end", end)?;
+    if !(start.is_finite() && end.is_finite()) {
+        return Err(IndicatorError::invalid_parameter(
+            "end",
+            end,
+            "must be a finite number",
+        ));
+    }
+    // Tolerate rounding so `{ start: 0.5, end: 3, step: 0.1 }` includes 3
+    let count = ((end - start) / step + 1e-9).floor() as usize + 1;
+    Ok((0..count).map(|i| start + i as f64 * step).collect())
+}
@@ -1,17 +1,26 @@
-use js_sys::Array;
+use crate::columns::columns;
+use crate::error::{
+    check_less_than, check_min_length, check_not_empty, check_period, check_period_nonzero,
+    check_same_length, IndicatorResult,
+};
+use crate::missing::{bulk, bulk_seeded};
+use crate::points::AroonPoint;
+use js_sys::{Array, Object};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsValue;
 
 // -------- SINGLE --------
 
 #[wasm_bindgen(js_name = trend_single_aroonUp)]
-pub fn trend_single_aroon_up(highs: Vec<f64>) -> f64 {
-    rust_ti::trend_indicators::single::aroon_up(&highs)
+pub fn trend_single_aroon_up(highs: &[f64]) -> IndicatorResult<f64> {
+    check_not_empty("highs", highs)?;
+    Ok(rust_ti::trend_indicators::single::aroon_up(highs))
 }
 
 #[wasm_bindgen(js_name = trend_single_aroonDown)]
-pub fn trend_single_aroon_down(lows: Vec<f64>) -> f64 {
-    rust_ti::trend_indicators::single::aroon_down(&lows)
+pub fn trend_single_aroon_down(lows: &[f64]) -> IndicatorResult<f64> {
+    check_not_empty("lows", lows)?;
+    Ok(rust_ti::trend_indicators::single::aroon_down(lows))
 }
 
 #[wasm_bindgen(js_name = trend_single_aroonOscillator)]
@@ -19,14 +28,30 @@ pub fn trend_single_aroon_oscillator(aroon_up: f64, aroon_down: f64) -> f64 {
     rust_ti::trend_indicators::single::aroon_oscillator(aroon_up, aroon_down)
 }
 
+fn aroon_indicator_value(highs: &[f64], lows: &[f64]) -> IndicatorResult<(f64, f64, f64)> {
+    check_not_empty("highs", highs)?;
+    check_same_length("highs", highs.len(), "lows", lows.len())?;
+    Ok(rust_ti::trend_indicators::single::aroon_indicator(
+        highs, lows,
+    ))
+}
+
 #[wasm_bindgen(js_name = trend_single_aroonIndicator)]
-pub fn trend_single_aroon_indicator(highs: Vec<f64>, lows: Vec<f64>) -> Array {
-    let (up, down, osc) = rust_ti::trend_indicators::single::aroon_indicator(&highs, &lows);
+pub fn trend_single_aroon_indicator(highs: &[f64], lows: &[f64]) -> IndicatorResult<Array> {
+    let (up, down, osc) = aroon_indicator_value(highs, lows)?;
     let arr = Array::new();
     arr.push(&JsValue::from_f64(up));
     arr.push(&JsValue::from_f64(down));
     arr.push(&JsValue::from_f64(osc));
-    arr
+    Ok(arr)
+}
+
+#[wasm_bindgen(js_name = trend_single_aroonIndicatorPoint)]
+pub fn trend_single_aroon_indicator_point(
+    highs: &[f64],
+    lows: &[f64],
+) -> IndicatorResult<AroonPoint> {
+    Ok(aroon_indicator_value(highs, lows)?.into())
 }
 
 #[wasm_bindgen(js_name = trend_single_longParabolicTimePriceSystem)]
@@ -76,54 +101,81 @@ pub fn trend_single_volume_price_trend(
 
 #[wasm_bindgen(js_name = trend_single_trueStrengthIndex)]
 pub fn trend_single_true_strength_index(
-    prices: Vec<f64>,
-    first_constant_model: crate::ConstantModelType,
+    prices: &[f64],
+    first_constant_model: crate::ConstantModelLike,
     first_period: usize,
-    second_constant_model: crate::ConstantModelType,
-) -> f64 {
-    rust_ti::trend_indicators::single::true_strength_index(
-        &prices,
-        first_constant_model.into(),
+    second_constant_model: crate::ConstantModelLike,
+) -> IndicatorResult<f64> {
+    check_period_nonzero("firstPeriod", first_period)?;
+    check_less_than("firstPeriod", first_period, "length", prices.len())?;
+    let first_constant_model =
+        crate::constant_model_type("firstConstantModel", &first_constant_model)?;
+    let second_constant_model =
+        crate::constant_model_type("secondConstantModel", &second_constant_model)?;
+    Ok(rust_ti::trend_indicators::single::true_strength_index(
+        prices,
+        first_constant_model,
         first_period,
-        second_constant_model.into(),
-    )
+        second_constant_model,
+    ))
 }
 
 // -------- BULK --------
 
 #[wasm_bindgen(js_name = trend_bulk_aroonUp)]
-pub fn trend_bulk_aroon_up(highs: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::trend_indicators::bulk::aroon_up(&highs, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn trend_bulk_aroon_up(highs: &[f64], period: usize) -> IndicatorResult<Vec<f64>> {
+    bulk(["highs"], [highs], |[highs]| {
+        check_period("period", period, highs.len())?;
+        Ok(rust_ti::trend_indicators::bulk::aroon_up(highs, period))
+    })
 }
 
 #[wasm_bindgen(js_name = trend_bulk_aroonDown)]
-pub fn trend_bulk_aroon_down(lows: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::trend_indicators::bulk::aroon_down(&lows, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn trend_bulk_aroon_down(lows: &[f64], period: usize) -> IndicatorResult<Vec<f64>> {
+    bulk(["lows"], [lows], |[lows]| {
+        check_period("period", period, lows.len())?;
+        Ok(rust_ti::trend_indicators::bulk::aroon_down(lows, period))
+    })
 }
 
 #[wasm_bindgen(js_name = trend_bulk_aroonOscillator)]
-pub fn trend_bulk_aroon_oscillator(aroon_up: Vec<f64>, aroon_down: Vec<f64>) -> Array {
-    let data = rust_ti::trend_indicators::bulk::aroon_oscillator(&aroon_up, &aroon_down);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn trend_bulk_aroon_oscillator(
+    aroon_up: &[f64],
+    aroon_down: &[f64],
+) -> IndicatorResult<Vec<f64>> {
+    bulk(
+        ["aroonUp", "aroonDown"],
+        [aroon_up, aroon_down],
+        |[aroon_up, aroon_down]| {
+            check_same_length("aroonUp", aroon_up.len(), "aroonDown", aroon_down.len())?;
+            Ok(rust_ti::trend_indicators::bulk::aroon_oscillator(
+                aroon_up, aroon_down,
+            ))
+        },
+    )
+}
+
+fn aroon_indicator_rows(
+    highs: &[f64],
+    lows: &[f64],
+    period: usize,
+) -> IndicatorResult<Vec<(f64, f64, f64)>> {
+    bulk(["highs", "lows"], [highs, lows], |[highs, lows]| {
+        check_same_length("highs", highs.len(), "lows", lows.len())?;
+        check_period("period", period, highs.len())?;
+        Ok(rust_ti::trend_indicators::bulk::aroon_indicator(
+            highs, lows, period,
+        ))
+    })
 }
 
 #[wasm_bindgen(js_name = trend_bulk_aroonIndicator)]
-pub fn trend_bulk_aroon_indicator(highs: Vec<f64>, lows: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::trend_indicators::bulk::aroon_indicator(&highs, &lows, period);
+pub fn trend_bulk_aroon_indicator(
+    highs: &[f64],
+    lows: &[f64],
+    period: usize,
+) -> IndicatorResult<Array> {
+    let data = aroon_indicator_rows(highs, lows, period)?;
     let out = Array::new();
     for (up, down, osc) in data {
         let t = Array::new();
@@ -132,50 +184,89 @@ pub fn trend_bulk_aroon_indicator(highs: Vec<f64>, lows: Vec<f64>, period: usize
         t.push(&JsValue::from_f64(osc));
         out.push(&t);
     }
-    out
+    Ok(out)
+}
+
+#[wasm_bindgen(js_name = trend_bulk_aroonIndicatorColumns)]
+pub fn trend_bulk_aroon_indicator_columns(
+    highs: &[f64],
+    lows: &[f64],
+    period: usize,
+) -> IndicatorResult<Object> {
+    let data = aroon_indicator_rows(highs, lows, period)?;
+    Ok(columns(
+        ["aroonUp", "aroonDown", "aroonOscillator"],
+        data.into_iter().map(|(up, down, osc)| [up, down, osc]),
+    ))
 }
 
 #[wasm_bindgen(js_name = trend_bulk_parabolicTimePriceSystem)]
 pub fn trend_bulk_parabolic_time_price_system(
-    highs: Vec<f64>,
-    lows: Vec<f64>,
+    highs: &[f64],
+    lows: &[f64],
     acceleration_factor_start: f64,
     acceleration_factor_max: f64,
     acceleration_factor_step: f64,
     start_position: crate::Position,
     previous_sar: f64,
-) -> Array {
-    let data = rust_ti::trend_indicators::bulk::parabolic_time_price_system(
-        &highs,
-        &lows,
-        acceleration_factor_start,
-        acceleration_factor_max,
-        acceleration_factor_step,
-        start_position.into(),
-        previous_sar,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["highs", "lows"], [highs, lows], |[highs, lows]| {
+        check_not_empty("highs", highs)?;
+        check_same_length("highs", highs.len(), "lows", lows.len())?;
+        Ok(
+            rust_ti::trend_indicators::bulk::parabolic_time_price_system(
+                highs,
+                lows,
+                acceleration_factor_start,
+                acceleration_factor_max,
+                acceleration_factor_step,
+                start_position.into(),
+                previous_sar,
+            ),
+        )
+    })
+}
+
+fn directional_movement_system_rows(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    period: usize,
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<Vec<(f64, f64, f64, f64)>> {
+    bulk(
+        ["highs", "lows", "close"],
+        [highs, lows, close],
+        |[highs, lows, close]| {
+            check_not_empty("highs", highs)?;
+            check_same_length("highs", highs.len(), "lows", lows.len())?;
+            check_same_length("highs", highs.len(), "close", close.len())?;
+            check_period_nonzero("period", period)?;
+            check_min_length("highs", highs.len(), 3 * period)?;
+            let constant_model_type =
+                crate::constant_model_type("constantModelType", &constant_model_type)?;
+            Ok(
+                rust_ti::trend_indicators::bulk::directional_movement_system(
+                    highs,
+                    lows,
+                    close,
+                    period,
+                    constant_model_type,
+                ),
+            )
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = trend_bulk_directionalMovementSystem)]
 pub fn trend_bulk_directional_movement_system(
-    highs: Vec<f64>,
-    lows: Vec<f64>,
-    close: Vec<f64>,
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
     period: usize,
-    constant_model_type: crate::ConstantModelType,
-) -> Array {
-    let data = rust_ti::trend_indicators::bulk::directional_movement_system(
-        &highs,
-        &lows,
-        &close,
-        period,
-        constant_model_type.into(),
-    );
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<Array> {
+    let data = directional_movement_system_rows(highs, lows, close, period, constant_model_type)?;
     let out = Array::new();
     for (pdi, ndi, adx, adxr) in data {
         let t = Array::new();
@@ -185,45 +276,71 @@ pub fn trend_bulk_directional_movement_system(
         t.push(&JsValue::from_f64(adxr));
         out.push(&t);
     }
-    out
+    Ok(out)
+}
+
+#[wasm_bindgen(js_name = trend_bulk_directionalMovementSystemColumns)]
+pub fn trend_bulk_directional_movement_system_columns(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    period: usize,
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<Object> {
+    let data = directional_movement_system_rows(highs, lows, close, period, constant_model_type)?;
+    Ok(columns(
+        ["pdi", "ndi", "adx", "adxr"],
+        data.into_iter()
+            .map(|(pdi, ndi, adx, adxr)| [pdi, ndi, adx, adxr]),
+    ))
 }
 
 #[wasm_bindgen(js_name = trend_bulk_volumePriceTrend)]
 pub fn trend_bulk_volume_price_trend(
-    prices: Vec<f64>,
-    volumes: Vec<f64>,
+    prices: &[f64],
+    volumes: &[f64],
     previous_volume_price_trend: f64,
-) -> Array {
-    let data = rust_ti::trend_indicators::bulk::volume_price_trend(
-        &prices,
-        &volumes,
+) -> IndicatorResult<Vec<f64>> {
+    bulk_seeded(
+        ["prices", "volumes"],
+        [prices, volumes],
         previous_volume_price_trend,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+        |value| *value,
+        |[prices, volumes], previous_volume_price_trend| {
+            check_min_length("prices", prices.len(), 2)?;
+            // One volume per price change
+            check_same_length("prices", prices.len() - 1, "volumes", volumes.len())?;
+            Ok(rust_ti::trend_indicators::bulk::volume_price_trend(
+                prices,
+                volumes,
+                previous_volume_price_trend,
+            ))
+        },
+    )
 }
 
 #[wasm_bindgen(js_name = trend_bulk_trueStrengthIndex)]
 pub fn trend_bulk_true_strength_index(
-    prices: Vec<f64>,
-    first_constant_model: crate::ConstantModelType,
+    prices: &[f64],
+    first_constant_model: crate::ConstantModelLike,
     first_period: usize,
-    second_constant_model: crate::ConstantModelType,
+    second_constant_model: crate::ConstantModelLike,
     second_period: usize,
-) -> Array {
-    let data = rust_ti::trend_indicators::bulk::true_strength_index(
-        &prices,
-        first_constant_model.into(),
-        first_period,
-        second_constant_model.into(),
-        second_period,
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period_nonzero("firstPeriod", first_period)?;
+        check_period_nonzero("secondPeriod", second_period)?;
+        check_min_length("prices", prices.len(), first_period + second_period)?;
+        let first_constant_model =
+            crate::constant_model_type("firstConstantModel", &first_constant_model)?;
+        let second_constant_model =
+            crate::constant_model_type("secondConstantModel", &second_constant_model)?;
+        Ok(rust_ti::trend_indicators::bulk::true_strength_index(
+            prices,
+            first_constant_model,
+            first_period,
+            second_constant_model,
+            second_period,
+        ))
+    })
 }
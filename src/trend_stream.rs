@@ -0,0 +1,404 @@
+use crate::error::{check_non_negative, check_period_nonzero, check_range, IndicatorResult};
+use crate::points::DirectionalMovementPoint;
+use crate::state::stream_state;
+use crate::stream::{extend, open_bar, MovingConstant, Window};
+use crate::Position;
+use rust_ti::basic_indicators::single::{max, min};
+use rust_ti::other_indicators::single::true_range;
+use rust_ti::trend_indicators::single::{
+    long_parabolic_time_price_system, short_parabolic_time_price_system,
+};
+use wasm_bindgen::prelude::*;
+
+// Streaming trend indicators.
+//
+// Like the other streams, `push` takes one bar and returns what the matching
+// `trendIndicators.bulk` function returns for it. The parabolic SAR keeps its position,
+// acceleration factor and the extremes since the last reversal instead of rescanning them; the
+// directional movement system keeps the directional movements and true ranges of the latest bars,
+// the moving constant of the DX and the ADX values the ADXR looks back over.
+
+/// Parabolic SAR (Wilder's parabolic time/price system) with automatic reversals, matching
+/// `trendIndicators.bulk.parabolicTimePriceSystem`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct ParabolicSarStream {
+    acceleration_factor_start: f64,
+    acceleration_factor_max: f64,
+    acceleration_factor_step: f64,
+    start_position: Position,
+    previous_sar: f64,
+    position: Position,
+    acceleration_factor: f64,
+    extreme_point: f64,
+    reversal: bool,
+    // Highest high and lowest low since the current position started, NaN ignored
+    highest: f64,
+    lowest: f64,
+    previous_high: f64,
+    previous_low: f64,
+    pushed: usize,
+    value: f64,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl ParabolicSarStream {
+    /// `startPosition` and `previousSar` seed the first bar like the bulk arguments (a
+    /// `previousSar` of 0 starts from the bar's low, or high when short). Throws unless
+    /// 0 <= `accelerationFactorStart` <= `accelerationFactorMax` and `accelerationFactorStep` >= 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        acceleration_factor_start: f64,
+        acceleration_factor_max: f64,
+        acceleration_factor_step: f64,
+        start_position: Position,
+        previous_sar: Option<f64>,
+    ) -> IndicatorResult<ParabolicSarStream> {
+        check_non_negative("accelerationFactorStart", acceleration_factor_start)?;
+        check_non_negative("accelerationFactorStep", acceleration_factor_step)?;
+        check_range(
+            "accelerationFactorStart",
+            acceleration_factor_start,
+            "accelerationFactorMax",
+            acceleration_factor_max,
+        )?;
+        Ok(Self {
+            acceleration_factor_start,
+            acceleration_factor_max,
+            acceleration_factor_step,
+            start_position,
+            previous_sar: previous_sar.unwrap_or(0.0),
+            position: start_position,
+            acceleration_factor: acceleration_factor_start,
+            extreme_point: f64::NAN,
+            reversal: false,
+            highest: f64::NAN,
+            lowest: f64::NAN,
+            previous_high: f64::NAN,
+            previous_low: f64::NAN,
+            pushed: 0,
+            value: f64::NAN,
+            committed: None,
+        })
+    }
+
+    /// Adds the next high and low and returns the SAR for that bar.
+    pub fn push(&mut self, high: f64, low: f64) -> f64 {
+        self.revert_open_bar();
+        // RustTI lowers the maximum slightly so float steps such as 0.02 * 10 still reach it
+        let acceleration_factor_max = self.acceleration_factor_max - 0.0000001;
+        self.reversal = false;
+        if self.pushed == 0 {
+            self.position = self.start_position;
+            self.acceleration_factor = self.acceleration_factor_start;
+            self.value = match self.position {
+                Position::Long => {
+                    self.extreme_point = high;
+                    let previous = if self.previous_sar == 0.0 {
+                        low
+                    } else {
+                        self.previous_sar
+                    };
+                    long_parabolic_time_price_system(previous, high, self.acceleration_factor, low)
+                }
+                Position::Short => {
+                    self.extreme_point = low;
+                    let previous = if self.previous_sar == 0.0 {
+                        high
+                    } else {
+                        self.previous_sar
+                    };
+                    short_parabolic_time_price_system(previous, low, self.acceleration_factor, high)
+                }
+            };
+        } else {
+            let previous_sar = self.value;
+            let previous_min = min(&[self.previous_low, low]);
+            let previous_max = max(&[self.previous_high, high]);
+            self.value = match self.position {
+                Position::Short if high > previous_sar => {
+                    let pivoted_sar = self.lowest;
+                    self.start(Position::Long, high);
+                    long_parabolic_time_price_system(
+                        pivoted_sar,
+                        high,
+                        self.acceleration_factor,
+                        previous_min,
+                    )
+                }
+                Position::Short => {
+                    self.extreme_point = self.lowest;
+                    if self.extreme_point > low {
+                        self.extreme_point = low;
+                        if self.acceleration_factor <= acceleration_factor_max {
+                            self.acceleration_factor += self.acceleration_factor_step;
+                        }
+                    }
+                    short_parabolic_time_price_system(
+                        previous_sar,
+                        self.extreme_point,
+                        self.acceleration_factor,
+                        previous_max,
+                    )
+                }
+                Position::Long if low < previous_sar => {
+                    let pivoted_sar = self.highest;
+                    self.start(Position::Short, low);
+                    short_parabolic_time_price_system(
+                        pivoted_sar,
+                        low,
+                        self.acceleration_factor,
+                        previous_max,
+                    )
+                }
+                Position::Long => {
+                    self.extreme_point = self.highest;
+                    if self.extreme_point < high {
+                        self.extreme_point = high;
+                        if self.acceleration_factor <= acceleration_factor_max {
+                            self.acceleration_factor += self.acceleration_factor_step;
+                        }
+                    }
+                    long_parabolic_time_price_system(
+                        previous_sar,
+                        self.extreme_point,
+                        self.acceleration_factor,
+                        previous_min,
+                    )
+                }
+            };
+        }
+        if !high.is_nan() {
+            self.highest = self.highest.max(high);
+        }
+        if !low.is_nan() {
+            self.lowest = self.lowest.min(low);
+        }
+        self.previous_high = high;
+        self.previous_low = low;
+        self.pushed += 1;
+        self.value
+    }
+
+    /// Latest SAR returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Position the latest SAR belongs to.
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Acceleration factor used for the latest SAR.
+    #[wasm_bindgen(getter, js_name = accelerationFactor)]
+    pub fn acceleration_factor(&self) -> f64 {
+        self.acceleration_factor
+    }
+
+    /// Extreme point used for the latest SAR: the highest high of a long position or the lowest
+    /// low of a short one.
+    #[wasm_bindgen(getter, js_name = extremePoint)]
+    pub fn extreme_point(&self) -> f64 {
+        self.extreme_point
+    }
+
+    /// Whether the latest bar reversed the position.
+    #[wasm_bindgen(getter)]
+    pub fn reversal(&self) -> bool {
+        self.reversal
+    }
+
+    /// Whether a bar has been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.pushed > 0
+    }
+
+    /// Forgets every pushed bar; the next bar starts from the constructor seed again.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.position = self.start_position;
+        self.acceleration_factor = self.acceleration_factor_start;
+        self.extreme_point = f64::NAN;
+        self.reversal = false;
+        self.highest = f64::NAN;
+        self.lowest = f64::NAN;
+        self.pushed = 0;
+        self.value = f64::NAN;
+    }
+
+    // Reverses into `position` at the current bar, whose extreme becomes the extreme point
+    fn start(&mut self, position: Position, extreme_point: f64) {
+        self.position = position;
+        self.acceleration_factor = self.acceleration_factor_start;
+        self.extreme_point = extreme_point;
+        self.reversal = true;
+        self.highest = f64::NAN;
+        self.lowest = f64::NAN;
+    }
+}
+
+open_bar!(ParabolicSarStream, (high: f64, low: f64) -> f64);
+extend!(ParabolicSarStream, (highs, lows) -> Vec<f64>);
+stream_state!(ParabolicSarStream {
+    acceleration_factor_start,
+    acceleration_factor_max,
+    acceleration_factor_step,
+    start_position,
+    previous_sar,
+    position,
+    acceleration_factor,
+    extreme_point,
+    reversal,
+    highest,
+    lowest,
+    previous_high,
+    previous_low,
+    pushed,
+    value,
+    committed
+});
+
+const NAN_DIRECTIONAL_MOVEMENT: (f64, f64, f64, f64) = (f64::NAN, f64::NAN, f64::NAN, f64::NAN);
+
+/// Directional movement system (+DI, -DI, ADX and ADXR) of the latest bars, matching
+/// `trendIndicators.bulk.directionalMovementSystem`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct DirectionalMovementSystemStream {
+    // +DM, -DM and true range of the latest `period` bars after the first; RustTI takes each
+    // bar's true range against its own close
+    positive_dm: Window,
+    negative_dm: Window,
+    true_range: Window,
+    // Moving constant of the DX, i.e. the ADX
+    adx: MovingConstant,
+    // Latest `period` ADX values; the ADXR averages the oldest and the newest
+    adx_history: Window,
+    previous_high: f64,
+    previous_low: f64,
+    pushed: usize,
+    value: DirectionalMovementPoint,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl DirectionalMovementSystemStream {
+    /// The first point is returned on bar `3 * period - 2`, like the first bulk value. Throws
+    /// unless `period` is greater than 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        period: usize,
+        constant_model_type: crate::ConstantModelLike,
+    ) -> IndicatorResult<DirectionalMovementSystemStream> {
+        check_period_nonzero("period", period)?;
+        let model = crate::constant_model_type("constantModelType", &constant_model_type)?;
+        Ok(Self {
+            positive_dm: Window::new(period),
+            negative_dm: Window::new(period),
+            true_range: Window::new(period),
+            adx: MovingConstant::new(model, period),
+            adx_history: Window::new(period),
+            previous_high: f64::NAN,
+            previous_low: f64::NAN,
+            pushed: 0,
+            value: NAN_DIRECTIONAL_MOVEMENT.into(),
+            committed: None,
+        })
+    }
+
+    /// Adds the next high, low and close and returns the latest point (NaN until `3 * period - 2`
+    /// bars).
+    pub fn push(&mut self, high: f64, low: f64, close: f64) -> DirectionalMovementPoint {
+        self.revert_open_bar();
+        self.pushed += 1;
+        let (previous_high, previous_low) = (self.previous_high, self.previous_low);
+        self.previous_high = high;
+        self.previous_low = low;
+        if self.pushed == 1 {
+            return self.value;
+        }
+        let high_diff = high - previous_high;
+        let low_diff = previous_low - low;
+        let (positive_dm, negative_dm) = if high_diff > 0.0 && high_diff > low_diff {
+            (high_diff, 0.0)
+        } else if low_diff > 0.0 && low_diff > high_diff {
+            (0.0, low_diff)
+        } else {
+            (0.0, 0.0)
+        };
+        self.positive_dm.push(positive_dm);
+        self.negative_dm.push(negative_dm);
+        self.true_range.push(true_range(close, high, low));
+        if !self.true_range.is_full() {
+            return self.value;
+        }
+        let tr_sum: f64 = self.true_range.iter().sum();
+        let positive_dm_sum: f64 = self.positive_dm.iter().sum();
+        let negative_dm_sum: f64 = self.negative_dm.iter().sum();
+        let pdi = (positive_dm_sum / tr_sum) * 100.0;
+        let ndi = (negative_dm_sum / tr_sum) * 100.0;
+        let dx = ((pdi - ndi).abs() / (pdi + ndi)) * 100.0;
+        let adx = self.adx.push(dx);
+        if !self.adx.is_full() {
+            return self.value;
+        }
+        self.adx_history.push(adx);
+        if self.adx_history.is_full() {
+            let adxr = (self.adx_history.as_slice()[0] + adx) / 2.0;
+            self.value = (pdi, ndi, adx, adxr).into();
+        }
+        self.value
+    }
+
+    /// Latest point returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> DirectionalMovementPoint {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> usize {
+        self.adx.period()
+    }
+
+    /// Whether `3 * period - 2` bars have been pushed.
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.adx_history.is_full()
+    }
+
+    /// Forgets every pushed bar.
+    pub fn reset(&mut self) {
+        self.committed = None;
+        self.positive_dm.clear();
+        self.negative_dm.clear();
+        self.true_range.clear();
+        self.adx.clear();
+        self.adx_history.clear();
+        self.pushed = 0;
+        self.value = NAN_DIRECTIONAL_MOVEMENT.into();
+    }
+}
+
+open_bar!(
+    DirectionalMovementSystemStream,
+    (high: f64, low: f64, close: f64) -> DirectionalMovementPoint
+);
+extend!(DirectionalMovementSystemStream, (highs, lows, close) -> js_sys::Object);
+stream_state!(DirectionalMovementSystemStream {
+    positive_dm,
+    negative_dm,
+    true_range,
+    adx,
+    adx_history,
+    previous_high,
+    previous_low,
+    pushed,
+    value,
+    committed
+});
@@ -1,46 +1,58 @@
-use js_sys::Array;
+use crate::error::{
+    check_min_length, check_not_empty, check_period, check_same_length, IndicatorResult,
+};
+use crate::missing::bulk;
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsValue;
 
 // -------- SINGLE --------
 
 #[wasm_bindgen(js_name = volatility_single_ulcerIndex)]
-pub fn volatility_single_ulcer_index(prices: Vec<f64>) -> f64 {
-    rust_ti::volatility_indicators::single::ulcer_index(&prices)
+pub fn volatility_single_ulcer_index(prices: &[f64]) -> IndicatorResult<f64> {
+    check_not_empty("prices", prices)?;
+    Ok(rust_ti::volatility_indicators::single::ulcer_index(prices))
 }
 
 // -------- BULK --------
 
 #[wasm_bindgen(js_name = volatility_bulk_ulcerIndex)]
-pub fn volatility_bulk_ulcer_index(prices: Vec<f64>, period: usize) -> Array {
-    let data = rust_ti::volatility_indicators::bulk::ulcer_index(&prices, period);
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+pub fn volatility_bulk_ulcer_index(prices: &[f64], period: usize) -> IndicatorResult<Vec<f64>> {
+    bulk(["prices"], [prices], |[prices]| {
+        check_period("period", period, prices.len())?;
+        Ok(rust_ti::volatility_indicators::bulk::ulcer_index(
+            prices, period,
+        ))
+    })
 }
 
 #[wasm_bindgen(js_name = volatility_bulk_volatilitySystem)]
 pub fn volatility_bulk_volatility_system(
-    high: Vec<f64>,
-    low: Vec<f64>,
-    close: Vec<f64>,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
     period: usize,
     constant_multiplier: f64,
-    constant_model_type: crate::ConstantModelType,
-) -> Array {
-    let data = rust_ti::volatility_indicators::bulk::volatility_system(
-        &high,
-        &low,
-        &close,
-        period,
-        constant_multiplier,
-        constant_model_type.into(),
-    );
-    let out = Array::new();
-    for v in data {
-        out.push(&JsValue::from_f64(v));
-    }
-    out
+    constant_model_type: crate::ConstantModelLike,
+) -> IndicatorResult<Vec<f64>> {
+    bulk(
+        ["high", "low", "close"],
+        [high, low, close],
+        |[high, low, close]| {
+            check_not_empty("close", close)?;
+            check_same_length("close", close.len(), "high", high.len())?;
+            check_same_length("close", close.len(), "low", low.len())?;
+            check_period("period", period, close.len())?;
+            // The SAR is seeded from the first two ATR windows
+            check_min_length("close", close.len(), period + 1)?;
+            let constant_model_type =
+                crate::constant_model_type("constantModelType", &constant_model_type)?;
+            Ok(rust_ti::volatility_indicators::bulk::volatility_system(
+                high,
+                low,
+                close,
+                period,
+                constant_multiplier,
+                constant_model_type,
+            ))
+        },
+    )
 }
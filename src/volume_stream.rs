@@ -0,0 +1,232 @@
+use crate::error::{IndicatorError, IndicatorResult};
+use crate::points::VolumeFlowPoint;
+use crate::state::{state_fields, stream_state};
+use crate::stream::{extend, open_bar};
+use js_sys::{Object, Reflect};
+use rust_ti::momentum_indicators::single::on_balance_volume;
+use rust_ti::strength_indicators::single::{accumulation_distribution, volume_index};
+use rust_ti::trend_indicators::single::volume_price_trend;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+// Streaming cumulative volume indicators.
+//
+// OBV, accumulation/distribution, the positive and negative volume indices and the volume price
+// trend are running totals: RustTI's single functions take the previous total and the previous
+// close or volume on every call. `VolumeFlowStream` keeps all of them, and the previous bar, in
+// WASM memory. `snapshot()` returns that state as a plain object that survives `JSON.stringify`,
+// and `VolumeFlowStream.fromSnapshot` continues from it as if the bars had never stopped.
+
+#[wasm_bindgen]
+extern "C" {
+    /// Running totals and previous bar of a `VolumeFlowStream`.
+    #[wasm_bindgen(
+        typescript_type = "{ onBalanceVolume?: number; accumulationDistribution?: number; positiveVolumeIndex?: number; negativeVolumeIndex?: number; volumePriceTrend?: number; previousClose?: number; previousVolume?: number }"
+    )]
+    pub type VolumeFlowSnapshot;
+}
+
+const NAN_VOLUME_FLOW: (f64, f64, f64, f64, f64) =
+    (f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN);
+
+// Running totals, and the close and volume of the bar they were last advanced with
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Totals {
+    on_balance_volume: f64,
+    accumulation_distribution: f64,
+    positive_volume_index: f64,
+    negative_volume_index: f64,
+    volume_price_trend: f64,
+    previous: Option<(f64, f64)>,
+}
+
+state_fields!(Totals {
+    on_balance_volume,
+    accumulation_distribution,
+    positive_volume_index,
+    negative_volume_index,
+    volume_price_trend,
+    previous
+});
+
+/// On-balance volume, accumulation/distribution, positive and negative volume index and volume
+/// price trend in one pass, matching `momentumIndicators.bulk.onBalanceVolume`,
+/// `strengthIndicators.bulk.accumulationDistribution`,
+/// `strengthIndicators.bulk.positiveVolumeIndex`, `strengthIndicators.bulk.negativeVolumeIndex`
+/// and `trendIndicators.bulk.volumePriceTrend`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct VolumeFlowStream {
+    seed: Totals,
+    totals: Totals,
+    ready: bool,
+    value: VolumeFlowPoint,
+    committed: Option<Box<Self>>,
+}
+
+#[wasm_bindgen]
+impl VolumeFlowStream {
+    /// The arguments seed the running totals like the `previous*` arguments of the bulk functions
+    /// (default 0).
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        previous_on_balance_volume: Option<f64>,
+        previous_accumulation_distribution: Option<f64>,
+        previous_positive_volume_index: Option<f64>,
+        previous_negative_volume_index: Option<f64>,
+        previous_volume_price_trend: Option<f64>,
+    ) -> VolumeFlowStream {
+        Self::from_totals(Totals {
+            on_balance_volume: previous_on_balance_volume.unwrap_or(0.0),
+            accumulation_distribution: previous_accumulation_distribution.unwrap_or(0.0),
+            positive_volume_index: previous_positive_volume_index.unwrap_or(0.0),
+            negative_volume_index: previous_negative_volume_index.unwrap_or(0.0),
+            volume_price_trend: previous_volume_price_trend.unwrap_or(0.0),
+            previous: None,
+        })
+    }
+
+    /// Continues from a `snapshot()`. Missing totals default to 0; without `previousClose` and
+    /// `previousVolume` the next bar is treated as the first one. Throws if a field is not a
+    /// number or only one of `previousClose` and `previousVolume` is given.
+    #[wasm_bindgen(js_name = fromSnapshot)]
+    pub fn from_snapshot(snapshot: &VolumeFlowSnapshot) -> IndicatorResult<VolumeFlowStream> {
+        if !snapshot.is_object() {
+            return Err(IndicatorError::invalid_parameter(
+                "snapshot",
+                f64::NAN,
+                "must be an object returned by VolumeFlowStream.snapshot()",
+            ));
+        }
+        let field = |name: &'static str| -> IndicatorResult<Option<f64>> {
+            let value = Reflect::get(snapshot, &JsValue::from_str(name)).unwrap_or_default();
+            if value.is_undefined() || value.is_null() {
+                return Ok(None);
+            }
+            value.as_f64().map(Some).ok_or_else(|| {
+                IndicatorError::invalid_parameter(name, f64::NAN, "must be a number")
+            })
+        };
+        let previous = match (field("previousClose")?, field("previousVolume")?) {
+            (Some(close), Some(volume)) => Some((close, volume)),
+            (None, None) => None,
+            (close, _) => {
+                let missing = if close.is_some() {
+                    "previousVolume"
+                } else {
+                    "previousClose"
+                };
+                return Err(IndicatorError::invalid_parameter(
+                    missing,
+                    f64::NAN,
+                    "must be given together with previousClose and previousVolume",
+                ));
+            }
+        };
+        Ok(Self::from_totals(Totals {
+            on_balance_volume: field("onBalanceVolume")?.unwrap_or(0.0),
+            accumulation_distribution: field("accumulationDistribution")?.unwrap_or(0.0),
+            positive_volume_index: field("positiveVolumeIndex")?.unwrap_or(0.0),
+            negative_volume_index: field("negativeVolumeIndex")?.unwrap_or(0.0),
+            volume_price_trend: field("volumePriceTrend")?.unwrap_or(0.0),
+            previous,
+        }))
+    }
+
+    /// Adds the next bar and returns the latest values. Accumulation/distribution starts on the
+    /// first bar; the others compare against the previous close or volume and are NaN until there
+    /// is one.
+    pub fn push(&mut self, high: f64, low: f64, close: f64, volume: f64) -> VolumeFlowPoint {
+        self.revert_open_bar();
+        let totals = &mut self.totals;
+        totals.accumulation_distribution =
+            accumulation_distribution(high, low, close, volume, totals.accumulation_distribution);
+        self.value.accumulation_distribution = totals.accumulation_distribution;
+        if let Some((previous_close, previous_volume)) = totals.previous {
+            totals.on_balance_volume =
+                on_balance_volume(close, previous_close, volume, totals.on_balance_volume);
+            if volume > previous_volume {
+                totals.positive_volume_index =
+                    volume_index(close, previous_close, totals.positive_volume_index);
+            }
+            if volume < previous_volume {
+                totals.negative_volume_index =
+                    volume_index(close, previous_close, totals.negative_volume_index);
+            }
+            totals.volume_price_trend =
+                volume_price_trend(close, previous_close, volume, totals.volume_price_trend);
+            self.value = (
+                totals.on_balance_volume,
+                totals.accumulation_distribution,
+                totals.positive_volume_index,
+                totals.negative_volume_index,
+                totals.volume_price_trend,
+            )
+                .into();
+            self.ready = true;
+        }
+        totals.previous = Some((close, volume));
+        self.value
+    }
+
+    /// Latest point returned by `push`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> VolumeFlowPoint {
+        self.value
+    }
+
+    /// Whether a bar has been pushed after the first one (or after a snapshot's previous bar).
+    #[wasm_bindgen(getter, js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    /// Running totals and the previous close and volume as of the last committed bar, as a plain
+    /// object for `VolumeFlowStream.fromSnapshot`.
+    pub fn snapshot(&self) -> VolumeFlowSnapshot {
+        let totals = &self.committed.as_deref().unwrap_or(self).totals;
+        let out = Object::new();
+        let mut fields = vec![
+            ("onBalanceVolume", totals.on_balance_volume),
+            ("accumulationDistribution", totals.accumulation_distribution),
+            ("positiveVolumeIndex", totals.positive_volume_index),
+            ("negativeVolumeIndex", totals.negative_volume_index),
+            ("volumePriceTrend", totals.volume_price_trend),
+        ];
+        if let Some((close, volume)) = totals.previous {
+            fields.push(("previousClose", close));
+            fields.push(("previousVolume", volume));
+        }
+        for (name, value) in fields {
+            let _ = Reflect::set(&out, &JsValue::from_str(name), &JsValue::from_f64(value));
+        }
+        out.unchecked_into()
+    }
+
+    /// Forgets every pushed bar; the totals start from the constructor seeds or snapshot again.
+    pub fn reset(&mut self) {
+        *self = Self::from_totals(self.seed);
+    }
+}
+
+open_bar!(VolumeFlowStream, (high: f64, low: f64, close: f64, volume: f64) -> VolumeFlowPoint);
+extend!(VolumeFlowStream, (highs, lows, close, volumes) -> js_sys::Object);
+stream_state!(VolumeFlowStream {
+    seed,
+    totals,
+    ready,
+    value,
+    committed
+});
+
+impl VolumeFlowStream {
+    fn from_totals(seed: Totals) -> Self {
+        Self {
+            seed,
+            totals: seed,
+            ready: false,
+            value: NAN_VOLUME_FLOW.into(),
+            committed: None,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+import { test, describe, before } from "node:test";
+import assert from "node:assert/strict";
+
+import init, {
+  computeBatch,
+  OhlcvSeries,
+  ConstantModelType,
+  MovingAverageType,
+  momentumIndicators,
+  movingAverage,
+  otherIndicators,
+  standardIndicators,
+  trendIndicators,
+} from "../index.node.js";
+
+before(async () => {
+  await init();
+});
+
+const close = Array.from({ length: 60 }, (_, i) => 100 + Math.sin(i / 4) * 5 + i * 0.1);
+const high = close.map((c) => c + 1);
+const low = close.map((c) => c - 1);
+const open = close.map((c, i) => (i === 0 ? c : close[i - 1]));
+const volume = close.map((_, i) => 1000 + i * 10);
+
+describe("computeBatch", () => {
+  test("matches the module functions", () => {
+    const out = computeBatch({ close, high, low }, [
+      { id: "rsi", indicator: "momentumIndicators.relativeStrengthIndex", params: { constantModelType: ConstantModelType.SmoothedMovingAverage, period: 14 } },
+      { indicator: "standardIndicators.bollingerBands" },
+      { id: "dms", indicator: "trendIndicators.directionalMovementSystem", params: { period: 5, constantModelType: "ExponentialMovingAverage" } },
+    ]);
+    assert.deepStrictEqual(
+      Array.from(out.rsi),
+      Array.from(momentumIndicators.bulk.relativeStrengthIndex(close, ConstantModelType.SmoothedMovingAverage, 14))
+    );
+    assert.deepStrictEqual(out["standardIndicators.bollingerBands"], standardIndicators.bulk.bollingerBandsColumns(close));
+    assert.deepStrictEqual(
+      out.dms,
+      trendIndicators.bulk.directionalMovementSystemColumns(high, low, close, 5, ConstantModelType.ExponentialMovingAverage)
+    );
+  });
+
+  test("accepts a JSON string and fills defaults from the registry", () => {
+    const specs = JSON.stringify([{ id: "macd", indicator: "momentumIndicators.macdLine" }]);
+    const out = computeBatch({ close }, specs);
+    assert.deepStrictEqual(
+      Array.from(out.macd),
+      Array.from(
+        momentumIndicators.bulk.macdLine(close, 12, ConstantModelType.ExponentialMovingAverage, 26, ConstantModelType.ExponentialMovingAverage)
+      )
+    );
+  });
+
+  test("feeds earlier results into later specs", () => {
+    const out = computeBatch({ close }, [
+      { id: "macd", indicator: "momentumIndicators.macdLine" },
+      { id: "signal", indicator: "momentumIndicators.signalLine", inputs: ["macd"], params: { period: 9 } },
+    ]);
+    assert.deepStrictEqual(
+      Array.from(out.signal),
+      Array.from(momentumIndicators.bulk.signalLine(out.macd, ConstantModelType.ExponentialMovingAverage, 9))
+    );
+  });
+
+  test("takes the input names of the registry", () => {
+    const SMA = ConstantModelType.SimpleMovingAverage;
+    const aligned = computeBatch({ open, close }, [{ id: "pi", indicator: "otherIndicators.positivityIndicator" }]);
+    const bulk = otherIndicators.bulk.positivityIndicatorColumns(open.slice(1), close.slice(0, -1), 5, SMA);
+    assert.deepStrictEqual(Array.from(aligned.pi.signal).slice(-bulk.signal.length), Array.from(bulk.signal));
+    const named = computeBatch({ open, close }, [
+      { id: "pi", indicator: "otherIndicators.positivityIndicator", inputs: ["open", "close"] },
+    ]);
+    assert.deepStrictEqual(named.pi, otherIndicators.bulk.positivityIndicatorColumns(open, close, 5, SMA));
+  });
+
+  test("reads the optional volume of a VolumeWeighted average", () => {
+    const out = computeBatch({ close, volume }, [
+      { id: "vwma", indicator: "movingAverage.movingAverage", params: { maType: "VolumeWeighted", period: 10 } },
+      { id: "sma", indicator: "movingAverage.movingAverage", params: { period: 10 } },
+      { id: "named", indicator: "movingAverage.movingAverage", inputs: ["close", "close"], params: { maType: "VolumeWeighted", period: 5 } },
+    ]);
+    assert.deepStrictEqual(Array.from(out.vwma), Array.from(movingAverage.bulk.volumeWeightedMovingAverage(close, volume, 10)));
+    assert.deepStrictEqual(Array.from(out.sma), Array.from(movingAverage.bulk.movingAverage(close, MovingAverageType.Simple, 10)));
+    assert.deepStrictEqual(Array.from(out.named), Array.from(movingAverage.bulk.volumeWeightedMovingAverage(close, close, 5)));
+    assert.throws(
+      () => computeBatch({ close }, [{ indicator: "movingAverage.movingAverage", params: { maType: "VolumeWeighted" } }]),
+      { name: "TiEngineError", code: "InvalidParameter" }
+    );
+  });
+
+  test("OhlcvSeries runs the same specs over resident columns", () => {
+    const series = new OhlcvSeries(open, high, low, close, volume);
+    const specs = [
+      { id: "atr", indicator: "otherIndicators.averageTrueRange", params: { period: 14 } },
+      { id: "obv", indicator: "momentumIndicators.onBalanceVolume" },
+    ];
+    assert.deepStrictEqual(series.computeBatch(specs), computeBatch({ open, high, low, close, volume }, specs));
+    series.free();
+  });
+
+  test("reports the failing spec", () => {
+    assert.throws(
+      () => computeBatch({ close }, [{ indicator: "momentumIndicators.macdLine" }, { indicator: "nope.nothing" }]),
+      (err) => err.code === "InvalidParameter" && err.values.spec === 1
+    );
+    assert.throws(
+      () => computeBatch({ close }, [{ indicator: "otherIndicators.averageTrueRange" }]),
+      (err) => err.argument === "series"
+    );
+    assert.throws(
+      () => computeBatch({ close }, [{ id: "a", indicator: "momentumIndicators.macdLine" }, { id: "a", indicator: "momentumIndicators.macdLine" }]),
+      (err) => err.argument === "id"
+    );
+    assert.throws(() => computeBatch({ close }, "{not json"), (err) => err.argument === "specs");
+    assert.throws(
+      () => computeBatch({ close }, [{ indicator: 5 }]),
+      (err) => err.code === "InvalidParameter" && err.argument === "spec" && err.values.spec === 0
+    );
+  });
+
+  test("rejects columns that are not arrays", () => {
+    assert.throws(
+      () => computeBatch({ close: 5 }, [{ indicator: "standardIndicators.simpleMovingAverage" }]),
+      (err) => err.code === "InvalidParameter" && err.argument === "close"
+    );
+    assert.deepStrictEqual(
+      computeBatch({ close: Float64Array.from(close) }, [{ id: "sma", indicator: "standardIndicators.simpleMovingAverage" }]),
+      computeBatch({ close }, [{ id: "sma", indicator: "standardIndicators.simpleMovingAverage" }])
+    );
+  });
+});
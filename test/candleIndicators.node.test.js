@@ -3,6 +3,7 @@ import assert from "node:assert/strict";
 
 import init, {
   candleIndicators,
+  IchimokuPoint,
   ConstantModelType,
   DeviationModel,
 } from "../index.node.js";
@@ -83,6 +84,19 @@ describe("candleIndicators.single (parity with Rust tests)", () => {
     ]);
   });
 
+  test("ichimokuCloudPoint (3,5,7)", () => {
+    const highs = [101.26, 102.57, 102.32, 100.69, 100.83, 101.73, 102.01];
+    const lows = [100.08, 98.75, 100.14, 98.98, 99.07, 100.1, 99.96];
+    const close = [100.46, 100.53, 100.38, 100.19, 100.21, 100.32, 100.28];
+    const out = candleIndicators.single.ichimokuCloudPoint(highs, lows, close, 3, 5, 7);
+    assert.ok(out instanceof IchimokuPoint);
+    assert.strictEqual(out.spanA, 100.595);
+    assert.strictEqual(out.spanB, 100.66);
+    assert.strictEqual(out.base, 100.65);
+    assert.strictEqual(out.conversion, 100.53999999999999);
+    assert.strictEqual(out.laggingClose, 100.38);
+  });
+
   test("donchianChannels", () => {
     const highs = [101.26, 102.57, 102.32, 100.69, 100.83];
     const lows = [100.08, 98.75, 100.14, 98.98, 99.07];
@@ -248,6 +262,6 @@ describe("candleIndicators.bulk (parity with Rust tests)", () => {
       2.0,
       5
     );
-    assert.deepEqual(out, [104.91999999999999, 105.1, 104.382]);
+    assert.deepEqual(Array.from(out), [104.91999999999999, 105.1, 104.382]);
   });
 });
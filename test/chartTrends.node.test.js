@@ -1,7 +1,7 @@
 import { test, describe, before } from "node:test";
 import assert from "node:assert/strict";
 
-import init, { chartTrends } from "../index.node.js";
+import init, { chartTrends, TrendBreakConfig } from "../index.node.js";
 
 before(async () => {
   await init();
@@ -94,6 +94,47 @@ describe("chartTrends (parity with Rust tests)", () => {
     ]);
   });
 
+  test("break_down_trends_with_config (builder matches positional)", () => {
+    const prices = [100.2, 100.46, 100.53, 100.38, 100.19];
+    const config = new TrendBreakConfig()
+      .withMaxOutliers(1)
+      .withSoftAdjRSquaredMinimum(0.75)
+      .withHardAdjRSquaredMinimum(0.5)
+      .withSoftRmseMultiplier(2.0)
+      .withHardRmseMultiplier(3.0)
+      .withSoftDurbinWatson(1.0, 3.0)
+      .withHardDurbinWatson(0.7, 3.3);
+    const out = chartTrends.breakDownTrendsWithConfig(prices, config);
+    assert.deepEqual(out, [
+      [0, 2, 0.16499999999999915, 100.23166666666665],
+      [2, 4, -0.1700000000000017, 100.87666666666668],
+    ]);
+  });
+
+  test("trend_break_config defaults and presets", () => {
+    const config = new TrendBreakConfig();
+    assert.strictEqual(config.maxOutliers, 1);
+    assert.strictEqual(config.softRmseMultiplier, 1.3);
+    assert.strictEqual(config.hardDurbinWatsonMax, 3.3);
+    assert.ok(TrendBreakConfig.conservative().maxOutliers > TrendBreakConfig.aggressive().maxOutliers);
+  });
+
+  test("trend_break_config validation", () => {
+    const config = new TrendBreakConfig();
+    assert.throws(
+      () => config.withSoftDurbinWatson(3.0, 1.0),
+      (e) => e.code === "InvalidParameter" && e.argument === "softDurbinWatsonMin"
+    );
+    assert.throws(
+      () => config.withHardRmseMultiplier(-1.0),
+      (e) => e.code === "InvalidParameter" && e.argument === "hardRmseMultiplier"
+    );
+    assert.throws(
+      () => chartTrends.breakDownTrends([100.2, 100.46, 100.53], 1, 0.25, 0.05, 1.3, 2.0, 3.0, 1.0, 0.7, 3.3),
+      (e) => e.code === "InvalidParameter" && e.argument === "softDurbinWatsonMin"
+    );
+  });
+
   // Optional: panic parity checks (commented out by default as they throw)
   // test("peaks_panic (period > len)", () => {
   //   const highs = [101.26, 102.57, 102.57, 100.69, 100.83, 101.73, 102.01];
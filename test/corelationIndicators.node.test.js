@@ -117,7 +117,7 @@ describe("correlationIndicators.bulk (parity with Rust tests)", () => {
       DeviationModel.StandardDeviation,
       5
     );
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       0.9042213658878326,
       0.9268640506930989,
       0.5300870380836703,
@@ -0,0 +1,75 @@
+import { test, describe, before } from "node:test";
+import assert from "node:assert/strict";
+
+import init, {
+  movingAverage,
+  momentumIndicators,
+  candleIndicators,
+  MovingAverageType,
+  ConstantModelType,
+} from "../index.node.js";
+
+before(async () => {
+  await init();
+});
+
+const isTiEngineError = (code, argument) => (e) =>
+  e instanceof Error &&
+  e.name === "TiEngineError" &&
+  e.code === code &&
+  e.argument === argument;
+
+describe("TiEngineError (structured errors on bad input)", () => {
+  test("EmptyInput", () => {
+    assert.throws(
+      () => movingAverage.single.movingAverage([], MovingAverageType.Simple),
+      isTiEngineError("EmptyInput", "prices")
+    );
+  });
+
+  test("PeriodTooLong", () => {
+    assert.throws(
+      () => movingAverage.bulk.movingAverage([100.2, 100.46], MovingAverageType.Simple, 5),
+      (e) => {
+        assert.ok(isTiEngineError("PeriodTooLong", "period")(e));
+        assert.deepEqual({ ...e.values }, { period: 5, length: 2 });
+        return true;
+      }
+    );
+  });
+
+  test("LengthMismatch", () => {
+    assert.throws(
+      () =>
+        candleIndicators.single.ichimokuCloud(
+          [101.0, 102.0, 103.0],
+          [99.0, 100.0],
+          [100.0, 101.0, 102.0],
+          1,
+          2,
+          3
+        ),
+      isTiEngineError("LengthMismatch", "lows")
+    );
+  });
+
+  test("InvalidParameter (shortPeriod >= longPeriod)", () => {
+    const prices = [100.2, 100.46, 100.53, 100.38, 100.19];
+    assert.throws(
+      () =>
+        momentumIndicators.bulk.percentagePriceOscillator(
+          prices,
+          4,
+          3,
+          ConstantModelType.SimpleMovingAverage
+        ),
+      isTiEngineError("InvalidParameter", "shortPeriod")
+    );
+  });
+
+  test("instance stays usable after an error", () => {
+    assert.throws(() => movingAverage.bulk.movingAverage([], MovingAverageType.Simple, 1));
+    const out = movingAverage.single.movingAverage([1, 2, 3], MovingAverageType.Simple);
+    assert.strictEqual(out, 2);
+  });
+});
@@ -0,0 +1,130 @@
+import { test, describe, before } from "node:test";
+import assert from "node:assert/strict";
+
+import init, {
+  lookback,
+  candleIndicators,
+  correlationIndicators,
+  momentumIndicators,
+  movingAverage,
+  otherIndicators,
+  standardIndicators,
+  strengthIndicators,
+  trendIndicators,
+  volatilityIndicators,
+  ConstantModelType,
+  DeviationModel,
+  MovingAverageType,
+  Position,
+} from "../index.node.js";
+
+before(async () => {
+  await init();
+});
+
+const N = 120;
+const close = Array.from({ length: N }, (_, i) => 100 + Math.sin(i * 0.7) * 5 + i * 0.05);
+const high = close.map((c, i) => c + 1 + (i % 3) * 0.3);
+const low = close.map((c, i) => c - 1 - (i % 4) * 0.2);
+const open = close.map((c, i) => c + Math.cos(i * 1.3));
+const volume = close.map((_, i) => 1000 + ((i * 37) % 101));
+const SMA = ConstantModelType.SimpleMovingAverage;
+const SD = DeviationModel.StandardDeviation;
+
+// [indicator, params, bulk call]
+const cases = [
+  ["candleIndicators.movingConstantEnvelopes", { period: 7 }, (p) => candleIndicators.bulk.movingConstantEnvelopes(close, SMA, 3, p.period)],
+  ["candleIndicators.mcginleyDynamicEnvelopes", { period: 7 }, (p) => candleIndicators.bulk.mcginleyDynamicEnvelopes(close, 3, 0, p.period)],
+  ["candleIndicators.movingConstantBands", { period: 7 }, (p) => candleIndicators.bulk.movingConstantBands(close, SMA, SD, 2, p.period)],
+  ["candleIndicators.mcginleyDynamicBands", { period: 7 }, (p) => candleIndicators.bulk.mcginleyDynamicBands(close, SD, 2, 0, p.period)],
+  ["candleIndicators.ichimokuCloud", { conversionPeriod: 9, basePeriod: 26, spanBPeriod: 52 }, (p) => candleIndicators.bulk.ichimokuCloud(high, low, close, p.conversionPeriod, p.basePeriod, p.spanBPeriod)],
+  ["candleIndicators.donchianChannels", { period: 7 }, (p) => candleIndicators.bulk.donchianChannels(high, low, p.period)],
+  ["candleIndicators.keltnerChannel", { period: 7 }, (p) => candleIndicators.bulk.keltnerChannel(high, low, close, SMA, SMA, 2, p.period)],
+  ["candleIndicators.supertrend", { period: 7 }, (p) => candleIndicators.bulk.supertrend(high, low, close, SMA, 2, p.period)],
+  ["correlationIndicators.correlateAssetPrices", { period: 7 }, (p) => correlationIndicators.bulk.correlateAssetPrices(close, open, SMA, SD, p.period)],
+  ["momentumIndicators.relativeStrengthIndex", { period: 7 }, (p) => momentumIndicators.bulk.relativeStrengthIndex(close, SMA, p.period)],
+  ["momentumIndicators.stochasticOscillator", { period: 7 }, (p) => momentumIndicators.bulk.stochasticOscillator(close, p.period)],
+  ["momentumIndicators.slowStochastic", { period: 7 }, (p) => momentumIndicators.bulk.slowStochastic(close, SMA, p.period)],
+  ["momentumIndicators.slowestStochastic", { period: 7 }, (p) => momentumIndicators.bulk.slowestStochastic(close, SMA, p.period)],
+  ["momentumIndicators.williamsPercentR", { period: 7 }, (p) => momentumIndicators.bulk.williamsPercentR(high, low, close, p.period)],
+  ["momentumIndicators.moneyFlowIndex", { period: 7 }, (p) => momentumIndicators.bulk.moneyFlowIndex(close, volume, p.period)],
+  ["momentumIndicators.rateOfChange", {}, () => momentumIndicators.bulk.rateOfChange(close)],
+  ["momentumIndicators.onBalanceVolume", {}, () => momentumIndicators.bulk.onBalanceVolume(close, volume, 0)],
+  ["momentumIndicators.commodityChannelIndex", { period: 7 }, (p) => momentumIndicators.bulk.commodityChannelIndex(close, SMA, SD, 0.015, p.period)],
+  ["momentumIndicators.mcginleyDynamicCommodityChannelIndex", { period: 7 }, (p) => momentumIndicators.bulk.mcginleyDynamicCommodityChannelIndex(close, 0, SD, 0.015, p.period)],
+  ["momentumIndicators.macdLine", { shortPeriod: 12, longPeriod: 26 }, (p) => momentumIndicators.bulk.macdLine(close, p.shortPeriod, SMA, p.longPeriod, SMA)],
+  ["momentumIndicators.signalLine", { period: 9 }, (p) => momentumIndicators.bulk.signalLine(close, SMA, p.period)],
+  ["momentumIndicators.mcginleyDynamicMacdLine", { shortPeriod: 12, longPeriod: 26 }, (p) => momentumIndicators.bulk.mcginleyDynamicMacdLine(close, p.shortPeriod, 0, p.longPeriod, 0)],
+  ["momentumIndicators.chaikinOscillator", { shortPeriod: 3, longPeriod: 10 }, (p) => momentumIndicators.bulk.chaikinOscillator(high, low, close, volume, p.shortPeriod, p.longPeriod, 0, SMA, SMA)],
+  ["momentumIndicators.percentagePriceOscillator", { shortPeriod: 12, longPeriod: 26 }, (p) => momentumIndicators.bulk.percentagePriceOscillator(close, p.shortPeriod, p.longPeriod, SMA)],
+  ["momentumIndicators.chandeMomentumOscillator", { period: 7 }, (p) => momentumIndicators.bulk.chandeMomentumOscillator(close, p.period)],
+  ["movingAverage.movingAverage", { period: 7 }, (p) => movingAverage.bulk.movingAverage(close, MovingAverageType.Simple, p.period)],
+  ["movingAverage.mcginleyDynamic", { period: 7 }, (p) => movingAverage.bulk.mcginleyDynamic(close, 0, p.period)],
+  ["movingAverage.kaufmanAdaptiveMovingAverage", { period: 10 }, (p) => movingAverage.bulk.kaufmanAdaptiveMovingAverage(close, 0, p.period, 2, 30)],
+  ["movingAverage.fractalAdaptiveMovingAverage", { period: 16 }, (p) => movingAverage.bulk.fractalAdaptiveMovingAverage(close, 0, p.period)],
+  ["movingAverage.variableIndexDynamicAverage", { cmoPeriod: 9 }, (p) => movingAverage.bulk.variableIndexDynamicAverage(close, 0, 14, p.cmoPeriod)],
+  ["movingAverage.arnaudLegouxMovingAverage", { period: 9 }, (p) => movingAverage.bulk.arnaudLegouxMovingAverage(close, p.period, 0.85, 6)],
+  ["movingAverage.tillsonT3", { period: 5 }, (p) => movingAverage.bulk.tillsonT3(close, p.period, 0.7)],
+  ["otherIndicators.returnOnInvestment", {}, () => otherIndicators.bulk.returnOnInvestment(close, 1000)],
+  ["otherIndicators.trueRange", {}, () => otherIndicators.bulk.trueRange(close, high, low)],
+  ["otherIndicators.averageTrueRange", { period: 7 }, (p) => otherIndicators.bulk.averageTrueRange(close, high, low, SMA, p.period)],
+  ["otherIndicators.internalBarStrength", {}, () => otherIndicators.bulk.internalBarStrength(high, low, close)],
+  ["otherIndicators.positivityIndicator", { signalPeriod: 5 }, (p) => otherIndicators.bulk.positivityIndicator(open, close, p.signalPeriod, SMA)],
+  ["standardIndicators.simpleMovingAverage", { period: 7 }, (p) => standardIndicators.bulk.simpleMovingAverage(close, p.period)],
+  ["standardIndicators.smoothedMovingAverage", { period: 7 }, (p) => standardIndicators.bulk.smoothedMovingAverage(close, p.period)],
+  ["standardIndicators.exponentialMovingAverage", { period: 7 }, (p) => standardIndicators.bulk.exponentialMovingAverage(close, p.period)],
+  ["standardIndicators.bollingerBands", undefined, () => standardIndicators.bulk.bollingerBands(close)],
+  ["standardIndicators.macd", undefined, () => standardIndicators.bulk.macd(close)],
+  ["standardIndicators.rsi", undefined, () => standardIndicators.bulk.rsi(close)],
+  ["strengthIndicators.accumulationDistribution", {}, () => strengthIndicators.bulk.accumulationDistribution(high, low, close, volume, 0)],
+  ["strengthIndicators.positiveVolumeIndex", {}, () => strengthIndicators.bulk.positiveVolumeIndex(close, volume, 0)],
+  ["strengthIndicators.negativeVolumeIndex", {}, () => strengthIndicators.bulk.negativeVolumeIndex(close, volume, 0)],
+  ["strengthIndicators.relativeVigorIndex", { period: 7 }, (p) => strengthIndicators.bulk.relativeVigorIndex(open, high, low, close, SMA, p.period)],
+  ["trendIndicators.aroonUp", { period: 7 }, (p) => trendIndicators.bulk.aroonUp(high, p.period)],
+  ["trendIndicators.aroonDown", { period: 7 }, (p) => trendIndicators.bulk.aroonDown(low, p.period)],
+  ["trendIndicators.aroonOscillator", {}, () => trendIndicators.bulk.aroonOscillator(close, open)],
+  ["trendIndicators.aroonIndicator", { period: 7 }, (p) => trendIndicators.bulk.aroonIndicator(high, low, p.period)],
+  ["trendIndicators.parabolicTimePriceSystem", {}, () => trendIndicators.bulk.parabolicTimePriceSystem(high, low, 0.02, 0.2, 0.02, Position.Long, 0)],
+  ["trendIndicators.directionalMovementSystem", { period: 14 }, (p) => trendIndicators.bulk.directionalMovementSystem(high, low, close, p.period, SMA)],
+  ["trendIndicators.volumePriceTrend", {}, () => trendIndicators.bulk.volumePriceTrend(close, volume.slice(1), 0)],
+  ["trendIndicators.trueStrengthIndex", { firstPeriod: 25, secondPeriod: 13 }, (p) => trendIndicators.bulk.trueStrengthIndex(close, SMA, p.firstPeriod, SMA, p.secondPeriod)],
+  ["volatilityIndicators.ulcerIndex", { period: 7 }, (p) => volatilityIndicators.bulk.ulcerIndex(close, p.period)],
+  ["volatilityIndicators.volatilitySystem", { period: 7 }, (p) => volatilityIndicators.bulk.volatilitySystem(high, low, close, p.period, 2, SMA)],
+];
+
+describe("lookback matches bulk output lengths", () => {
+  for (const [indicator, params, call] of cases) {
+    test(indicator, () => {
+      assert.strictEqual(call(params ?? {}).length, N - lookback(indicator, params));
+    });
+  }
+
+  test("Columns variants share the base lookback", () => {
+    const params = { conversionPeriod: 9, basePeriod: 26, spanBPeriod: 52 };
+    assert.strictEqual(
+      lookback("candleIndicators.ichimokuCloudColumns", params),
+      lookback("candleIndicators.ichimokuCloud", params)
+    );
+  });
+});
+
+describe("lookback validation", () => {
+  test("unknown indicator throws", () => {
+    assert.throws(() => lookback("momentumIndicators.nope", {}), {
+      name: "TiEngineError",
+      code: "InvalidParameter",
+      argument: "indicator",
+    });
+  });
+
+  test("missing or zero period throws", () => {
+    assert.throws(() => lookback("momentumIndicators.macdLine", { shortPeriod: 12 }), {
+      code: "InvalidParameter",
+      argument: "longPeriod",
+    });
+    assert.throws(() => lookback("standardIndicators.simpleMovingAverage", { period: 0 }), {
+      code: "InvalidParameter",
+      argument: "period",
+    });
+  });
+});
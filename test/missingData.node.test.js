@@ -0,0 +1,108 @@
+import { test, describe, before, afterEach } from "node:test";
+import assert from "node:assert/strict";
+
+import init, {
+  MissingData,
+  setMissingData,
+  getMissingData,
+  withMissingData,
+  setAlignedOutput,
+  computeBatch,
+  momentumIndicators,
+  movingAverage,
+  standardIndicators,
+} from "../index.node.js";
+
+before(async () => {
+  await init();
+});
+
+afterEach(() => {
+  setMissingData(MissingData.Passthrough);
+  setAlignedOutput(false);
+});
+
+const prices = [10, 11, 12, 13, 14, 15, 16, 17, 18, 19];
+// Bar 4 is missing
+const gapped = prices.map((p, i) => (i === 4 ? NaN : p));
+const ema = (values) => Array.from(standardIndicators.bulk.exponentialMovingAverage(values, 3));
+const affected = (values) => values.map((v) => Number.isNaN(v));
+
+describe("missing data", () => {
+  test("Passthrough keeps the previous behaviour", () => {
+    assert.strictEqual(getMissingData(), MissingData.Passthrough);
+    // The EMA over the gap is NaN for every window containing bar 4
+    assert.deepStrictEqual(affected(ema(gapped)), [false, false, true, true, true, false, false, false]);
+  });
+
+  test("Reject names the input and index", () => {
+    setMissingData(MissingData.Reject);
+    assert.throws(
+      () => ema(gapped),
+      (err) => err.code === "MissingValue" && err.argument === "prices" && err.values.index === 4
+    );
+    // Gap-free input is unaffected
+    assert.deepStrictEqual(ema(prices), withMissingData(MissingData.Passthrough, () => ema(prices)));
+  });
+
+  test("Propagate only affects windows that contain the gap", () => {
+    setMissingData(MissingData.Propagate);
+    const out = ema(gapped);
+    assert.deepStrictEqual(affected(out), [false, false, true, true, true, false, false, false]);
+    assert.deepStrictEqual(out.slice(5), ema(prices.slice(5)));
+    assert.deepStrictEqual(out.slice(0, 2), ema(prices.slice(0, 4)));
+  });
+
+  test("Propagate carries the previous value of recursive indicators across the gap", () => {
+    setMissingData(MissingData.Propagate);
+    const mcginley = (values, previous) => Array.from(movingAverage.bulk.mcginleyDynamic(values, previous, 3));
+    const out = mcginley(gapped, 0);
+    assert.deepStrictEqual(affected(out), [false, false, true, true, true, false, false, false]);
+    assert.deepStrictEqual(out.slice(0, 2), mcginley(prices.slice(0, 4), 0));
+    // The run after the gap starts from the last value before it, not from 0
+    assert.deepStrictEqual(out.slice(5), mcginley(prices.slice(5), out[1]));
+
+    const volume = prices.map((_, i) => 100 + i);
+    const obv = (values, previous) => Array.from(momentumIndicators.bulk.onBalanceVolume(values, volume.slice(-values.length), previous));
+    const flows = obv(gapped, 50);
+    assert.deepStrictEqual(flows.slice(5), obv(prices.slice(5), flows[2]));
+  });
+
+  test("Skip computes over the remaining bars", () => {
+    setMissingData(MissingData.Skip);
+    const out = ema(gapped);
+    const compacted = ema(gapped.filter((v) => !Number.isNaN(v)));
+    // NaN at the missing bar only
+    assert.deepStrictEqual(affected(out), [false, false, true, false, false, false, false, false]);
+    assert.deepStrictEqual(out.filter((v) => !Number.isNaN(v)), compacted);
+  });
+
+  test("ForwardFill and Interpolate fill the gap", () => {
+    const filled = prices.map((p, i) => (i === 4 ? 13 : p));
+    assert.deepStrictEqual(withMissingData(MissingData.ForwardFill, () => ema(gapped)), ema(filled));
+    assert.deepStrictEqual(withMissingData(MissingData.Interpolate, () => ema(gapped)), ema(prices));
+    assert.strictEqual(getMissingData(), MissingData.Passthrough);
+  });
+
+  test("leading gaps and aligned output", () => {
+    setAlignedOutput(true);
+    const leading = [NaN, NaN, ...prices.slice(2)];
+    const out = withMissingData(MissingData.ForwardFill, () => ema(leading));
+    assert.strictEqual(out.length, prices.length);
+    assert.deepStrictEqual(affected(out), [true, true, true, true, false, false, false, false, false, false]);
+  });
+
+  test("multi-input indicators and per-spec policies", () => {
+    const high = prices.map((p) => p + 1);
+    const low = gapped.map((p) => p - 1);
+    const spec = { id: "atr", indicator: "otherIndicators.averageTrueRange", params: { period: 3 } };
+    const out = computeBatch({ high, low, close: prices }, [{ ...spec, missingData: "Propagate" }]);
+    // A gap in any input marks the bar as missing
+    assert.deepStrictEqual(affected(Array.from(out.atr)), [false, false, true, true, true, false, false, false]);
+    assert.throws(
+      () => computeBatch({ high, low, close: prices }, [{ ...spec, missingData: MissingData.Reject }]),
+      (err) => err.code === "MissingValue" && err.argument === "low" && err.values.spec === 0
+    );
+    assert.strictEqual(getMissingData(), MissingData.Passthrough);
+  });
+});
@@ -171,7 +171,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
       ConstantModelType.SimpleMovingAverage,
       5
     );
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       49.2537313432832,
       20.930232558140005,
       27.6595744680842,
@@ -182,7 +182,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
   test("stochasticOscillator (period 5)", () => {
     const prices = [100.2,100.46,100.53,100.38,100.19,100.21,100.32,100.28];
     const out = momentumIndicators.bulk.stochasticOscillator(prices, 5);
-    assert.deepEqual(out, [0.0, 5.882352941175241, 38.23529411764534, 47.36842105263394]);
+    assert.deepEqual(Array.from(out), [0.0, 5.882352941175241, 38.23529411764534, 47.36842105263394]);
   });
 
   test("slowStochastic (SMA, period 3)", () => {
@@ -192,7 +192,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
       ConstantModelType.SimpleMovingAverage,
       3
     );
-    assert.deepEqual(out, [14.705882352940193, 30.49535603715151]);
+    assert.deepEqual(Array.from(out), [14.705882352940193, 30.49535603715151]);
   });
 
   test("slowestStochastic (SMA, period 3)", () => {
@@ -202,7 +202,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
       ConstantModelType.SimpleMovingAverage,
       3
     );
-    assert.deepEqual(out, [14.705882352940193, 30.49535603715151]);
+    assert.deepEqual(Array.from(out), [14.705882352940193, 30.49535603715151]);
   });
 
   test("williamsPercentR (period 3)", () => {
@@ -210,14 +210,14 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
     const low = [100.37, 100.57, 100.94, 100.89];
     const close = [100.49, 101.06, 101.13, 100.95];
     const out = momentumIndicators.bulk.williamsPercentR(high, low, close, 3);
-    assert.deepEqual(out, [-37.190082644628525, -66.95652173912976]);
+    assert.deepEqual(Array.from(out), [-37.190082644628525, -66.95652173912976]);
   });
 
   test("moneyFlowIndex (period 5)", () => {
     const prices = [100.2,100.46,100.53,100.38,100.19,100.21,100.32,100.28];
     const volume = [1200,1400,1450,1100,900,875,1025,1100];
     const out = momentumIndicators.bulk.moneyFlowIndex(prices, volume, 5);
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       58.811420498704834,
       33.5840199520207,
       26.291946512503486,
@@ -228,7 +228,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
   test("rateOfChange", () => {
     const prices = [100.2, 100.46, 100.38, 100.19, 100.32, 100.32];
     const out = momentumIndicators.bulk.rateOfChange(prices);
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       0.25948103792414257,
       -0.07963368504877394,
       -0.18928073321378536,
@@ -241,7 +241,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
     const prices = [100.46, 100.53, 100.38, 100.19, 100.21];
     const volume = [1400, 1450, 1100, 900, 875];
     const out = momentumIndicators.bulk.onBalanceVolume(prices, volume, 0.0);
-    assert.deepEqual(out, [1450.0, 350.0, -550.0, 325.0]);
+    assert.deepEqual(Array.from(out), [1450.0, 350.0, -550.0, 325.0]);
   });
 
   test("commodityChannelIndex (SMA+MeanAD, k=0.015, period 3)", () => {
@@ -253,7 +253,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
       0.015,
       3
     );
-    assert.deepEqual(out, [-100.0, -100.00000000000804, -41.66666666666519]);
+    assert.deepEqual(Array.from(out), [-100.0, -100.00000000000804, -41.66666666666519]);
   });
 
   test("mcginleyDynamicCommodityChannelIndex (no previous, period 5)", () => {
@@ -281,7 +281,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
       5,
       ConstantModelType.ExponentialMovingAverage
     );
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       -0.06067027758972188,
       -0.022417061611406552,
       0.005788761002008869,
@@ -298,7 +298,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
       ConstantModelType.ExponentialMovingAverage,
       3
     );
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       -0.011764193829181728,
       0.0166350710900523,
       -0.4117854724828291,
@@ -347,7 +347,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
       5,
       ConstantModelType.ExponentialMovingAverage
     );
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       0.08979623002617415,
       -0.008380468415664317,
       -0.08769552039759204,
@@ -357,7 +357,7 @@ describe("momentumIndicators.bulk (parity, one model each)", () => {
   test("chandeMomentumOscillator (period 5)", () => {
     const prices = [100.01, 100.44, 100.39, 100.63, 100.71, 100.35, 100.12];
     const out = momentumIndicators.bulk.chandeMomentumOscillator(prices, 5);
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       87.50000000000044,
       -12.328767123288312,
       -29.67032967032981,
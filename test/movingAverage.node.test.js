@@ -1,7 +1,16 @@
 import { test, describe, before } from "node:test";
 import assert from "node:assert/strict";
 
-import init, { movingAverage, MovingAverageType } from "../index.node.js";
+import init, {
+  movingAverage,
+  momentumIndicators,
+  candleIndicators,
+  MovingAverageType,
+  ConstantModelType,
+  ConstantModelFamily,
+  describeIndicator,
+  PersonalisedModel,
+} from "../index.node.js";
 
 before(async () => {
   await init();
@@ -24,12 +33,227 @@ describe("movingAverage.bulk (one model)", () => {
   test("movingAverage (Simple, period 3)", () => {
     const prices = [100.2, 100.46, 100.53, 100.38, 100.19];
     const out = movingAverage.bulk.movingAverage(prices, MovingAverageType.Simple, 3);
-    assert.deepEqual(out, [100.39666666666666, 100.456666666666666, 100.36666666666667]);
+    assert.ok(out instanceof Float64Array);
+    assert.deepEqual(Array.from(out), [100.39666666666666, 100.456666666666666, 100.36666666666667]);
   });
 
   test("mcginleyDynamic (period 3, no previous)", () => {
     const prices = [100.2, 100.46, 100.53, 100.38, 100.19];
     const out = movingAverage.bulk.mcginleyDynamic(prices, 0.0, 3);
-    assert.deepEqual(out, [100.53, 100.47970046511769, 100.38201189376744]);
+    assert.deepEqual(Array.from(out), [100.53, 100.47970046511769, 100.38201189376744]);
+  });
+});
+
+describe("personalised models", () => {
+  const prices = [100.2, 100.46, 100.53, 100.38, 100.19];
+
+  test("PersonalisedModel(2, 1) matches Exponential", () => {
+    const model = new PersonalisedModel(2, 1);
+    assert.strictEqual(
+      movingAverage.single.movingAverage(prices, model),
+      movingAverage.single.movingAverage(prices, MovingAverageType.Exponential)
+    );
+    assert.deepEqual(
+      Array.from(movingAverage.bulk.movingAverage(prices, { alphaNum: 1, alphaDen: 0 }, 3)),
+      Array.from(movingAverage.bulk.movingAverage(prices, MovingAverageType.Smoothed, 3))
+    );
+  });
+
+  test("accepted as a ConstantModelType", () => {
+    assert.strictEqual(
+      momentumIndicators.single.relativeStrengthIndex(prices, new PersonalisedModel(2, 1)),
+      momentumIndicators.single.relativeStrengthIndex(prices, ConstantModelType.ExponentialMovingAverage)
+    );
+  });
+
+  test("fromAlpha", () => {
+    const model = PersonalisedModel.fromAlpha(0.5, 4);
+    assert.strictEqual(model.alphaNum, 2);
+    assert.strictEqual(model.alphaDen, 0);
+    assert.strictEqual(model.alpha(4), 0.5);
+  });
+
+  test("invalid alpha throws", () => {
+    assert.throws(() => new PersonalisedModel(0, 1), { name: "TiEngineError", code: "InvalidParameter", argument: "alphaNum" });
+    assert.throws(() => new PersonalisedModel(1, -1), { name: "TiEngineError", code: "InvalidParameter", argument: "alphaDen" });
+    assert.throws(
+      () => movingAverage.single.movingAverage(prices, { alphaNum: 1 }),
+      { name: "TiEngineError", code: "InvalidParameter", argument: "maType" }
+    );
+    assert.throws(
+      () => movingAverage.single.movingAverage(prices, 42),
+      { name: "TiEngineError", code: "InvalidParameter", argument: "maType" }
+    );
+  });
+});
+
+describe("moving average families", () => {
+  const prices = Array.from({ length: 60 }, (_, i) => 100 + Math.sin(i / 4) * 5 + (i % 5) * 0.3);
+  const families = [
+    ["Weighted", "WeightedMovingAverage"],
+    ["Hull", "HullMovingAverage"],
+    ["DoubleExponential", "DoubleExponentialMovingAverage"],
+    ["TripleExponential", "TripleExponentialMovingAverage"],
+    ["ZeroLagExponential", "ZeroLagExponentialMovingAverage"],
+    ["Triangular", "TriangularMovingAverage"],
+  ];
+
+  test("window-based reference values", () => {
+    // Computed independently from the definitions documented on ConstantModelFamily
+    const window = [10, 11, 13, 12, 15, 14, 16, 18, 17];
+    const reference = {
+      Weighted: 15.266666666666667,
+      Hull: 17.700793650793653,
+      DoubleExponential: 17.081159628528006,
+      TripleExponential: 17.58075450515645,
+      ZeroLagExponential: 20.007139457401237,
+      Triangular: 14.04,
+    };
+    for (const [maType, model] of families) {
+      for (const value of [
+        movingAverage.single.movingAverage(window, MovingAverageType[maType]),
+        momentumIndicators.single.signalLine(window, ConstantModelFamily[model]),
+      ]) {
+        assert.ok(Math.abs(value - reference[maType]) < 1e-12, `${maType}: ${value}`);
+      }
+    }
+  });
+
+  test("weighted and triangular weights", () => {
+    assert.strictEqual(movingAverage.single.movingAverage([1, 2, 3], MovingAverageType.Weighted), 14 / 6);
+    assert.strictEqual(movingAverage.single.movingAverage([1, 2, 3, 4], MovingAverageType.Triangular), 2.5);
+  });
+
+  for (const [maType, model] of families) {
+    test(`${maType}: flat prices, rolling windows and constant models`, () => {
+      for (const length of [1, 2, 7, 20]) {
+        const flat = movingAverage.single.movingAverage(Array(length).fill(42.5), MovingAverageType[maType]);
+        assert.ok(Math.abs(flat - 42.5) < 1e-9, `${maType} over ${length}`);
+      }
+      const out = movingAverage.bulk.movingAverage(prices, MovingAverageType[maType], 14);
+      assert.strictEqual(out.length, prices.length - 13);
+      assert.strictEqual(out[out.length - 1], movingAverage.single.movingAverage(prices.slice(-14), MovingAverageType[maType]));
+
+      // The same family as the moving constant of envelopes and the signal line
+      const envelopes = candleIndicators.bulk.movingConstantEnvelopesColumns(prices, ConstantModelFamily[model], 3, 14);
+      assert.deepEqual(Array.from(envelopes.middle), Array.from(out));
+      assert.deepEqual(Array.from(momentumIndicators.bulk.signalLine(prices, ConstantModelFamily[model], 14)), Array.from(out));
+
+      const macd = momentumIndicators.bulk.macdLine(prices, 12, ConstantModelFamily[model], 26, ConstantModelType.ExponentialMovingAverage);
+      const long = movingAverage.bulk.movingAverage(prices, MovingAverageType.Exponential, 26);
+      const short = movingAverage.bulk.movingAverage(prices.slice(14), MovingAverageType[maType], 12);
+      assert.deepEqual(Array.from(macd), Array.from(short, (v, i) => v - long[i]));
+      assert.strictEqual(momentumIndicators.bulk.relativeStrengthIndex(prices, ConstantModelFamily[model], 14).length, prices.length - 13);
+    });
+  }
+
+  test("only the family-capable indicators take ConstantModelFamily", () => {
+    assert.strictEqual(ConstantModelType.HullMovingAverage, undefined);
+    assert.throws(
+      () => momentumIndicators.bulk.slowStochastic(prices, ConstantModelFamily.HullMovingAverage, 5),
+      { name: "TiEngineError", code: "InvalidParameter", argument: "constantModelType" }
+    );
+    const choices = (indicator) =>
+      describeIndicator(indicator).params.find((p) => p.name === "constantModelType").choices;
+    assert.ok(choices("momentumIndicators.relativeStrengthIndex").includes("HullMovingAverage"));
+    assert.deepEqual(choices("momentumIndicators.slowStochastic"), Object.keys(ConstantModelType).filter((k) => isNaN(k)));
+  });
+
+  test("VolumeWeighted takes the trailing volume", () => {
+    const volume = prices.map((_, i) => 1000 + (i % 3) * 250);
+    assert.strictEqual(movingAverage.single.movingAverage([10, 20], MovingAverageType.VolumeWeighted, [3, 1]), 12.5);
+    assert.deepEqual(
+      Array.from(movingAverage.bulk.movingAverage(prices, MovingAverageType.VolumeWeighted, 10, volume)),
+      Array.from(movingAverage.bulk.volumeWeightedMovingAverage(prices, volume, 10))
+    );
+    assert.throws(
+      () => movingAverage.bulk.movingAverage(prices, MovingAverageType.VolumeWeighted, 10),
+      { name: "TiEngineError", code: "InvalidParameter", argument: "volume" }
+    );
+    assert.throws(
+      () => movingAverage.single.movingAverage([10, 20], MovingAverageType.Simple, [3, 1]),
+      { name: "TiEngineError", code: "InvalidParameter", argument: "volume" }
+    );
+    assert.throws(
+      () => movingAverage.single.movingAverage([10, 20], MovingAverageType.VolumeWeighted, [3]),
+      { name: "TiEngineError", code: "LengthMismatch", argument: "volume" }
+    );
+  });
+
+  test("volumeWeightedMovingAverage", () => {
+    assert.strictEqual(movingAverage.single.volumeWeightedMovingAverage([10, 20], [3, 1]), 12.5);
+    assert.ok(Number.isNaN(movingAverage.single.volumeWeightedMovingAverage([10, 20], [0, 0])));
+    const volume = prices.map((_, i) => 1000 + (i % 3) * 250);
+    const out = movingAverage.bulk.volumeWeightedMovingAverage(prices, volume, 10);
+    assert.strictEqual(out.length, prices.length - 9);
+    assert.strictEqual(out[0], movingAverage.single.volumeWeightedMovingAverage(prices.slice(0, 10), volume.slice(0, 10)));
+    assert.throws(
+      () => movingAverage.bulk.volumeWeightedMovingAverage(prices, volume.slice(1), 10),
+      { name: "TiEngineError", code: "LengthMismatch" }
+    );
+  });
+});
+
+describe("adaptive moving averages", () => {
+  const prices = Array.from({ length: 80 }, (_, i) => 100 + Math.sin(i / 6) * 4 + (i % 4) * 0.4);
+  const close = (a, b) => Math.abs(a - b) < 1e-9;
+
+  test("single steps", () => {
+    // Efficiency ratio 1: smoothing (2 / 3)^2
+    assert.ok(close(movingAverage.single.kaufmanAdaptiveMovingAverage([10, 11, 12], 10, 2, 30), 10 + (4 / 9) * 2));
+    assert.strictEqual(movingAverage.single.kaufmanAdaptiveMovingAverage([10, 11, 12], 0, 2, 30), 12);
+    // |CMO| = 100%: alpha 2 / (4 + 1)
+    assert.ok(close(movingAverage.single.variableIndexDynamicAverage([1, 2, 3], 1, 4), 1.8));
+    // A flat window keeps the previous VIDYA, a flat FRAMA window moves all the way
+    assert.strictEqual(movingAverage.single.variableIndexDynamicAverage([5, 5, 5], 4, 4), 4);
+    assert.strictEqual(movingAverage.single.fractalAdaptiveMovingAverage([5, 5, 5, 5], 4), 5);
+    assert.strictEqual(movingAverage.single.arnaudLegouxMovingAverage([1, 2, 3, 4, 5], 0.5, 6), 3);
+    assert.ok(movingAverage.single.arnaudLegouxMovingAverage([1, 2, 3, 4, 5], 0.85, 6) > 3);
+    for (const volumeFactor of [0, 0.7, 1]) {
+      assert.ok(close(movingAverage.single.tillsonT3(Array(9).fill(42.5), volumeFactor), 42.5));
+    }
+  });
+
+  test("recursive bulk functions chain the single step", () => {
+    const kama = movingAverage.bulk.kaufmanAdaptiveMovingAverage(prices, 0, 10, 2, 30);
+    assert.strictEqual(kama.length, prices.length - 10);
+    assert.strictEqual(kama[0], prices[10]);
+    assert.strictEqual(kama[1], movingAverage.single.kaufmanAdaptiveMovingAverage(prices.slice(1, 12), kama[0], 2, 30));
+
+    const frama = movingAverage.bulk.fractalAdaptiveMovingAverage(prices, 101, 16);
+    assert.strictEqual(frama.length, prices.length - 15);
+    assert.strictEqual(frama[0], movingAverage.single.fractalAdaptiveMovingAverage(prices.slice(0, 16), 101));
+    assert.strictEqual(frama[1], movingAverage.single.fractalAdaptiveMovingAverage(prices.slice(1, 17), frama[0]));
+
+    const vidya = movingAverage.bulk.variableIndexDynamicAverage(prices, 0, 14, 9);
+    assert.strictEqual(vidya.length, prices.length - 9);
+    assert.strictEqual(vidya[0], prices[9]);
+    assert.strictEqual(vidya[1], movingAverage.single.variableIndexDynamicAverage(prices.slice(1, 11), vidya[0], 14));
+  });
+
+  test("window bulk functions match the single function", () => {
+    const alma = movingAverage.bulk.arnaudLegouxMovingAverage(prices, 9, 0.85, 6);
+    assert.strictEqual(alma.length, prices.length - 8);
+    assert.strictEqual(alma[alma.length - 1], movingAverage.single.arnaudLegouxMovingAverage(prices.slice(-9), 0.85, 6));
+    const t3 = movingAverage.bulk.tillsonT3(prices, 5, 0.7);
+    assert.strictEqual(t3.length, prices.length - 4);
+    assert.strictEqual(t3[t3.length - 1], movingAverage.single.tillsonT3(prices.slice(-5), 0.7));
+  });
+
+  test("validates the tuning parameters", () => {
+    const invalid = (call, argument) =>
+      assert.throws(call, { name: "TiEngineError", code: "InvalidParameter", argument });
+    invalid(() => movingAverage.bulk.kaufmanAdaptiveMovingAverage(prices, 0, 10, 30, 30), "fastPeriod");
+    invalid(() => movingAverage.bulk.kaufmanAdaptiveMovingAverage(prices, 0, 10, 0, 30), "fastPeriod");
+    invalid(() => movingAverage.bulk.fractalAdaptiveMovingAverage(prices, 0, 15), "period");
+    invalid(() => movingAverage.single.fractalAdaptiveMovingAverage([1, 2, 3], 0), "period");
+    invalid(() => movingAverage.bulk.variableIndexDynamicAverage(prices, 0, 0, 9), "period");
+    invalid(() => movingAverage.bulk.arnaudLegouxMovingAverage(prices, 9, 1.5, 6), "offset");
+    invalid(() => movingAverage.bulk.arnaudLegouxMovingAverage(prices, 9, 0.85, 0), "sigma");
+    invalid(() => movingAverage.single.tillsonT3(prices, -0.1), "volumeFactor");
+    assert.throws(
+      () => movingAverage.bulk.kaufmanAdaptiveMovingAverage(prices.slice(0, 10), 0, 10, 2, 30),
+      { name: "TiEngineError", code: "PeriodTooLong" }
+    );
   });
 });
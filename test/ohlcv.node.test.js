@@ -0,0 +1,68 @@
+import { test, describe, before } from "node:test";
+import assert from "node:assert/strict";
+
+import init, {
+  OhlcvSeries,
+  candleIndicators,
+  momentumIndicators,
+  otherIndicators,
+  trendIndicators,
+  ConstantModelType,
+} from "../index.node.js";
+
+before(async () => {
+  await init();
+});
+
+const N = 60;
+const close = Array.from({ length: N }, (_, i) => 100 + Math.sin(i * 0.7) * 5 + i * 0.05);
+const high = close.map((c, i) => c + 1 + (i % 3) * 0.3);
+const low = close.map((c, i) => c - 1 - (i % 4) * 0.2);
+const open = close.map((c, i) => c + Math.cos(i * 1.3));
+const volume = close.map((_, i) => 1000 + ((i * 37) % 101));
+const SMA = ConstantModelType.SimpleMovingAverage;
+
+describe("OhlcvSeries", () => {
+  const series = new OhlcvSeries(open, high, low, close, volume);
+
+  test("keeps its columns", () => {
+    assert.strictEqual(series.length, N);
+    assert.deepEqual(Array.from(series.close), close);
+    assert.strictEqual(series.timestamps, undefined);
+  });
+
+  test("methods match the bulk functions", () => {
+    assert.deepEqual(
+      Array.from(series.relativeStrengthIndex(SMA, 14)),
+      Array.from(momentumIndicators.bulk.relativeStrengthIndex(close, SMA, 14))
+    );
+    assert.deepEqual(
+      series.keltnerChannel(SMA, SMA, 2, 10),
+      candleIndicators.bulk.keltnerChannel(high, low, close, SMA, SMA, 2, 10)
+    );
+    assert.deepEqual(
+      Array.from(series.averageTrueRange(SMA, 5)),
+      Array.from(otherIndicators.bulk.averageTrueRange(close, high, low, SMA, 5))
+    );
+    assert.deepEqual(
+      Array.from(series.volumePriceTrend(0)),
+      Array.from(trendIndicators.bulk.volumePriceTrend(close, volume.slice(1), 0))
+    );
+    assert.deepEqual(
+      series.positivityIndicator(5, SMA),
+      otherIndicators.bulk.positivityIndicator(open.slice(1), close.slice(0, -1), 5, SMA)
+    );
+  });
+
+  test("mismatched columns throw", () => {
+    assert.throws(() => new OhlcvSeries(open, high, low.slice(1), close, volume), {
+      name: "TiEngineError",
+      code: "LengthMismatch",
+      argument: "low",
+    });
+    assert.throws(() => new OhlcvSeries(open, high, low, close, volume, [1, 2]), {
+      code: "LengthMismatch",
+      argument: "timestamps",
+    });
+  });
+});
@@ -13,6 +13,12 @@ describe("otherIndicators.single (parity, one model where applicable)", () => {
     assert.deepEqual(out, [1000.6967947441768, 0.06967947441768274]);
   });
 
+  test("returnOnInvestmentPoint", () => {
+    const out = otherIndicators.single.returnOnInvestmentPoint(100.46, 100.53, 1000.0);
+    assert.strictEqual(out.finalValue, 1000.6967947441768);
+    assert.strictEqual(out.percentReturn, 0.06967947441768274);
+  });
+
   test("trueRange", () => {
     // Args: previousClose, high, low
     assert.strictEqual(
@@ -65,7 +71,7 @@ describe("otherIndicators.bulk (parity, one model where applicable)", () => {
     const high = [101.12, 101.3, 100.11];
     const low = [100.29, 100.87, 99.94];
     const out = otherIndicators.bulk.trueRange(close, high, low);
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       0.8299999999999983,
       0.769999999999996,
       0.4399999999999977,
@@ -83,7 +89,7 @@ describe("otherIndicators.bulk (parity, one model where applicable)", () => {
       ConstantModelType.SimpleMovingAverage,
       3
     );
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       0.6799999999999974,
       0.6333333333333305,
       0.5500000000000019,
@@ -95,7 +101,7 @@ describe("otherIndicators.bulk (parity, one model where applicable)", () => {
     const high = [102.32, 100.69, 100.83, 101.73, 102.01];
     const low = [100.14, 98.98, 99.07, 100.1, 99.96];
     const out = otherIndicators.bulk.internalBarStrength(high, low, close);
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       0.1880733944954119,
       0.017543859649123535,
       0.7727272727272783,
@@ -0,0 +1,64 @@
+import { test, describe, before } from "node:test";
+import assert from "node:assert/strict";
+
+import init, { computePanel, standardIndicators, trendIndicators } from "../index.node.js";
+
+before(async () => {
+  await init();
+});
+
+const symbol = (seed, length) => Array.from({ length }, (_, i) => 50 + seed * 10 + Math.sin(i / (2 + seed)) * 3 + i * 0.05);
+
+// Row of a panel matrix without its NaN padding
+const row = (matrix, [, bars], index) =>
+  Array.from(matrix.subarray(index * bars, (index + 1) * bars)).filter((v) => !Number.isNaN(v));
+
+describe("computePanel", () => {
+  test("flat matrix with shape, multi-output indicator", () => {
+    const closes = [symbol(0, 40), symbol(1, 40), symbol(2, 40)];
+    const out = computePanel({ close: Float64Array.from(closes.flat()), shape: [3, 40] }, { indicator: "standardIndicators.bollingerBands" });
+    assert.deepStrictEqual(out.shape, [3, 40]);
+    closes.forEach((close, i) => {
+      const expected = standardIndicators.bulk.bollingerBandsColumns(close);
+      for (const name of ["lower", "middle", "upper"]) {
+        assert.deepStrictEqual(row(out.columns[name], out.shape, i), Array.from(expected[name]));
+      }
+    });
+  });
+
+  test("per-symbol arrays of differing lengths are right-aligned", () => {
+    const highs = [symbol(0, 30), symbol(1, 45)];
+    const lows = highs.map((h) => h.map((v) => v - 2));
+    const out = computePanel({ high: highs, low: lows }, { indicator: "trendIndicators.aroonIndicator", params: { period: 10 } });
+    assert.deepStrictEqual(out.shape, [2, 45]);
+    const [, bars] = out.shape;
+    highs.forEach((high, i) => {
+      const expected = trendIndicators.bulk.aroonIndicatorColumns(high, lows[i], 10);
+      for (const [name, values] of Object.entries(expected)) {
+        // Latest bar in the last column
+        assert.strictEqual(out.columns[name][i * bars + bars - 1], values[values.length - 1]);
+        assert.deepStrictEqual(row(out.columns[name], out.shape, i), Array.from(values));
+      }
+    });
+  });
+
+  test("single-output indicators return values, specs may be JSON", () => {
+    const highs = [symbol(0, 20), symbol(1, 25)];
+    const out = computePanel({ high: highs }, JSON.stringify({ indicator: "trendIndicators.aroonUp", params: { period: 5 } }));
+    assert.strictEqual(out.columns, undefined);
+    highs.forEach((high, i) => {
+      assert.deepStrictEqual(row(out.values, out.shape, i), Array.from(trendIndicators.bulk.aroonUp(high, 5)));
+    });
+  });
+
+  test("reports the failing symbol", () => {
+    assert.throws(
+      () => computePanel({ close: [symbol(0, 40), symbol(1, 10)] }, { indicator: "standardIndicators.bollingerBands" }),
+      (err) => err.code === "PeriodTooLong" && err.values.symbol === 1
+    );
+    assert.throws(
+      () => computePanel({ close: new Float64Array(10), shape: [3, 4] }, { indicator: "standardIndicators.simpleMovingAverage", params: { period: 2 } }),
+      (err) => err.code === "LengthMismatch"
+    );
+  });
+});
@@ -0,0 +1,66 @@
+import { test, describe, before } from "node:test";
+import assert from "node:assert/strict";
+
+import init, * as api from "../index.node.js";
+
+const { listIndicators, describeIndicator } = api;
+
+before(async () => {
+  await init();
+});
+
+describe("indicator registry", () => {
+  test("every entry matches an exported function and its arity", () => {
+    const entries = listIndicators();
+    assert.ok(entries.length > 50);
+    for (const info of entries) {
+      for (const kind of ["single", "bulk"]) {
+        const signature = info[kind];
+        if (!signature) continue;
+        const fn = api[info.module][kind][info.name];
+        assert.strictEqual(typeof fn, "function", `${info.module}.${kind}.${info.name}`);
+        assert.strictEqual(
+          fn.length,
+          signature.inputs.length + signature.params.length + signature.optional.length,
+          `${info.module}.${kind}.${info.name}`
+        );
+        for (const name of signature.params) {
+          assert.ok(info.params.some((p) => p.name === name), `${info.name}: ${name}`);
+        }
+      }
+    }
+  });
+
+  test("defaults are within range and enum choices exist", () => {
+    for (const info of listIndicators()) {
+      for (const param of info.params) {
+        if (param.choices) {
+          assert.ok(param.choices.includes(param.default), `${info.name}: ${param.name}`);
+          // ConstantModelFamily parameters also take the ConstantModelType values
+          const enums = param.type === "ConstantModelFamily" ? [api.ConstantModelType, api.ConstantModelFamily] : [api[param.type]];
+          for (const choice of param.choices) {
+            assert.ok(enums.some((values) => values[choice] !== undefined), `${info.name}: ${choice}`);
+          }
+        } else if (param.default !== undefined) {
+          if (param.min !== undefined) assert.ok(param.default >= param.min, `${info.name}: ${param.name}`);
+          if (param.max !== undefined) assert.ok(param.default <= param.max, `${info.name}: ${param.name}`);
+        }
+      }
+    }
+  });
+
+  test("multi-output names match the Columns keys", () => {
+    const info = describeIndicator("trendIndicators.directionalMovementSystem");
+    assert.deepEqual(info.outputs, ["pdi", "ndi", "adx", "adxr"]);
+    assert.deepEqual(info.bulk, { inputs: ["highs", "lows", "close"], params: ["period", "constantModelType"], optional: [] });
+    assert.deepEqual(describeIndicator("movingAverage.movingAverage").bulk.optional, ["volume"]);
+  });
+
+  test("unknown indicator throws", () => {
+    assert.throws(() => describeIndicator("trendIndicators.nope"), {
+      name: "TiEngineError",
+      code: "InvalidParameter",
+      argument: "indicator",
+    });
+  });
+});
@@ -1,7 +1,7 @@
-import { test, describe, before } from "node:test";
+import { test, describe, before, after } from "node:test";
 import assert from "node:assert/strict";
 
-import init, { standardIndicators } from "../index.node.js";
+import init, { standardIndicators, setAlignedOutput, isAlignedOutput } from "../index.node.js";
 
 before(async () => {
   await init();
@@ -68,19 +68,19 @@ describe("standardIndicators.bulk (parity)", () => {
   test("simpleMovingAverage", () => {
     const prices = [100.2, 100.46, 100.53, 100.38, 100.19];
     const out = standardIndicators.bulk.simpleMovingAverage(prices, 4);
-    assert.deepEqual(out, [100.3925, 100.39]);
+    assert.deepEqual(Array.from(out), [100.3925, 100.39]);
   });
 
   test("smoothedMovingAverage", () => {
     const prices = [100.2, 100.46, 100.53, 100.38, 100.19];
     const out = standardIndicators.bulk.smoothedMovingAverage(prices, 4);
-    assert.deepEqual(out, [100.40982857142858, 100.35371428571428]);
+    assert.deepEqual(Array.from(out), [100.40982857142858, 100.35371428571428]);
   });
 
   test("exponentialMovingAverage", () => {
     const prices = [100.2, 100.46, 100.53, 100.38, 100.19];
     const out = standardIndicators.bulk.exponentialMovingAverage(prices, 4);
-    assert.deepEqual(out, [100.41672794117645, 100.32544117647058]);
+    assert.deepEqual(Array.from(out), [100.41672794117645, 100.32544117647058]);
   });
 
   test("bollingerBands", () => {
@@ -97,6 +97,22 @@ describe("standardIndicators.bulk (parity)", () => {
     ]);
   });
 
+  test("bollingerBandsColumns", () => {
+    const prices = [
+      99.39, 99.59, 99.68, 99.98, 99.06, 98.39, 99.23, 98.66, 98.88, 98.31, 98.16, 97.87,
+      98.74, 99.47, 98.86, 99.73, 100.06, 100.66, 99.69, 100.63, 99.75, 99.55, 98.8,
+    ];
+    const out = standardIndicators.bulk.bollingerBandsColumns(prices);
+    assert.ok(out.lower instanceof Float64Array);
+    assert.deepEqual(Array.from(out.lower), [
+      97.73388801467088, 97.7373030306026, 97.73687492346315, 97.69218538980725,
+    ]);
+    assert.deepEqual(Array.from(out.middle), [99.25200000000002, 99.27000000000001, 99.268, 99.224]);
+    assert.deepEqual(Array.from(out.upper), [
+      100.77011198532917, 100.80269696939742, 100.79912507653685, 100.75581461019276,
+    ]);
+  });
+
   test("macd", () => {
     const prices = [
       99.39, 99.59, 99.68, 99.98, 99.06, 98.39, 99.23, 98.66, 98.88, 98.31, 98.16, 97.87,
@@ -117,6 +133,32 @@ describe("standardIndicators.bulk (parity)", () => {
       98.03, 98.21, 98.05, 98.24,
     ];
     const out = standardIndicators.bulk.rsi(prices);
-    assert.deepEqual(out, [49.49693728728258, 51.7387808206744, 49.93948387240627]);
+    assert.deepEqual(Array.from(out), [49.49693728728258, 51.7387808206744, 49.93948387240627]);
+  });
+});
+
+describe("standardIndicators.bulk (aligned output)", () => {
+  after(() => setAlignedOutput(false));
+
+  test("simpleMovingAverage is NaN-padded to the input length", () => {
+    const prices = [100.2, 100.46, 100.53, 100.38, 100.19];
+    setAlignedOutput(true);
+    assert.strictEqual(isAlignedOutput(), true);
+    const out = standardIndicators.bulk.simpleMovingAverage(prices, 4);
+    assert.deepEqual(Array.from(out), [NaN, NaN, NaN, 100.3925, 100.39]);
+  });
+
+  test("bollingerBandsColumns pads every column", () => {
+    const prices = [
+      99.39, 99.59, 99.68, 99.98, 99.06, 98.39, 99.23, 98.66, 98.88, 98.31, 98.16, 97.87,
+      98.74, 99.47, 98.86, 99.73, 100.06, 100.66, 99.69, 100.63, 99.75, 99.55, 98.8,
+    ];
+    setAlignedOutput(true);
+    const out = standardIndicators.bulk.bollingerBandsColumns(prices);
+    for (const column of [out.lower, out.middle, out.upper]) {
+      assert.strictEqual(column.length, prices.length);
+      assert.ok(Number.isNaN(column[18]));
+      assert.ok(!Number.isNaN(column[19]));
+    }
   });
 });
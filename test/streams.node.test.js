@@ -0,0 +1,682 @@
+import { test, describe, before } from "node:test";
+import assert from "node:assert/strict";
+
+import init, {
+  SimpleMovingAverageStream,
+  ExponentialMovingAverageStream,
+  SmoothedMovingAverageStream,
+  MedianStream,
+  ModeStream,
+  McGinleyDynamicStream,
+  KaufmanAdaptiveMovingAverageStream,
+  FractalAdaptiveMovingAverageStream,
+  VariableIndexDynamicAverageStream,
+  ArnaudLegouxMovingAverageStream,
+  TillsonT3Stream,
+  RelativeStrengthIndexStream,
+  StochasticOscillatorStream,
+  SlowStochasticStream,
+  SlowestStochasticStream,
+  WilliamsPercentRStream,
+  MoneyFlowIndexStream,
+  RateOfChangeStream,
+  OnBalanceVolumeStream,
+  CommodityChannelIndexStream,
+  McGinleyDynamicCommodityChannelIndexStream,
+  MacdLineStream,
+  McGinleyDynamicMacdLineStream,
+  ChaikinOscillatorStream,
+  PercentagePriceOscillatorStream,
+  ChandeMomentumOscillatorStream,
+  MovingConstantEnvelopesStream,
+  McGinleyDynamicEnvelopesStream,
+  MovingConstantBandsStream,
+  McGinleyDynamicBandsStream,
+  IchimokuCloudStream,
+  DonchianChannelsStream,
+  KeltnerChannelStream,
+  SupertrendStream,
+  ParabolicSarStream,
+  DirectionalMovementSystemStream,
+  VolumeFlowStream,
+  ConstantModelType,
+  ConstantModelFamily,
+  Position,
+  DeviationModel,
+  standardIndicators,
+  momentumIndicators,
+  movingAverage,
+  candleIndicators,
+  otherIndicators,
+  trendIndicators,
+  strengthIndicators,
+  setAlignedOutput,
+} from "../index.node.js";
+
+before(async () => {
+  await init();
+});
+
+const prices = Array.from({ length: 200 }, (_, i) => 100 + Math.sin(i / 5) * 7 + Math.cos(i / 3) * 2 + (i % 7) * 0.37);
+
+// Stream output for every price, without the warm-up NaNs
+const run = (stream, values = prices) => values.map((p) => stream.push(p)).slice(stream.period - 1);
+
+describe("moving average streams", () => {
+  for (const period of [1, 5, 20]) {
+    test(`SMA, EMA and SMMA match the bulk functions (period ${period})`, () => {
+      assert.deepStrictEqual(run(new SimpleMovingAverageStream(period)), Array.from(standardIndicators.bulk.simpleMovingAverage(prices, period)));
+      assert.deepStrictEqual(run(new ExponentialMovingAverageStream(period)), Array.from(standardIndicators.bulk.exponentialMovingAverage(prices, period)));
+      assert.deepStrictEqual(run(new SmoothedMovingAverageStream(period)), Array.from(standardIndicators.bulk.smoothedMovingAverage(prices, period)));
+    });
+
+    test(`median and mode match the constant models (period ${period})`, () => {
+      assert.deepStrictEqual(run(new MedianStream(period)), Array.from(momentumIndicators.bulk.signalLine(prices, ConstantModelType.SimpleMovingMedian, period)));
+      assert.deepStrictEqual(run(new ModeStream(period)), Array.from(momentumIndicators.bulk.signalLine(prices, ConstantModelType.SimpleMovingMode, period)));
+    });
+
+    test(`McGinley dynamic matches the bulk function (period ${period})`, () => {
+      assert.deepStrictEqual(run(new McGinleyDynamicStream(period)), Array.from(movingAverage.bulk.mcginleyDynamic(prices, 0, period)));
+      assert.deepStrictEqual(
+        run(new McGinleyDynamicStream(period, 101)),
+        Array.from(movingAverage.bulk.mcginleyDynamic(prices, 101, period))
+      );
+    });
+  }
+
+  test("adaptive averages match the bulk functions", () => {
+    const tailOf = (stream, bulk) => {
+      const out = prices.map((p) => stream.push(p));
+      assert.ok(out.slice(0, prices.length - bulk.length).every(Number.isNaN));
+      assert.deepStrictEqual(out.slice(prices.length - bulk.length), Array.from(bulk));
+    };
+    tailOf(new KaufmanAdaptiveMovingAverageStream(10, 2, 30), movingAverage.bulk.kaufmanAdaptiveMovingAverage(prices, 0, 10, 2, 30));
+    tailOf(new FractalAdaptiveMovingAverageStream(16, 101), movingAverage.bulk.fractalAdaptiveMovingAverage(prices, 101, 16));
+    tailOf(new VariableIndexDynamicAverageStream(14, 9), movingAverage.bulk.variableIndexDynamicAverage(prices, 0, 14, 9));
+    tailOf(new ArnaudLegouxMovingAverageStream(9, 0.85, 6), movingAverage.bulk.arnaudLegouxMovingAverage(prices, 9, 0.85, 6));
+    tailOf(new TillsonT3Stream(5, 0.7), movingAverage.bulk.tillsonT3(prices, 5, 0.7));
+    assert.throws(() => new FractalAdaptiveMovingAverageStream(15), (err) => err.code === "InvalidParameter" && err.argument === "period");
+    assert.throws(() => new TillsonT3Stream(5, 2), (err) => err.code === "InvalidParameter" && err.argument === "volumeFactor");
+  });
+
+  test("warm-up, value, isReady and reset", () => {
+    const sma = new SimpleMovingAverageStream(3);
+    assert.ok(Number.isNaN(sma.push(1)));
+    assert.ok(Number.isNaN(sma.push(2)));
+    assert.strictEqual(sma.isReady, false);
+    assert.strictEqual(sma.push(3), 2);
+    assert.strictEqual(sma.value, 2);
+    assert.strictEqual(sma.isReady, true);
+    sma.reset();
+    assert.ok(Number.isNaN(sma.value));
+    assert.ok(Number.isNaN(sma.push(10)));
+    sma.free();
+  });
+
+  test("median ignores NaN like the bulk function", () => {
+    const median = new MedianStream(3);
+    [1, NaN, 3].forEach((p) => median.push(p));
+    assert.strictEqual(median.value, 2);
+  });
+
+  test("rejects a zero period", () => {
+    assert.throws(() => new ModeStream(0), (err) => err.code === "InvalidParameter" && err.argument === "period");
+  });
+});
+
+const highs = prices.map((p, i) => p + 1.5 + Math.sin(i * 3) * 0.5);
+const lows = prices.map((p, i) => p - 1.2 - Math.cos(i * 2) * 0.5);
+const volumes = prices.map((_, i) => 1000 + Math.sin(i * 7) * 300);
+
+// Last `bulk.length` stream outputs, where `push` maps bar index to the pushed value
+const tail = (push, bulk) => prices.map((_, i) => push(i)).slice(prices.length - bulk.length);
+
+describe("momentum streams", () => {
+  const models = [
+    ConstantModelType.SimpleMovingAverage,
+    ConstantModelType.ExponentialMovingAverage,
+    ConstantModelType.SimpleMovingMedian,
+  ];
+
+  for (const model of models) {
+    test(`RSI, CCI and PPO match the bulk functions (model ${model})`, () => {
+      const rsi = new RelativeStrengthIndexStream(model, 14);
+      const rsiBulk = Array.from(momentumIndicators.bulk.relativeStrengthIndex(prices, model, 14));
+      assert.deepStrictEqual(tail((i) => rsi.push(prices[i]), rsiBulk), rsiBulk);
+
+      const cci = new CommodityChannelIndexStream(model, DeviationModel.MeanAbsoluteDeviation, 0.015, 20);
+      const cciBulk = Array.from(
+        momentumIndicators.bulk.commodityChannelIndex(prices, model, DeviationModel.MeanAbsoluteDeviation, 0.015, 20)
+      );
+      assert.deepStrictEqual(tail((i) => cci.push(prices[i]), cciBulk), cciBulk);
+
+      const ppo = new PercentagePriceOscillatorStream(12, 26, model);
+      const ppoBulk = Array.from(momentumIndicators.bulk.percentagePriceOscillator(prices, 12, 26, model));
+      assert.deepStrictEqual(tail((i) => ppo.push(prices[i]), ppoBulk), ppoBulk);
+    });
+
+    test(`slow and slowest stochastics chain like the bulk functions (model ${model})`, () => {
+      const stochastics = momentumIndicators.bulk.stochasticOscillator(prices, 14);
+      const slowBulk = Array.from(momentumIndicators.bulk.slowStochastic(stochastics, model, 3));
+      const slowestBulk = Array.from(momentumIndicators.bulk.slowestStochastic(slowBulk, model, 3));
+      const slow = new SlowStochasticStream(14, model, 3);
+      const slowest = new SlowestStochasticStream(14, model, 3, model, 3);
+      assert.deepStrictEqual(tail((i) => slow.push(prices[i]), slowBulk), slowBulk);
+      assert.deepStrictEqual(tail((i) => slowest.push(prices[i]), slowestBulk), slowestBulk);
+      assert.strictEqual(slowest.isReady, true);
+    });
+
+    test(`MACD and signal line match the bulk functions (model ${model})`, () => {
+      const ema = ConstantModelType.ExponentialMovingAverage;
+      const macd = new MacdLineStream(12, model, 26, ema, 9, model);
+      const signals = [];
+      const macdBulk = Array.from(momentumIndicators.bulk.macdLine(prices, 12, model, 26, ema));
+      const signalBulk = Array.from(momentumIndicators.bulk.signalLine(macdBulk, model, 9));
+      assert.deepStrictEqual(
+        tail((i) => {
+          macd.push(prices[i]);
+          signals.push(macd.signal);
+          return macd.value;
+        }, macdBulk),
+        macdBulk
+      );
+      assert.deepStrictEqual(signals.slice(signals.length - signalBulk.length), signalBulk);
+    });
+  }
+
+  test("RSI and MACD take the moving average families", () => {
+    const ema = ConstantModelType.ExponentialMovingAverage;
+    for (const model of [ConstantModelFamily.HullMovingAverage, ConstantModelFamily.ZeroLagExponentialMovingAverage]) {
+      const rsi = new RelativeStrengthIndexStream(model, 14);
+      const rsiBulk = Array.from(momentumIndicators.bulk.relativeStrengthIndex(prices, model, 14));
+      assert.deepStrictEqual(tail((i) => rsi.push(prices[i]), rsiBulk), rsiBulk);
+
+      const macd = new MacdLineStream(12, model, 26, ema, 9, model);
+      const signals = [];
+      const macdBulk = Array.from(momentumIndicators.bulk.macdLine(prices, 12, model, 26, ema));
+      const signalBulk = Array.from(momentumIndicators.bulk.signalLine(macdBulk, model, 9));
+      assert.deepStrictEqual(
+        tail((i) => {
+          macd.push(prices[i]);
+          signals.push(macd.signal);
+          return macd.value;
+        }, macdBulk),
+        macdBulk
+      );
+      assert.deepStrictEqual(signals.slice(signals.length - signalBulk.length), signalBulk);
+    }
+  });
+
+  test("stochastic, Williams %R, MFI and Chande match the bulk functions", () => {
+    for (const period of [1, 5, 14]) {
+      const so = new StochasticOscillatorStream(period);
+      const soBulk = Array.from(momentumIndicators.bulk.stochasticOscillator(prices, period));
+      assert.deepStrictEqual(tail((i) => so.push(prices[i]), soBulk), soBulk);
+
+      const wpr = new WilliamsPercentRStream(period);
+      const wprBulk = Array.from(momentumIndicators.bulk.williamsPercentR(highs, lows, prices, period));
+      assert.deepStrictEqual(tail((i) => wpr.push(highs[i], lows[i], prices[i]), wprBulk), wprBulk);
+
+      const mfi = new MoneyFlowIndexStream(period);
+      const mfiBulk = Array.from(momentumIndicators.bulk.moneyFlowIndex(prices, volumes, period));
+      assert.deepStrictEqual(tail((i) => mfi.push(prices[i], volumes[i]), mfiBulk), mfiBulk);
+
+      const cmo = new ChandeMomentumOscillatorStream(period);
+      const cmoBulk = Array.from(momentumIndicators.bulk.chandeMomentumOscillator(prices, period));
+      assert.deepStrictEqual(tail((i) => cmo.push(prices[i]), cmoBulk), cmoBulk);
+    }
+  });
+
+  test("rate of change and on-balance volume match the bulk functions", () => {
+    const roc = new RateOfChangeStream();
+    const rocBulk = Array.from(momentumIndicators.bulk.rateOfChange(prices));
+    assert.deepStrictEqual(tail((i) => roc.push(prices[i]), rocBulk), rocBulk);
+
+    const obv = new OnBalanceVolumeStream(250);
+    const obvBulk = Array.from(momentumIndicators.bulk.onBalanceVolume(prices, volumes, 250));
+    assert.deepStrictEqual(tail((i) => obv.push(prices[i], volumes[i]), obvBulk), obvBulk);
+  });
+
+  test("McGinley variants and Chaikin carry their state like the bulk functions", () => {
+    const cci = new McGinleyDynamicCommodityChannelIndexStream(0, DeviationModel.StandardDeviation, 0.015, 20);
+    const cciBulk = Array.from(
+      momentumIndicators.bulk.mcginleyDynamicCommodityChannelIndexColumns(prices, 0, DeviationModel.StandardDeviation, 0.015, 20).cci
+    );
+    assert.deepStrictEqual(tail((i) => cci.push(prices[i]), cciBulk), cciBulk);
+
+    const macd = new McGinleyDynamicMacdLineStream(12, 0, 26, 0);
+    const macdBulk = Array.from(momentumIndicators.bulk.mcginleyDynamicMacdLineColumns(prices, 12, 0, 26, 0).macd);
+    assert.deepStrictEqual(tail((i) => macd.push(prices[i]), macdBulk), macdBulk);
+
+    const sma = ConstantModelType.SimpleMovingAverage;
+    const chaikin = new ChaikinOscillatorStream(3, 10, 0, sma, sma);
+    const chaikinBulk = Array.from(
+      momentumIndicators.bulk.chaikinOscillatorColumns(highs, lows, prices, volumes, 3, 10, 0, sma, sma).oscillator
+    );
+    assert.deepStrictEqual(tail((i) => chaikin.push(highs[i], lows[i], prices[i], volumes[i]), chaikinBulk), chaikinBulk);
+  });
+
+  test("warm-up and validation", () => {
+    const macd = new MacdLineStream(2, ConstantModelType.SimpleMovingAverage, 3, ConstantModelType.SimpleMovingAverage);
+    assert.ok(Number.isNaN(macd.push(1)));
+    assert.ok(Number.isNaN(macd.push(2)));
+    assert.strictEqual(macd.push(3), 0.5);
+    assert.ok(Number.isNaN(macd.signal));
+    assert.throws(
+      () => new MacdLineStream(26, ConstantModelType.SimpleMovingAverage, 12, ConstantModelType.SimpleMovingAverage),
+      (err) => err.code === "InvalidParameter" && err.argument === "shortPeriod"
+    );
+    const cci = new CommodityChannelIndexStream(ConstantModelType.SimpleMovingAverage, DeviationModel.LogStandardDeviation, 0.015, 3);
+    assert.throws(() => cci.push(-1), (err) => err.code === "InvalidParameter");
+    assert.strictEqual(cci.isReady, false);
+  });
+});
+
+// Point fields of the last `columns.<first field>.length` pushes, as bulk-style columns
+const pointColumns = (push, columns, fields) => {
+  const points = prices.map((_, i) => push(i));
+  const length = columns[Object.keys(columns)[0]].length;
+  return Object.fromEntries(
+    Object.keys(columns).map((name, f) => [name, Float64Array.from(points.slice(points.length - length), (p) => p[fields[f]])])
+  );
+};
+
+describe("channel and band streams", () => {
+  const sma = ConstantModelType.SimpleMovingAverage;
+  const ema = ConstantModelType.ExponentialMovingAverage;
+  const band = ["lower", "middle", "upper"];
+  const mcginleyBand = ["lower", "mcginley", "upper"];
+
+  test("envelopes and bands match the bulk functions", () => {
+    const families = [ConstantModelFamily.TripleExponentialMovingAverage, ConstantModelFamily.TriangularMovingAverage];
+    for (const model of [sma, ema, ConstantModelType.SimpleMovingMedian, ...families]) {
+      const envelopes = new MovingConstantEnvelopesStream(model, 3, 20);
+      const envelopesBulk = candleIndicators.bulk.movingConstantEnvelopesColumns(prices, model, 3, 20);
+      assert.deepStrictEqual(pointColumns((i) => envelopes.push(prices[i]), envelopesBulk, band), envelopesBulk);
+
+      const bands = new MovingConstantBandsStream(model, DeviationModel.StandardDeviation, 2, 20);
+      const bandsBulk = candleIndicators.bulk.movingConstantBandsColumns(prices, model, DeviationModel.StandardDeviation, 2, 20);
+      assert.deepStrictEqual(pointColumns((i) => bands.push(prices[i]), bandsBulk, band), bandsBulk);
+    }
+
+    const envelopes = new McGinleyDynamicEnvelopesStream(3, 0, 20);
+    const envelopesBulk = candleIndicators.bulk.mcginleyDynamicEnvelopesColumns(prices, 3, 0, 20);
+    assert.deepStrictEqual(pointColumns((i) => envelopes.push(prices[i]), envelopesBulk, mcginleyBand), envelopesBulk);
+
+    const bands = new McGinleyDynamicBandsStream(DeviationModel.MeanAbsoluteDeviation, 2, 0, 20);
+    const bandsBulk = candleIndicators.bulk.mcginleyDynamicBandsColumns(prices, DeviationModel.MeanAbsoluteDeviation, 2, 0, 20);
+    assert.deepStrictEqual(pointColumns((i) => bands.push(prices[i]), bandsBulk, mcginleyBand), bandsBulk);
+  });
+
+  test("Ichimoku, Donchian and Keltner match the bulk functions", () => {
+    const cloud = new IchimokuCloudStream(9, 26, 52);
+    const cloudBulk = candleIndicators.bulk.ichimokuCloudColumns(highs, lows, prices, 9, 26, 52);
+    assert.deepStrictEqual(
+      pointColumns((i) => cloud.push(highs[i], lows[i], prices[i]), cloudBulk, ["spanA", "spanB", "base", "conversion", "laggingClose"]),
+      cloudBulk
+    );
+
+    const donchian = new DonchianChannelsStream(20);
+    const donchianBulk = candleIndicators.bulk.donchianChannelsColumns(highs, lows, 20);
+    assert.deepStrictEqual(pointColumns((i) => donchian.push(highs[i], lows[i]), donchianBulk, band), donchianBulk);
+
+    const keltner = new KeltnerChannelStream(ema, sma, 2, 20);
+    const keltnerBulk = candleIndicators.bulk.keltnerChannelColumns(highs, lows, prices, ema, sma, 2, 20);
+    assert.deepStrictEqual(pointColumns((i) => keltner.push(highs[i], lows[i], prices[i]), keltnerBulk, band), keltnerBulk);
+  });
+
+  test("Supertrend carries its final bands and trend from the bulk value", () => {
+    const period = 10;
+    const supertrend = new SupertrendStream(sma, 3, period);
+    // The bulk Supertrend is the basic upper band around the Donchian middle, ATRs away
+    const basicUpper = Array.from(candleIndicators.bulk.supertrend(highs, lows, prices, sma, 3, period));
+    const middle = candleIndicators.bulk.donchianChannelsColumns(highs, lows, period).middle;
+    const atr = otherIndicators.bulk.averageTrueRange(prices, highs, lows, sma, period);
+    for (let i = 0; i < period - 1; i++) {
+      assert.ok(Number.isNaN(supertrend.push(highs[i], lows[i], prices[i]).upper));
+    }
+
+    let previous = { lower: NaN, upper: NaN };
+    let position = Position.Short;
+    let reversals = 0;
+    basicUpper.forEach((basic, j) => {
+      const i = j + period - 1;
+      const basicLower = middle[j] - 3 * atr[j];
+      const upper = Number.isNaN(previous.upper) || basic < previous.upper || prices[i - 1] > previous.upper ? basic : previous.upper;
+      const lower = Number.isNaN(previous.lower) || basicLower > previous.lower || prices[i - 1] < previous.lower ? basicLower : previous.lower;
+      const next = position === Position.Short ? (prices[i] > upper ? Position.Long : position) : prices[i] < lower ? Position.Short : position;
+      reversals += next === position ? 0 : 1;
+      position = next;
+
+      const point = supertrend.push(highs[i], lows[i], prices[i]);
+      assert.deepStrictEqual([point.lower, point.middle, point.upper], [lower, middle[j], upper]);
+      assert.strictEqual(supertrend.position, position);
+      assert.strictEqual(supertrend.supertrend, position === Position.Long ? lower : upper);
+      previous = point;
+    });
+    assert.ok(reversals > 1);
+
+    supertrend.reset();
+    assert.strictEqual(supertrend.position, Position.Short);
+    assert.ok(Number.isNaN(supertrend.supertrend));
+  });
+
+  test("warm-up returns NaN points until the longest period", () => {
+    const cloud = new IchimokuCloudStream(2, 3, 4);
+    for (let i = 0; i < 3; i++) {
+      assert.ok(Number.isNaN(cloud.push(highs[i], lows[i], prices[i]).spanB));
+    }
+    assert.strictEqual(cloud.isReady, false);
+    assert.ok(!Number.isNaN(cloud.push(highs[3], lows[3], prices[3]).spanB));
+    assert.strictEqual(cloud.value.laggingClose, prices[1]);
+    cloud.reset();
+    assert.ok(Number.isNaN(cloud.value.spanA));
+  });
+});
+
+describe("parabolic SAR stream", () => {
+  for (const position of [Position.Long, Position.Short]) {
+    test(`matches the bulk function bar for bar (start ${position})`, () => {
+      for (const previousSar of [0, 95]) {
+        const sar = new ParabolicSarStream(0.02, 0.2, 0.02, position, previousSar);
+        const bulk = Array.from(trendIndicators.bulk.parabolicTimePriceSystem(highs, lows, 0.02, 0.2, 0.02, position, previousSar));
+        assert.deepStrictEqual(prices.map((_, i) => sar.push(highs[i], lows[i])), bulk);
+      }
+    });
+  }
+
+  test("reports reversals, position and acceleration factor", () => {
+    const sar = new ParabolicSarStream(0.02, 0.2, 0.02, Position.Long);
+    let reversals = 0;
+    for (let i = 0; i < prices.length; i++) {
+      const position = sar.position;
+      sar.push(highs[i], lows[i]);
+      if (sar.reversal) {
+        reversals++;
+        assert.notStrictEqual(sar.position, position);
+        assert.strictEqual(sar.accelerationFactor, 0.02);
+      }
+      assert.ok(sar.accelerationFactor <= 0.2 + 1e-9);
+      if (sar.position === Position.Long) {
+        assert.ok(sar.value <= lows[i]);
+      } else {
+        assert.ok(sar.value >= highs[i]);
+      }
+    }
+    assert.ok(reversals > 0);
+    assert.throws(() => new ParabolicSarStream(0.3, 0.2, 0.02, Position.Long), (err) => err.code === "InvalidParameter");
+  });
+});
+
+describe("directional movement system stream", () => {
+  const smma = ConstantModelType.SmoothedMovingAverage;
+  for (const model of [smma, ConstantModelType.ExponentialMovingAverage, ConstantModelType.SimpleMovingMedian]) {
+    test(`matches the bulk function bar for bar (model ${model})`, () => {
+      const dms = new DirectionalMovementSystemStream(14, model);
+      const bulk = trendIndicators.bulk.directionalMovementSystemColumns(highs, lows, prices, 14, model);
+      assert.deepStrictEqual(
+        pointColumns((i) => dms.push(highs[i], lows[i], prices[i]), bulk, ["pdi", "ndi", "adx", "adxr"]),
+        bulk
+      );
+    });
+  }
+
+  test("stays NaN until 3 * period - 2 bars, then resets", () => {
+    const dms = new DirectionalMovementSystemStream(5, smma);
+    for (let i = 0; i < 12; i++) {
+      assert.ok(Number.isNaN(dms.push(highs[i], lows[i], prices[i]).adxr));
+    }
+    assert.strictEqual(dms.isReady, false);
+    const first = dms.push(highs[12], lows[12], prices[12]);
+    assert.strictEqual(dms.isReady, true);
+    assert.strictEqual(first.adx, trendIndicators.bulk.directionalMovementSystemColumns(highs.slice(0, 13), lows.slice(0, 13), prices.slice(0, 13), 5, smma).adx[0]);
+    dms.reset();
+    assert.strictEqual(dms.isReady, false);
+    assert.throws(() => new DirectionalMovementSystemStream(0, smma), (err) => err.code === "InvalidParameter");
+  });
+});
+
+describe("volume flow stream", () => {
+  const flowBulk = (seed, from = 0) => {
+    const [h, l, c, v] = [highs, lows, prices, volumes].map((a) => a.slice(from));
+    return {
+      onBalanceVolume: Array.from(momentumIndicators.bulk.onBalanceVolume(c, v, seed)),
+      accumulationDistribution: Array.from(strengthIndicators.bulk.accumulationDistribution(h, l, c, v, seed)),
+      positiveVolumeIndex: Array.from(strengthIndicators.bulk.positiveVolumeIndex(c, v, seed)),
+      negativeVolumeIndex: Array.from(strengthIndicators.bulk.negativeVolumeIndex(c, v, seed)),
+      volumePriceTrend: Array.from(trendIndicators.bulk.volumePriceTrend(c, v.slice(1), seed)),
+    };
+  };
+  const pushAll = (flow, from = 0) => {
+    const out = { onBalanceVolume: [], accumulationDistribution: [], positiveVolumeIndex: [], negativeVolumeIndex: [], volumePriceTrend: [] };
+    for (let i = from; i < prices.length; i++) {
+      const point = flow.push(highs[i], lows[i], prices[i], volumes[i]);
+      for (const name of Object.keys(out)) out[name].push(point[name]);
+    }
+    return out;
+  };
+
+  for (const seed of [0, 250]) {
+    test(`matches the five bulk functions bar for bar (seed ${seed})`, () => {
+      const flow = new VolumeFlowStream(seed, seed, seed, seed, seed);
+      const out = pushAll(flow);
+      const bulk = flowBulk(seed);
+      assert.deepStrictEqual(out.accumulationDistribution, bulk.accumulationDistribution);
+      for (const name of ["onBalanceVolume", "positiveVolumeIndex", "negativeVolumeIndex", "volumePriceTrend"]) {
+        assert.ok(Number.isNaN(out[name][0]));
+        assert.deepStrictEqual(out[name].slice(1), bulk[name]);
+      }
+    });
+  }
+
+  test("resumes from a JSON snapshot with identical outputs", () => {
+    const whole = new VolumeFlowStream();
+    const expected = pushAll(whole);
+
+    const first = new VolumeFlowStream();
+    for (let i = 0; i < 80; i++) first.push(highs[i], lows[i], prices[i], volumes[i]);
+    const snapshot = JSON.parse(JSON.stringify(first.snapshot()));
+    assert.strictEqual(snapshot.previousClose, prices[79]);
+
+    const resumed = VolumeFlowStream.fromSnapshot(snapshot);
+    assert.strictEqual(resumed.isReady, false);
+    const out = pushAll(resumed, 80);
+    assert.strictEqual(resumed.isReady, true);
+    for (const name of Object.keys(out)) {
+      assert.deepStrictEqual(out[name], expected[name].slice(80));
+    }
+
+    resumed.reset();
+    assert.deepStrictEqual(pushAll(resumed, 80), out);
+  });
+
+  test("rejects malformed snapshots", () => {
+    assert.throws(() => VolumeFlowStream.fromSnapshot({ previousClose: 100 }), (err) => err.code === "InvalidParameter" && err.argument === "previousVolume");
+    assert.throws(() => VolumeFlowStream.fromSnapshot({ onBalanceVolume: "12" }), (err) => err.code === "InvalidParameter");
+    assert.throws(() => VolumeFlowStream.fromSnapshot(42), (err) => err.code === "InvalidParameter");
+  });
+});
+
+describe("open-bar revisions", () => {
+  // Revises each bar a few times (wider highs/lows, drifting closes) before closing it
+  const revise = (stream, bar, close) => {
+    const results = [];
+    for (let i = 0; i < prices.length; i++) {
+      for (const step of [3, -2, 1]) stream.update(...bar(i, step));
+      results.push([stream.update(...bar(i, 0)), close(stream, i)]);
+    }
+    return results;
+  };
+  // Point objects as plain field values, numbers as they are
+  const plain = (fields) => (value) => (fields ? fields.map((f) => value[f]) : value);
+
+  test("updates never count the forming bar twice", () => {
+    const sma = ConstantModelType.SimpleMovingAverage;
+    const ema = ConstantModelType.ExponentialMovingAverage;
+    const price = (i, step) => [prices[i] + step];
+    const cases = [
+      [() => new ExponentialMovingAverageStream(20), price],
+      [() => new MacdLineStream(12, ema, 26, ema, 9), price],
+      [() => new RelativeStrengthIndexStream(ConstantModelType.SmoothedMovingAverage, 14), price],
+      [() => new IchimokuCloudStream(9, 26, 52), (i, step) => [highs[i] + step, lows[i] - step, prices[i] + step], ["spanA", "spanB", "base", "conversion", "laggingClose"]],
+      [() => new ParabolicSarStream(0.02, 0.2, 0.02, Position.Long), (i, step) => [highs[i] + step, lows[i] - step]],
+      [() => new DirectionalMovementSystemStream(5, sma), (i, step) => [highs[i] + step, lows[i] - step, prices[i]], ["pdi", "ndi", "adx", "adxr"]],
+      [() => new ChaikinOscillatorStream(3, 10, 0, sma, sma), (i, step) => [highs[i] + step, lows[i] - step, prices[i], volumes[i] + step]],
+    ];
+    for (const [create, bar, fields] of cases) {
+      const closed = create();
+      const expected = prices.map((_, i) => closed.push(...bar(i, 0))).map(plain(fields));
+      const revised = revise(create(), bar, (stream, i) => (i % 2 ? stream.commit(...bar(i, 0)) : stream.push(...bar(i, 0))));
+      assert.deepStrictEqual(revised.map(([live]) => live).map(plain(fields)), expected);
+      assert.deepStrictEqual(revised.map(([, committed]) => committed).map(plain(fields)), expected);
+    }
+  });
+
+  test("getters follow the revision and reset drops it", () => {
+    const macd = new MacdLineStream(3, ConstantModelType.SimpleMovingAverage, 5, ConstantModelType.SimpleMovingAverage, 2);
+    for (let i = 0; i < 10; i++) macd.push(prices[i]);
+    const committed = macd.signal;
+    macd.update(prices[10] + 50);
+    assert.notStrictEqual(macd.signal, committed);
+    const expected = new MacdLineStream(3, ConstantModelType.SimpleMovingAverage, 5, ConstantModelType.SimpleMovingAverage, 2);
+    for (let i = 0; i < 10; i++) expected.push(prices[i]);
+    expected.push(prices[10] + 50);
+    assert.strictEqual(macd.signal, expected.signal);
+    macd.reset();
+    assert.strictEqual(macd.isReady, false);
+    assert.ok(Number.isNaN(macd.update(prices[0])));
+  });
+
+  test("snapshots and failed revisions keep the committed state", () => {
+    const flow = new VolumeFlowStream();
+    for (let i = 0; i < 20; i++) flow.push(highs[i], lows[i], prices[i], volumes[i]);
+    const snapshot = JSON.stringify(flow.snapshot());
+    flow.update(highs[20], lows[20], prices[20], volumes[20]);
+    assert.strictEqual(JSON.stringify(flow.snapshot()), snapshot);
+
+    const cci = new CommodityChannelIndexStream(ConstantModelType.SimpleMovingAverage, DeviationModel.LogStandardDeviation, 0.015, 3);
+    for (let i = 0; i < 5; i++) cci.push(prices[i]);
+    const value = cci.value;
+    assert.throws(() => cci.update(-1), (err) => err.code === "InvalidParameter");
+    assert.strictEqual(cci.value, value);
+  });
+});
+
+describe("state snapshots", () => {
+  const sma = ConstantModelType.SimpleMovingAverage;
+  const ema = ConstantModelType.ExponentialMovingAverage;
+  const price = (i) => [prices[i]];
+  const candle = (i) => [highs[i], lows[i], prices[i]];
+  // [create, bar arguments, comparable output of a push]
+  const cases = [
+    [() => new ExponentialMovingAverageStream(7), price, (s, v) => v],
+    [() => new MedianStream(7), price, (s, v) => v],
+    [() => new ModeStream(7), price, (s, v) => v],
+    [() => new MacdLineStream(12, { alphaNum: 1, alphaDen: 2 }, 26, ema, 9), price, (s, v) => [v, s.signal]],
+    [() => new SlowestStochasticStream(5, sma, 3, ConstantModelType.SimpleMovingMedian, 3), price, (s, v) => v],
+    [() => new McGinleyDynamicBandsStream(DeviationModel.MeanAbsoluteDeviation, 2, 0, 10), price, (s, v) => [v.lower, v.mcginley, v.upper]],
+    [() => new MovingConstantEnvelopesStream(ConstantModelFamily.HullMovingAverage, 3, 10), price, (s, v) => [v.lower, v.middle, v.upper]],
+    [() => new KaufmanAdaptiveMovingAverageStream(10, 2, 30, 99), price, (s, v) => v],
+    [() => new VariableIndexDynamicAverageStream(14, 9), price, (s, v) => v],
+    [() => new IchimokuCloudStream(9, 26, 52), candle, (s, v) => [v.spanA, v.spanB, v.laggingClose]],
+    [() => new ParabolicSarStream(0.02, 0.2, 0.02, Position.Short, 0), (i) => [highs[i], lows[i]], (s, v) => [v, s.position, s.accelerationFactor]],
+    [() => new DirectionalMovementSystemStream(5, ConstantModelType.SimpleMovingMode), candle, (s, v) => [v.pdi, v.ndi, v.adx, v.adxr]],
+    [() => new VolumeFlowStream(1, 2, 3, 4, 5), (i) => [...candle(i), volumes[i]], (s, v) => [v.onBalanceVolume, v.negativeVolumeIndex]],
+  ];
+
+  for (const [create, bar, output] of cases) {
+    test(`${create().constructor.name} continues identically after a round trip`, () => {
+      for (const cut of [0, 40, 120]) {
+        const original = create();
+        for (let i = 0; i < cut; i++) original.push(...bar(i));
+        // A forming bar is part of the state too
+        if (cut > 0) original.update(...bar(cut).map((v) => v + 1));
+        const state = original.toState();
+        assert.ok(state instanceof Uint8Array);
+        const restored = original.constructor.fromState(state);
+        assert.deepStrictEqual(restored.toState(), state);
+        for (let i = cut; i < prices.length; i++) {
+          assert.deepStrictEqual(output(restored, restored.push(...bar(i))), output(original, original.push(...bar(i))));
+        }
+      }
+    });
+  }
+
+  test("rejects another version, another class and damaged bytes", () => {
+    const state = new SimpleMovingAverageStream(3).toState();
+    const newer = state.slice();
+    newer[0] += 1;
+    assert.throws(
+      () => SimpleMovingAverageStream.fromState(newer),
+      (err) => err.code === "InvalidState" && err.values.version === state[0] + 1 && err.values.expected === state[0]
+    );
+    assert.throws(() => MedianStream.fromState(state), (err) => err.code === "InvalidState");
+    assert.throws(() => SimpleMovingAverageStream.fromState(state.slice(0, -1)), (err) => err.code === "InvalidState");
+    assert.throws(() => SimpleMovingAverageStream.fromState(new Uint8Array()), (err) => err.code === "InvalidState");
+  });
+
+  test("rejects a zero period instead of failing on the next push", () => {
+    for (const Stream of [SimpleMovingAverageStream, MedianStream, ModeStream]) {
+      const stream = new Stream(3);
+      [1, 2, 3].forEach((p) => stream.push(p));
+      const state = stream.toState();
+      // Version, class name length and class name, then the window period as a u64
+      const period = 1 + 8 + Stream.name.length;
+      assert.strictEqual(state[period], 3);
+      state[period] = 0;
+      assert.throws(() => Stream.fromState(state), (err) => err.code === "InvalidState");
+    }
+  });
+
+  test("rejects a corrupted period instead of allocating for it", () => {
+    for (const Stream of [SimpleMovingAverageStream, MedianStream, ModeStream]) {
+      const stream = new Stream(3);
+      [1, 2, 3].forEach((p) => stream.push(p));
+      const state = stream.toState();
+      // Period 3 + 2^45, beyond any period a constructor takes
+      state[1 + 8 + Stream.name.length + 5] = 0x20;
+      assert.throws(() => Stream.fromState(state), (err) => err.code === "InvalidState");
+      assert.deepStrictEqual(Array.from(Stream.fromState(stream.toState()).extend([4])), Array.from(stream.extend([4])));
+    }
+  });
+});
+
+describe("incremental extend", () => {
+  const sma = ConstantModelType.SimpleMovingAverage;
+  const ema = ConstantModelType.ExponentialMovingAverage;
+  const cuts = [0, 15, 16, 90, 91, prices.length];
+  const chunks = (values) => cuts.slice(1).map((end, i) => values.slice(cuts[i], end));
+
+  test("chunks concatenate to the bulk result", () => {
+    const bands = new MovingConstantBandsStream(sma, DeviationModel.StandardDeviation, 2, 20);
+    const parts = chunks(prices).map((chunk) => bands.extend(chunk));
+    const expected = standardIndicators.bulk.bollingerBandsColumns(prices);
+    for (const column of ["lower", "middle", "upper"]) {
+      assert.deepStrictEqual(Array.from(parts.flatMap((part) => Array.from(part[column]))), Array.from(expected[column]));
+    }
+
+    const macd = new MacdLineStream(12, ema, 26, ema);
+    const out = chunks(prices).flatMap((chunk) => Array.from(macd.extend(chunk)));
+    assert.deepStrictEqual(out, Array.from(momentumIndicators.bulk.macdLine(prices, 12, ema, 26, ema)));
+  });
+
+  test("aligned output keeps one value per input bar", () => {
+    setAlignedOutput(true);
+    try {
+      const stream = new SimpleMovingAverageStream(20);
+      const lengths = chunks(prices).map((chunk) => stream.extend(chunk).length);
+      assert.deepStrictEqual(lengths, chunks(prices).map((chunk) => chunk.length));
+    } finally {
+      setAlignedOutput(false);
+    }
+  });
+
+  test("a failing call keeps none of its bars", () => {
+    const cci = new CommodityChannelIndexStream(sma, DeviationModel.LogStandardDeviation, 0.015, 20);
+    cci.extend(prices.slice(0, 30));
+    const state = cci.toState();
+    assert.throws(() => cci.extend([101, 102, -1, 103]));
+    assert.deepStrictEqual(cci.toState(), state);
+    assert.throws(() => new WilliamsPercentRStream(14).extend(highs, lows, prices.slice(1)), (err) => err.code === "LengthMismatch");
+  });
+});
@@ -44,14 +44,14 @@ describe("strengthIndicators.bulk (parity, one model where applicable)", () => {
     const out = strengthIndicators.bulk.accumulationDistribution(
       highs, lows, close, volume, 0.0
     );
-    assert.deepEqual(out, [-38.28571428571309, 65.05231388329526]);
+    assert.deepEqual(Array.from(out), [-38.28571428571309, 65.05231388329526]);
   });
 
   test("positiveVolumeIndex (no previous)", () => {
     const close = [100.14, 98.98, 99.07, 100.1];
     const volume = [1000.0, 1200.0, 1300.0, 1100.0];
     const out = strengthIndicators.bulk.positiveVolumeIndex(close, volume, 0.0);
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       -0.011449598682475618,
       -0.011460009511748427,
       -0.011460009511748427,
@@ -62,7 +62,7 @@ describe("strengthIndicators.bulk (parity, one model where applicable)", () => {
     const close = [100.14, 98.98, 99.07, 100.1];
     const volume = [1000.0, 1200.0, 1300.0, 1100.0];
     const out = strengthIndicators.bulk.negativeVolumeIndex(close, volume, 0.0);
-    assert.deepEqual(out, [0.0, 0.0, 0.010504780356171802]);
+    assert.deepEqual(Array.from(out), [0.0, 0.0, 0.010504780356171802]);
   });
 
   test("relativeVigorIndex (SMA, period 6)", () => {
@@ -73,6 +73,6 @@ describe("strengthIndicators.bulk (parity, one model where applicable)", () => {
     const out = strengthIndicators.bulk.relativeVigorIndex(
       open, high, low, close, ConstantModelType.SimpleMovingAverage, 6
     );
-    assert.deepEqual(out, [0.2063784115302081]);
+    assert.deepEqual(Array.from(out), [0.2063784115302081]);
   });
 });
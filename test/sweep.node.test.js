@@ -0,0 +1,96 @@
+import { test, describe, before } from "node:test";
+import assert from "node:assert/strict";
+
+import init, {
+  sweep,
+  withMissingData,
+  MissingData,
+  OhlcvSeries,
+  ConstantModelType,
+  momentumIndicators,
+  volatilityIndicators,
+} from "../index.node.js";
+
+before(async () => {
+  await init();
+});
+
+const close = Array.from({ length: 50 }, (_, i) => 100 + Math.sin(i / 3) * 4 + i * 0.2);
+const high = close.map((c) => c + 1.5);
+const low = close.map((c) => c - 1.5);
+
+// Row of a sweep result without its NaN padding
+const row = ({ values, shape }, index) => {
+  const bars = shape[shape.length - 1];
+  return Array.from(values.subarray(index * bars, (index + 1) * bars)).filter((v) => !Number.isNaN(v));
+};
+
+describe("sweep", () => {
+  test("one row per period, padded to the number of bars", () => {
+    const out = sweep(close, {
+      indicator: "momentumIndicators.relativeStrengthIndex",
+      params: { constantModelType: ConstantModelType.SmoothedMovingAverage },
+      sweep: { period: { start: 2, end: 20, step: 2 } },
+    });
+    assert.deepStrictEqual(out.shape, [10, close.length]);
+    assert.strictEqual(out.values.length, 10 * close.length);
+    assert.deepStrictEqual(out.axes[0].values, [2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
+    out.axes[0].values.forEach((period, i) => {
+      assert.deepStrictEqual(
+        row(out, i),
+        Array.from(momentumIndicators.bulk.relativeStrengthIndex(close, ConstantModelType.SmoothedMovingAverage, period))
+      );
+    });
+  });
+
+  test("two parameters, including a model, from a JSON string", () => {
+    const spec = JSON.stringify({
+      indicator: "volatilityIndicators.volatilitySystem",
+      sweep: { period: [5, 10], constantMultiplier: [1, 2, 3] },
+      params: { constantModelType: "SimpleMovingAverage" },
+    });
+    const series = new OhlcvSeries(close, high, low, close, close.map(() => 1));
+    const out = series.sweep(spec);
+    assert.deepStrictEqual(out.shape, [2, 3, close.length]);
+    // Row 4 is period 10 (axis 0, index 1) with multiplier 2 (axis 1, index 1)
+    assert.deepStrictEqual(
+      row(out, 4),
+      Array.from(volatilityIndicators.bulk.volatilitySystem(high, low, close, 10, 2, ConstantModelType.SimpleMovingAverage))
+    );
+    series.free();
+  });
+
+  test("fills gaps once for every row like the bulk function", () => {
+    const gappy = close.map((c, i) => (i % 9 === 4 || i === 20 || i === 21 ? NaN : c));
+    for (const policy of [MissingData.ForwardFill, MissingData.Interpolate, MissingData.Skip]) {
+      const out = sweep(gappy, {
+        indicator: "momentumIndicators.relativeStrengthIndex",
+        missingData: policy,
+        sweep: { period: [3, 5, 8] },
+      });
+      out.axes[0].values.forEach((period, i) => {
+        const bars = out.shape[1];
+        const expected = withMissingData(policy, () =>
+          momentumIndicators.bulk.relativeStrengthIndex(gappy, ConstantModelType.SmoothedMovingAverage, period)
+        );
+        const values = Array.from(out.values.subarray(i * bars, (i + 1) * bars));
+        assert.deepStrictEqual(values.slice(bars - expected.length), Array.from(expected));
+      });
+    }
+  });
+
+  test("rejects multi-output indicators and unknown parameters", () => {
+    assert.throws(
+      () => sweep(close, { indicator: "candleIndicators.donchianChannels", sweep: { period: [5] } }),
+      (err) => err.code === "InvalidParameter"
+    );
+    assert.throws(
+      () => sweep(close, { indicator: "momentumIndicators.relativeStrengthIndex", sweep: { nope: [5] } }),
+      (err) => err.argument === "sweep"
+    );
+    assert.throws(
+      () => sweep(close, { indicator: "momentumIndicators.relativeStrengthIndex", sweep: { period: [5, 500] } }),
+      (err) => err.code === "PeriodTooLong" && err.values.row === 1
+    );
+  });
+});
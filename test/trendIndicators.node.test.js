@@ -74,20 +74,20 @@ describe("trendIndicators.bulk (parity, one model where applicable)", () => {
   test("aroonUp", () => {
     const highs = [101.26, 102.57, 102.32, 100.69, 100.83, 101.73, 102.01];
     const out = trendIndicators.bulk.aroonUp(highs, 4);
-    assert.deepEqual(out, [33.33333333333333, 0.0, 0.0, 100.0]);
+    assert.deepEqual(Array.from(out), [33.33333333333333, 0.0, 0.0, 100.0]);
   });
 
   test("aroonDown", () => {
     const lows = [100.08, 98.75, 100.14, 98.98, 99.07, 100.1, 99.96];
     const out = trendIndicators.bulk.aroonDown(lows, 4);
-    assert.deepEqual(out, [33.33333333333333, 0.0, 33.33333333333333, 0.0]);
+    assert.deepEqual(Array.from(out), [33.33333333333333, 0.0, 33.33333333333333, 0.0]);
   });
 
   test("aroonOscillator", () => {
     const up = [33.33333333333333, 0.0, 0.0, 100.0];
     const down = [33.33333333333333, 0.0, 33.33333333333333, 0.0];
     const out = trendIndicators.bulk.aroonOscillator(up, down);
-    assert.deepEqual(out, [0.0, 0.0, -33.33333333333333, 100.0]);
+    assert.deepEqual(Array.from(out), [0.0, 0.0, -33.33333333333333, 100.0]);
   });
 
   test("aroonIndicator", () => {
@@ -111,7 +111,7 @@ describe("trendIndicators.bulk (parity, one model where applicable)", () => {
       Position.Long,
       0.0
     );
-    assert.deepEqual(out, [95.92, 95.92, 102.39, 101.9432, 101.17380800000001]);
+    assert.deepEqual(Array.from(out), [95.92, 95.92, 102.39, 101.9432, 101.17380800000001]);
   });
 
   test("directionalMovementSystem (SMA, period 3)", () => {
@@ -127,11 +127,24 @@ describe("trendIndicators.bulk (parity, one model where applicable)", () => {
     ]);
   });
 
+  test("directionalMovementSystemColumns (SMA, period 3)", () => {
+    const highs = [100.83,100.91,101.03,101.27,100.52,101.27,101.03,100.91,100.83];
+    const lows  = [100.59,100.72,100.84,100.91, 99.85,100.91,100.84,100.72,100.59];
+    const close = [100.76,100.88,100.96,101.14,100.01,101.14,100.96,100.88,100.76];
+    const out = trendIndicators.bulk.directionalMovementSystemColumns(
+      highs, lows, close, 3, ConstantModelType.SimpleMovingAverage
+    );
+    assert.deepEqual(Array.from(out.pdi), [101.35135135135205, 0.0]);
+    assert.deepEqual(Array.from(out.ndi), [25.675675675675546, 51.61290322580615]);
+    assert.deepEqual(Array.from(out.adx), [27.733956062965074, 59.92907801418446]);
+    assert.deepEqual(Array.from(out.adxr), [39.31871283052075, 42.118401465704885]);
+  });
+
   test("volumePriceTrend (no previous)", () => {
     const prices = [100.55, 99.01, 100.43, 101.0, 101.76];
     const volume = [743.0, 1074.0, 861.0, 966.0];
     const out = trendIndicators.bulk.volumePriceTrend(prices, volume, 0.0);
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       -11.379612133266974,
       4.023680463440446,
       8.910367708287545,
@@ -146,7 +159,7 @@ describe("trendIndicators.bulk (parity, one model where applicable)", () => {
       ConstantModelType.ExponentialMovingAverage, 5,
       ConstantModelType.ExponentialMovingAverage, 3
     );
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       0.6031084483806584,
       0.43792017300550673,
       0.06758060421426838,
@@ -22,7 +22,7 @@ describe("volatilityIndicators.bulk (parity, one model where applicable)", () =>
   test("ulcerIndex (period 5)", () => {
     const prices = [100.46, 100.53, 100.38, 100.19, 100.21, 100.32, 100.28];
     const out = volatilityIndicators.bulk.ulcerIndex(prices, 5);
-    assert.deepEqual(out, [
+    assert.deepEqual(Array.from(out), [
       0.21816086938686668,
       0.2373213243162752,
       0.12490478596260104,
@@ -41,7 +41,7 @@ describe("volatilityIndicators.bulk (parity, one model where applicable)", () =>
       2.0,
       ConstantModelType.SimpleMovingAverage
     );
-    assert.deepEqual(out, [100.54666666666667, 100.46666666666667, 101.95333333333333]);
+    assert.deepEqual(Array.from(out), [100.54666666666667, 100.46666666666667, 101.95333333333333]);
   });
 
   test("volatilitySystem (SMA, short start)", () => {
@@ -56,6 +56,6 @@ describe("volatilityIndicators.bulk (parity, one model where applicable)", () =>
       2.0,
       ConstantModelType.SimpleMovingAverage
     );
-    assert.deepEqual(out, [101.37333333333332, 101.29333333333332, 99.9]);
+    assert.deepEqual(Array.from(out), [101.37333333333332, 101.29333333333332, 99.9]);
   });
 });